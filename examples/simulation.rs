@@ -5,7 +5,7 @@
 //! comfort-zone boundary discovery, decay, and warm-start recovery.
 
 use ccf_core::accumulator::CoherenceField;
-use ccf_core::boundary::MinCutBoundary;
+use ccf_core::boundary::{MinCutBoundary, Partition};
 use ccf_core::mbot::{
     BrightnessBand, MbotSensors, MotionContext, NoiseBand, Orientation,
     PresenceSignature, TimePeriod,
@@ -177,10 +177,10 @@ fn main() {
     }
 
     // Update boundary trust after week 1
-    for i in 0..5 {
-        let coh = field.context_coherence(&keys[i]);
-        let n = field.context_interaction_count(&keys[i]);
-        boundary.update_trust(&keys[i], coh, n);
+    for key in &keys {
+        let coh = field.context_coherence(key);
+        let n = field.context_interaction_count(key);
+        boundary.update_trust(key, coh, n);
     }
 
     // ── Week 2: Evening routine added ────────────────────────────────────────
@@ -203,33 +203,26 @@ fn main() {
     }
 
     // Update boundary trust after week 2
-    for i in 0..5 {
-        let coh = field.context_coherence(&keys[i]);
-        let n = field.context_interaction_count(&keys[i]);
-        boundary.update_trust(&keys[i], coh, n);
+    for key in &keys {
+        let coh = field.context_coherence(key);
+        let n = field.context_interaction_count(key);
+        boundary.update_trust(key, coh, n);
     }
 
     // ── Comfort zone boundary ─────────────────────────────────────────────────
     println!("▶  DAY 15 — Comfort zone boundary (Stoer-Wagner min-cut)\n");
 
     let cut_val = boundary.min_cut_value();
-    let result  = boundary.partition();
 
     println!("  Min-cut value: {:.4}  (lower = sharper edge between zones)", cut_val);
     print!("  INSIDE  (high trust cluster): ");
-    for j in 0..result.partition_s_count {
-        let h = result.partition_s[j];
-        for (i, k) in keys.iter().enumerate() {
-            if k.context_hash_u32() == h { print!("[{}] ", names[i]); }
-        }
+    for (i, k) in keys.iter().enumerate() {
+        if boundary.side_of(k) == Some(Partition::Inside) { print!("[{}] ", names[i]); }
     }
     println!();
     print!("  OUTSIDE (unfamiliar / thin bridge): ");
-    for j in 0..result.partition_complement_count {
-        let h = result.partition_complement[j];
-        for (i, k) in keys.iter().enumerate() {
-            if k.context_hash_u32() == h { print!("[{}] ", names[i]); }
-        }
+    for (i, k) in keys.iter().enumerate() {
+        if boundary.side_of(k) == Some(Partition::Outside) { print!("[{}] ", names[i]); }
     }
     println!("\n");
 
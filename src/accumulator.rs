@@ -18,9 +18,12 @@
 //!
 //! # Invariants
 //!
-//! - **CCF-001**: `effective_coherence` uses asymmetric gate:
+//! - **CCF-001**: `effective_coherence` uses asymmetric gate (thresholds per [`GateConfig`],
+//!   shown at the defaults):
 //!   - Unfamiliar contexts (ctx < 0.3): `min(instant, ctx)` — earn trust first.
 //!   - Familiar contexts (ctx >= 0.3): `0.3 * instant + 0.7 * ctx` — history buffers noise.
+//!   - The unfamiliar arm never exceeds `ctx`, whatever the configuration, and a
+//!     cold start always lands below the configured familiarity threshold.
 //! - **CCF-002**: All accumulator values bounded [0.0, 1.0].
 //! - **CCF-003**: Personality modulates deltas, not structure.
 //! - **I-DIST-001**: no_std compatible; uses `hashbrown::HashMap` (no `std` dependency).
@@ -28,9 +31,198 @@
 
 use hashbrown::HashMap;
 
+use crate::defaults::{
+    ALONE_BOOST, BASELINE_PER_CURIOSITY, DECAY_DEAD_ZONE, DECAY_PER_TICK,
+    EARNED_FLOOR_HALF_COUNT, EARNED_FLOOR_MAX, FAMILIARITY_THRESHOLD, INSTANT_WEIGHT,
+    NEGATIVE_DELTA_BASE, POSITIVE_DELTA_BASE, TREND_DEADBAND, TREND_EMA_ALPHA,
+};
+use crate::history::{HistoryBuffer, HistoryPolicy, InteractionRecord, HISTORY_CAPACITY};
 use crate::phase::Personality;
+use crate::schedule::DecaySchedule;
 use crate::vocabulary::{ContextKey, SensorVocabulary};
 
+// ─── Cold-Start Curve ───────────────────────────────────────────────────────
+
+/// Gap kept between any cold-start baseline and the familiarity threshold, so
+/// that a brand-new context always has to earn familiarity.
+const COLD_START_MARGIN: f32 = 1e-3;
+
+/// Maps personality `curiosity_drive` to the coherence a brand-new context starts at.
+///
+/// ```text
+/// baseline = max_baseline × curiosity ^ exponent
+///   exponent = 1 → linear (default: 0.15 × curiosity)
+///   exponent = 2 → bold robots start warm, timid ones essentially cold
+/// ```
+///
+/// The result is always clamped below the familiarity threshold of the
+/// asymmetric gate (CCF-001): `[0.0, 0.3)` for [`Self::baseline`], or below a
+/// configured [`GateConfig::familiarity_threshold`] via [`Self::baseline_below`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColdStartCurve {
+    /// Baseline reached at `curiosity = 1.0` (default: 0.15).
+    pub max_baseline: f32,
+    /// Integer power applied to curiosity before scaling (default: 1 = linear).
+    pub exponent: u32,
+}
+
+impl ColdStartCurve {
+    /// Create a curve `max_baseline × curiosity ^ exponent`.
+    pub fn new(max_baseline: f32, exponent: u32) -> Self {
+        Self { max_baseline, exponent }
+    }
+
+    /// Linear curve `max_baseline × curiosity`.
+    pub fn linear(max_baseline: f32) -> Self {
+        Self::new(max_baseline, 1)
+    }
+
+    /// Cold-start coherence for a personality with the given `curiosity` in
+    /// [0.0, 1.0], kept below the default [`FAMILIARITY_THRESHOLD`].
+    pub fn baseline(&self, curiosity: f32) -> f32 {
+        self.baseline_below(curiosity, FAMILIARITY_THRESHOLD)
+    }
+
+    /// [`Self::baseline`] kept below a custom `familiarity_threshold`, e.g. the
+    /// one a field's [`GateConfig`] uses.
+    pub fn baseline_below(&self, curiosity: f32, familiarity_threshold: f32) -> f32 {
+        let c = curiosity.clamp(0.0, 1.0);
+        let mut shaped = 1.0_f32;
+        for _ in 0..self.exponent {
+            shaped *= c;
+        }
+        let ceiling = (familiarity_threshold - COLD_START_MARGIN).max(0.0);
+        (self.max_baseline * shaped).clamp(0.0, ceiling)
+    }
+}
+
+impl Default for ColdStartCurve {
+    fn default() -> Self {
+        Self::linear(BASELINE_PER_CURIOSITY)
+    }
+}
+
+/// Base rates of the accumulator dynamics.
+///
+/// Defaults are [`POSITIVE_DELTA_BASE`], [`NEGATIVE_DELTA_BASE`],
+/// [`DECAY_PER_TICK`] and [`TREND_EMA_ALPHA`] — today's behaviour. Faster-reacting platforms can raise
+/// them; personality modulation and the earned floor still apply on top.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoherenceDynamics {
+    /// Base gain of one positive interaction (default 0.02).
+    pub positive_delta_base: f32,
+    /// Base drop of one negative interaction (default 0.05).
+    pub negative_delta_base: f32,
+    /// Coherence lost per elapsed tick of disuse (default 0.0001).
+    pub decay_per_tick: f32,
+    /// Smoothing factor `a` of the recent-interaction EMA in (0.0, 1.0] (default 0.1).
+    #[cfg_attr(feature = "serde", serde(default = "default_trend_alpha"))]
+    pub trend_alpha: f32,
+}
+
+#[cfg(feature = "serde")]
+fn default_trend_alpha() -> f32 {
+    TREND_EMA_ALPHA
+}
+
+impl Default for CoherenceDynamics {
+    fn default() -> Self {
+        Self {
+            positive_delta_base: POSITIVE_DELTA_BASE,
+            negative_delta_base: NEGATIVE_DELTA_BASE,
+            decay_per_tick: DECAY_PER_TICK,
+            trend_alpha: TREND_EMA_ALPHA,
+        }
+    }
+}
+
+/// Direction a context's trust is moving right now, from its recent-interaction EMA.
+///
+/// Independent of the slow accumulator value: a context can be well above its
+/// floor and still be [`Trend::Falling`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trend {
+    /// Recent interactions are mostly positive.
+    Rising,
+    /// Recent interactions are mostly negative.
+    Falling,
+    /// Recent interactions are mixed, or there have been none.
+    Stable,
+}
+
+/// Upper bound on a configurable earned-floor asymptote — a floor of 1.0 would
+/// make trust immune to every negative event.
+const MAX_FLOOR_ASYMPTOTE: f32 = 0.99;
+
+/// Shape of the earned floor (CCF-002).
+///
+/// ```text
+/// floor = asymptote × (1 − 1 / (1 + count / half_count))
+/// ```
+///
+/// `half_count` interactions reach half the asymptote. The default (0.5 / 20)
+/// is the original fixed curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FloorParams {
+    /// Limit the floor approaches with unbounded interactions (default 0.5).
+    pub asymptote: f32,
+    /// Interaction count at which the floor reaches half the asymptote (default 20).
+    pub half_count: f32,
+}
+
+impl FloorParams {
+    /// Create floor parameters, clamping `asymptote` to `[0.0, 0.99]` and
+    /// `half_count` to a positive value.
+    pub fn new(asymptote: f32, half_count: f32) -> Self {
+        Self {
+            asymptote: asymptote.clamp(0.0, MAX_FLOOR_ASYMPTOTE),
+            half_count: half_count.max(f32::EPSILON),
+        }
+    }
+
+    /// Earned floor after `count` positive interactions.
+    pub fn floor_at(&self, count: u32) -> f32 {
+        self.asymptote * (1.0 - 1.0 / (1.0 + count as f32 / self.half_count))
+    }
+
+    /// Fewest positive interactions at which [`Self::floor_at`] reaches `target_floor`.
+    ///
+    /// Inverts the curve: `count = half_count × floor / (asymptote − floor)`.
+    /// Returns `Some(0)` for targets ≤ 0.0 and `None` for targets at or above
+    /// the asymptote, which the floor only approaches.
+    pub fn interactions_for(&self, target_floor: f32) -> Option<u32> {
+        if target_floor <= 0.0 {
+            return Some(0);
+        }
+        if target_floor.is_nan() || target_floor >= self.asymptote {
+            return None;
+        }
+        let exact = self.half_count * target_floor / (self.asymptote - target_floor);
+        if exact >= u32::MAX as f32 {
+            return None;
+        }
+        // Settle float rounding against the curve actually used by `floor_at`.
+        let mut count = exact as u32;
+        while count > 0 && self.floor_at(count - 1) >= target_floor {
+            count -= 1;
+        }
+        while self.floor_at(count) < target_floor {
+            count = count.checked_add(1)?;
+        }
+        Some(count)
+    }
+}
+
+impl Default for FloorParams {
+    fn default() -> Self {
+        Self { asymptote: EARNED_FLOOR_MAX, half_count: EARNED_FLOOR_HALF_COUNT }
+    }
+}
+
 // ─── Coherence Accumulator ──────────────────────────────────────────────────
 
 /// Per-context coherence accumulator. Grows through repeated positive
@@ -40,7 +232,7 @@ use crate::vocabulary::{ContextKey, SensorVocabulary};
 /// cannot be erased by transient negative events.
 ///
 /// Patent Claims 2–5.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoherenceAccumulator {
     /// Accumulated coherence for this context [0.0, 1.0].
@@ -49,6 +241,25 @@ pub struct CoherenceAccumulator {
     pub interaction_count: u32,
     /// Tick of the most recent interaction (positive or negative).
     pub last_interaction_tick: u64,
+    /// Highest coherence this context has ever held [0.0, 1.0].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub peak_value: f32,
+    /// Tick of the positive interaction that first lifted `value` to the
+    /// familiarity threshold ([`FAMILIARITY_THRESHOLD`], or the owning field's
+    /// [`GateConfig`]); `None` if the context has never been familiar.
+    /// Set once and never reset.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub first_familiar_tick: Option<u64>,
+    /// Shape of this context's earned floor.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub floor_params: FloorParams,
+    /// Base rates for growth, drops and decay.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dynamics: CoherenceDynamics,
+    /// Exponential moving average of recent interaction signs in [-1.0, 1.0]:
+    /// +1 per positive, −1 per negative, smoothed by `dynamics.trend_alpha`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recent_ema: f32,
 }
 
 impl CoherenceAccumulator {
@@ -58,35 +269,150 @@ impl CoherenceAccumulator {
             value: 0.0,
             interaction_count: 0,
             last_interaction_tick: 0,
+            peak_value: 0.0,
+            first_familiar_tick: None,
+            floor_params: FloorParams::default(),
+            dynamics: CoherenceDynamics::default(),
+            recent_ema: 0.0,
+        }
+    }
+
+    /// Construct a fresh accumulator whose earned floor follows `params`
+    /// (re-clamped as in [`FloorParams::new`]).
+    pub fn with_floor_params(params: FloorParams) -> Self {
+        Self {
+            floor_params: FloorParams::new(params.asymptote, params.half_count),
+            ..Self::new()
         }
     }
 
+    /// Construct a fresh accumulator with custom base rates.
+    pub fn with_dynamics(dynamics: CoherenceDynamics) -> Self {
+        Self { dynamics, ..Self::new() }
+    }
+
     /// Cold-start constructor: initialise value from personality `curiosity_drive`.
     ///
     /// `curiosity`: personality curiosity_drive in [0.0, 1.0].
     /// Baseline = 0.15 × curiosity (max 0.15 for curiosity = 1.0).
     pub fn new_with_baseline(curiosity: f32) -> Self {
+        let value = (BASELINE_PER_CURIOSITY * curiosity).clamp(0.0, 1.0);
         Self {
-            value: (0.15 * curiosity).clamp(0.0, 1.0),
+            value,
             interaction_count: 0,
             last_interaction_tick: 0,
+            peak_value: value,
+            first_familiar_tick: None,
+            floor_params: FloorParams::default(),
+            dynamics: CoherenceDynamics::default(),
+            recent_ema: 0.0,
+        }
+    }
+
+    /// Cold-start constructor using a configurable [`ColdStartCurve`].
+    ///
+    /// With `ColdStartCurve::default()` this is identical to [`Self::new_with_baseline`].
+    pub fn new_with_curve(curiosity: f32, curve: &ColdStartCurve) -> Self {
+        Self::cold_at(curve.baseline(curiosity))
+    }
+
+    /// A fresh accumulator whose coherence (and peak) start at `value`.
+    pub(crate) fn cold_at(value: f32) -> Self {
+        Self {
+            value,
+            interaction_count: 0,
+            last_interaction_tick: 0,
+            peak_value: value,
+            first_familiar_tick: None,
+            floor_params: FloorParams::default(),
+            dynamics: CoherenceDynamics::default(),
+            recent_ema: 0.0,
         }
     }
 
     /// The minimum coherence that interaction history protects against decay or negative events.
     ///
-    /// Asymptotically approaches 0.5 with repeated interactions — never fully
-    /// immune, but increasingly resilient.
+    /// Asymptotically approaches the [`FloorParams`] asymptote (default 0.5)
+    /// with repeated interactions — never fully immune, but increasingly resilient.
     ///
     /// ```text
-    /// floor = 0.5 × (1 − 1 / (1 + count / 20))
+    /// floor = 0.5 × (1 − 1 / (1 + count / 20))     (default params)
     ///   count =  0 → floor ≈ 0.00
     ///   count = 20 → floor ≈ 0.25
     ///   count = 100 → floor ≈ 0.42
     ///   limit  → 0.50
     /// ```
     pub fn earned_floor(&self) -> f32 {
-        0.5 * (1.0 - 1.0 / (1.0 + self.interaction_count as f32 / 20.0))
+        self.floor_params.floor_at(self.interaction_count)
+    }
+
+    /// Honesty-preserving merge of two accumulators (I-CKM-001, I-CKM-002):
+    /// the lower trust and peak, the summed interaction count, the latest tick.
+    ///
+    /// Associative and commutative; this is the merge used by
+    /// `cardinality::merge_accumulators` (Patent Continuation Claim B).
+    pub fn merged(&self, other: &CoherenceAccumulator) -> CoherenceAccumulator {
+        CoherenceAccumulator {
+            value: self.value.min(other.value),
+            interaction_count: self.interaction_count.saturating_add(other.interaction_count),
+            last_interaction_tick: self.last_interaction_tick.max(other.last_interaction_tick),
+            peak_value: self.peak_value.min(other.peak_value),
+            // Familiar only once both sides were: the later of the two crossings.
+            first_familiar_tick: self
+                .first_familiar_tick
+                .zip(other.first_familiar_tick)
+                .map(|(x, y)| x.max(y)),
+            // The less protective floor of the two.
+            floor_params: FloorParams {
+                asymptote: self.floor_params.asymptote.min(other.floor_params.asymptote),
+                half_count: self.floor_params.half_count.max(other.floor_params.half_count),
+            },
+            // Likewise the more cautious rates: slowest growth, sharpest drops and decay.
+            dynamics: CoherenceDynamics {
+                positive_delta_base: self.dynamics.positive_delta_base.min(other.dynamics.positive_delta_base),
+                negative_delta_base: self.dynamics.negative_delta_base.max(other.dynamics.negative_delta_base),
+                decay_per_tick: self.dynamics.decay_per_tick.max(other.dynamics.decay_per_tick),
+                trend_alpha: self.dynamics.trend_alpha.min(other.dynamics.trend_alpha),
+            },
+            // The cooler of the two recent trends.
+            recent_ema: self.recent_ema.min(other.recent_ema),
+        }
+    }
+
+    /// Fewest positive interactions at which the default earned floor reaches `target_floor`.
+    ///
+    /// Inverts the floor curve: `count = 20 × floor / (0.5 − floor)`, so a floor
+    /// of 0.3 needs 30 interactions. Returns `Some(0)` for targets ≤ 0.0 and
+    /// `None` for targets ≥ 0.5, which the floor only approaches asymptotically.
+    /// See [`FloorParams::interactions_for`] for custom floors.
+    pub fn interactions_for_resilient_floor(target_floor: f32) -> Option<u32> {
+        FloorParams::default().interactions_for(target_floor)
+    }
+
+    /// Direction of recent interactions: [`Trend::Rising`] or [`Trend::Falling`]
+    /// once `recent_ema` leaves the ±[`TREND_DEADBAND`] band, else [`Trend::Stable`].
+    pub fn trend(&self) -> Trend {
+        if self.recent_ema > TREND_DEADBAND {
+            Trend::Rising
+        } else if self.recent_ema < -TREND_DEADBAND {
+            Trend::Falling
+        } else {
+            Trend::Stable
+        }
+    }
+
+    /// Fold one interaction sign (+1.0 / −1.0) into `recent_ema`.
+    fn record_trend(&mut self, signal: f32) {
+        let a = self.dynamics.trend_alpha.clamp(0.0, 1.0);
+        self.recent_ema = ((1.0 - a) * self.recent_ema + a * signal).clamp(-1.0, 1.0);
+    }
+
+    /// Trust genuinely earned above the cold-start `baseline`: `(value − baseline).max(0.0)`.
+    ///
+    /// A context still at its baseline reports 0.0 — a bold robot's warm first
+    /// impression is not earned trust. Read alongside `interaction_count`.
+    pub fn earned_component(&self, baseline: f32) -> f32 {
+        (self.value - baseline).max(0.0)
     }
 
     /// Record a positive interaction. Coherence grows asymptotically toward 1.0.
@@ -95,13 +421,43 @@ impl CoherenceAccumulator {
     /// - `tick`: current tick for freshness tracking.
     /// - `alone`: `true` if presence is Absent — doubles delta for faster bootstrap.
     pub fn positive_interaction(&mut self, recovery_speed: f32, tick: u64, alone: bool) {
-        let mut delta = 0.02 * (0.5 + recovery_speed) * (1.0 - self.value);
+        self.positive_interaction_weighted(recovery_speed, tick, alone, 1.0);
+    }
+
+    /// Record a positive interaction whose delta is scaled by `weight` in [0.0, 1.0].
+    ///
+    /// `weight = 1.0` is identical to [`Self::positive_interaction`]. The
+    /// interaction is always counted; only the coherence gain is scaled.
+    pub fn positive_interaction_weighted(
+        &mut self,
+        recovery_speed: f32,
+        tick: u64,
+        alone: bool,
+        weight: f32,
+    ) {
+        self.grow(recovery_speed, tick, alone, weight.clamp(0.0, 1.0), FAMILIARITY_THRESHOLD);
+    }
+
+    /// Shared positive-interaction step; `gain` (≥ 0) scales the delta.
+    ///
+    /// `recovery_speed` is clamped to [0.0, 1.0], so an out-of-range
+    /// personality can neither overshoot nor turn growth into a loss.
+    /// `familiarity_threshold` is the level that stamps `first_familiar_tick`.
+    pub(crate) fn grow(&mut self, recovery_speed: f32, tick: u64, alone: bool, gain: f32, familiarity_threshold: f32) {
+        let speed = recovery_speed.clamp(0.0, 1.0);
+        let mut delta = self.dynamics.positive_delta_base * (0.5 + speed) * (1.0 - self.value);
         if alone {
-            delta *= 2.0; // alone contexts bootstrap faster
+            delta *= ALONE_BOOST; // alone contexts bootstrap faster
         }
+        delta *= gain;
         self.value = (self.value + delta).min(1.0);
+        self.peak_value = self.peak_value.max(self.value);
+        if self.first_familiar_tick.is_none() && self.value >= familiarity_threshold {
+            self.first_familiar_tick = Some(tick);
+        }
         self.interaction_count = self.interaction_count.saturating_add(1);
         self.last_interaction_tick = tick;
+        self.record_trend(1.0);
     }
 
     /// Record a negative interaction (startle, collision, high tension).
@@ -111,23 +467,90 @@ impl CoherenceAccumulator {
     ///
     /// - `startle_sensitivity`: personality parameter [0.0, 1.0] — higher = bigger drop.
     /// - `tick`: current tick.
-    pub fn negative_interaction(&mut self, startle_sensitivity: f32, tick: u64) {
+    ///
+    /// Returns the coherence actually lost — `0.0` when the context already
+    /// sits at its earned floor.
+    pub fn negative_interaction(&mut self, startle_sensitivity: f32, tick: u64) -> f32 {
+        self.negative_interaction_weighted(startle_sensitivity, tick, 1.0)
+    }
+
+    /// Record a negative interaction whose drop is scaled by `weight` in [0.0, 1.0].
+    ///
+    /// `weight = 1.0` is identical to [`Self::negative_interaction`]. Still
+    /// floored at `earned_floor()`. Returns the coherence actually lost.
+    pub fn negative_interaction_weighted(&mut self, startle_sensitivity: f32, tick: u64, weight: f32) -> f32 {
+        self.negative_interaction_bounded(startle_sensitivity, tick, weight, 0.0, 1.0)
+    }
+
+    /// Record a weighted negative interaction whose drop is clamped to
+    /// `[min_delta, max_delta]` before being applied.
+    ///
+    /// `max_delta` caps how far any single negative event can move trust,
+    /// regardless of sensitivity; `(0.0, 1.0)` leaves the drop unclamped.
+    /// `startle_sensitivity` is clamped to [0.0, 1.0].
+    /// Still floored at `earned_floor()`. Returns the coherence actually lost.
+    pub fn negative_interaction_bounded(
+        &mut self,
+        startle_sensitivity: f32,
+        tick: u64,
+        weight: f32,
+        min_delta: f32,
+        max_delta: f32,
+    ) -> f32 {
         let floor = self.earned_floor();
-        let delta = 0.05 * (0.5 + startle_sensitivity);
+        let sensitivity = startle_sensitivity.clamp(0.0, 1.0);
+        let delta = self.dynamics.negative_delta_base * (0.5 + sensitivity) * weight.clamp(0.0, 1.0);
+        let delta = delta.max(min_delta).min(max_delta).max(0.0);
+        let before = self.value;
         self.value = (self.value - delta).max(floor);
         self.last_interaction_tick = tick;
+        self.record_trend(-1.0);
+        before - self.value
+    }
+
+    /// Mark the context as seen at `tick` without changing coherence or count.
+    pub fn touch(&mut self, tick: u64) {
+        self.last_interaction_tick = tick;
     }
 
     /// Apply time-based decay. Call once per elapsed period.
     ///
     /// Coherence decays toward `earned_floor()`, not toward zero.
     /// More interactions = higher floor = harder to lose earned trust.
+    /// Within [`DECAY_DEAD_ZONE`] of the floor the context is at rest and
+    /// decay is a no-op.
     pub fn decay(&mut self, elapsed_ticks: u64) {
+        self.decay_with_dead_zone(elapsed_ticks, DECAY_DEAD_ZONE, false);
+    }
+
+    /// Apply time-based decay with an explicit rest dead-zone.
+    ///
+    /// Once `value − earned_floor() < dead_zone` the context is at rest: decay
+    /// does nothing, or snaps `value` onto the floor if `snap_to_floor`. A
+    /// context that recovers above the dead-zone resumes normal decay.
+    ///
+    /// Returns `true` if `value` changed.
+    pub fn decay_with_dead_zone(&mut self, elapsed_ticks: u64, dead_zone: f32, snap_to_floor: bool) -> bool {
+        self.decay_effective(elapsed_ticks as f32, dead_zone, snap_to_floor)
+    }
+
+    /// Decay by a possibly fractional number of (schedule-weighted) ticks.
+    fn decay_effective(&mut self, effective_ticks: f32, dead_zone: f32, snap_to_floor: bool) -> bool {
         let floor = self.earned_floor();
-        if self.value > floor {
-            let decay_rate = 0.0001 * elapsed_ticks as f32;
-            self.value = (self.value - decay_rate).max(floor);
+        if self.value <= floor {
+            return false;
+        }
+        if self.value - floor < dead_zone {
+            if snap_to_floor {
+                self.value = floor;
+                return true;
+            }
+            return false;
         }
+        let decay_rate = self.dynamics.decay_per_tick * effective_ticks;
+        let before = self.value;
+        self.value = (self.value - decay_rate).max(floor);
+        self.value != before
     }
 }
 
@@ -142,6 +565,152 @@ impl Default for CoherenceAccumulator {
 /// Maximum number of tracked contexts. Oldest entry is evicted when full.
 const MAX_CONTEXTS: usize = 64;
 
+/// Valence magnitude below which [`CoherenceField::interaction`] only touches the context.
+const VALENCE_EPSILON: f32 = 1e-3;
+
+/// Tunable behaviour of a [`CoherenceField`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FieldConfig {
+    /// Curve used to initialise accumulators for unseen contexts.
+    pub cold_start: ColdStartCurve,
+    /// Slew-rate limit: maximum |Δcoherence| a single interaction call may apply.
+    /// `None` (default) leaves interactions unlimited.
+    pub max_delta_per_tick: Option<f32>,
+    /// Which contexts record a per-context interaction history (default: none).
+    pub history: HistoryPolicy,
+    /// Lower clamp on the drop applied by one negative interaction (default 0.0).
+    pub min_negative_delta: f32,
+    /// Upper clamp on the drop applied by one negative interaction (default 1.0).
+    ///
+    /// Bounds how much any single startle can cost, whatever the personality's
+    /// `startle_sensitivity`.
+    pub max_negative_delta: f32,
+    /// Rest dead-zone above the earned floor for [`CoherenceField::decay_all`]
+    /// (default [`DECAY_DEAD_ZONE`]).
+    pub decay_dead_zone: f32,
+    /// Snap resting contexts onto their floor instead of leaving them in place.
+    pub snap_to_floor: bool,
+    /// Faster growth in contexts similar to already-trusted ones (default: off).
+    pub similarity_boost: Option<SimilarityBoost>,
+    /// Earned-floor shape given to newly created contexts (default 0.5 / 20).
+    pub floor_params: FloorParams,
+    /// Base rates given to newly created contexts (default: the crate constants).
+    pub dynamics: CoherenceDynamics,
+    /// CCF-001 gate thresholds used by [`CoherenceField::effective_coherence`].
+    pub gate: GateConfig,
+}
+
+/// Kind of a logged event for [`CoherenceField::replay`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InteractionKind {
+    /// A positive interaction; `alone` as in [`CoherenceField::positive_interaction`].
+    Positive {
+        /// `true` if presence was Absent (faster bootstrap).
+        alone: bool,
+    },
+    /// A negative interaction (startle, collision).
+    Negative,
+    /// Field-wide decay up to the event's tick.
+    Decay,
+}
+
+/// Thresholds of the CCF-001 asymmetric gate in [`CoherenceField::effective_coherence`].
+///
+/// - **Unfamiliar** (`ctx < familiarity_threshold`): `min(instant, ctx)`.
+/// - **Familiar**: `instant_weight × instant + (1 − instant_weight) × ctx`.
+///
+/// Defaults are [`FAMILIARITY_THRESHOLD`] (0.3) and [`INSTANT_WEIGHT`] (0.3).
+/// A skittish platform might raise the threshold and lower the instant weight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GateConfig {
+    /// Context coherence at which the familiar arm takes over.
+    pub familiarity_threshold: f32,
+    /// Weight of the instant reading in the familiar arm; history gets the rest.
+    pub instant_weight: f32,
+}
+
+impl GateConfig {
+    /// Create a gate configuration, clamping both values to `[0.0, 1.0]`.
+    pub fn new(familiarity_threshold: f32, instant_weight: f32) -> Self {
+        Self {
+            familiarity_threshold: familiarity_threshold.clamp(0.0, 1.0),
+            instant_weight: instant_weight.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Default for GateConfig {
+    fn default() -> Self {
+        Self { familiarity_threshold: FAMILIARITY_THRESHOLD, instant_weight: INSTANT_WEIGHT }
+    }
+}
+
+/// Transfer of familiarity to the *growth rate* of similar contexts.
+///
+/// On a positive interaction, the delta is multiplied by
+/// `1 + max_boost × c`, where `c` is the highest coherence among other known
+/// contexts whose cosine similarity exceeds `threshold`. Only the rate changes:
+/// new contexts still start at the cold-start baseline and must climb.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimilarityBoost {
+    /// Minimum cosine similarity for a known context to contribute.
+    pub threshold: f32,
+    /// Maximum extra growth, as a fraction of the normal delta (clamped ≥ 0).
+    pub max_boost: f32,
+}
+
+impl Default for FieldConfig {
+    fn default() -> Self {
+        Self {
+            cold_start: ColdStartCurve::default(),
+            max_delta_per_tick: None,
+            history: HistoryPolicy::default(),
+            min_negative_delta: 0.0,
+            max_negative_delta: 1.0,
+            decay_dead_zone: DECAY_DEAD_ZONE,
+            snap_to_floor: false,
+            similarity_boost: None,
+            floor_params: FloorParams::default(),
+            dynamics: CoherenceDynamics::default(),
+            gate: GateConfig::default(),
+        }
+    }
+}
+
+/// Clamp the change made by one interaction to `±max_delta` around the
+/// `(value, peak_value)` held before it.
+///
+/// The earned floor is re-applied afterwards, so a limited drop still never
+/// lands below `earned_floor()`.
+pub(crate) fn slew_limit(acc: &mut CoherenceAccumulator, before: (f32, f32), max_delta: Option<f32>) {
+    let (before, before_peak) = before;
+    if let Some(max) = max_delta {
+        let max = max.max(0.0);
+        acc.value = acc.value.clamp(before - max, before + max);
+        if acc.value < before {
+            acc.value = acc.value.max(acc.earned_floor());
+        }
+        acc.peak_value = before_peak.max(acc.value);
+    }
+}
+
+/// Lightweight summary of a [`CoherenceField`], cheap enough to log every tick.
+///
+/// Derives `defmt::Format` under the `defmt` feature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldStats {
+    /// Number of tracked contexts.
+    pub context_count: usize,
+    /// Coherence of the most trusted context (0.0 for an empty field).
+    pub strongest_coherence: f32,
+}
+
 /// The coherence field: a map of context → [`CoherenceAccumulator`].
 ///
 /// Generic over any sensor vocabulary `V` implementing [`SensorVocabulary<N>`].
@@ -155,37 +724,124 @@ pub struct CoherenceField<V: SensorVocabulary<N>, const N: usize> {
     personality_baseline: f32,
     /// Fallback coherence used as floor for unseen contexts in degraded mode.
     fallback_coherence: Option<f32>,
+    /// Field configuration (cold-start curve, slew limit, history policy).
+    config: FieldConfig,
+    /// Per-context interaction histories (empty unless `config.history` enables them).
+    histories: HashMap<ContextKey<V, N>, HistoryBuffer<HISTORY_CAPACITY>>,
+    /// Tick up to which decay has been applied by the tick-based decay methods.
+    last_decay_tick: u64,
 }
 
 impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
     /// Construct a fresh field with no accumulated coherence.
     pub fn new() -> Self {
+        Self::with_config(FieldConfig::default())
+    }
+
+    /// Construct a fresh field with the given configuration.
+    pub fn with_config(config: FieldConfig) -> Self {
         Self {
             accumulators: HashMap::new(),
             personality_baseline: 0.0,
             fallback_coherence: None,
+            config,
+            histories: HashMap::new(),
+            last_decay_tick: 0,
         }
     }
 
+    /// Construct a fresh field whose cold-start baseline is seeded from
+    /// `personality` (0.15 × curiosity_drive), so unseen contexts start warm.
+    pub fn with_personality(personality: &Personality) -> Self {
+        let mut field = Self::new();
+        field.update_personality(personality);
+        field
+    }
+
+    /// The field configuration.
+    pub fn config(&self) -> &FieldConfig {
+        &self.config
+    }
+
+    /// Personality baseline used for cold starts (0.15 × curiosity_drive).
+    pub fn personality_baseline(&self) -> f32 {
+        self.personality_baseline
+    }
+
+    /// Fallback coherence for unseen contexts, if set.
+    pub fn fallback(&self) -> Option<f32> {
+        self.fallback_coherence
+    }
+
+    /// Set the personality baseline for cold-start contexts (0.15 × curiosity_drive).
+    ///
+    /// The curiosity recovered from this baseline is passed through
+    /// [`FieldConfig::cold_start`] when an unseen context is created.
+    pub fn set_personality_baseline(&mut self, baseline: f32) {
+        self.personality_baseline = baseline.clamp(0.0, 1.0);
+    }
+
+    /// Adopt a new personality for *future* cold starts.
+    ///
+    /// Recomputes the personality baseline from `personality.curiosity_drive`.
+    /// Existing accumulators are left untouched — earned trust is never altered
+    /// by a change of temperament (CCF-003).
+    pub fn update_personality(&mut self, personality: &Personality) {
+        self.set_personality_baseline(BASELINE_PER_CURIOSITY * personality.curiosity_drive.clamp(0.0, 1.0));
+    }
+
+    /// Adopt a new personality and lift cold-start-level contexts to the new baseline.
+    ///
+    /// Only untouched cold-start contexts are raised: no positive interactions
+    /// (nothing earned yet) and still at their peak value, i.e. never pulled
+    /// down by a startle or by decay — earned distrust stays where it is. They
+    /// are lifted only if below the new cold-start value; a lower baseline never
+    /// pulls a context down. Returns the number of contexts lifted.
+    pub fn rebaseline_unfamiliar(&mut self, personality: &Personality) -> usize {
+        self.update_personality(personality);
+        let baseline = self.cold_start_value();
+        let mut lifted = 0;
+        for acc in self.accumulators.values_mut() {
+            let untouched = acc.interaction_count == 0 && acc.value >= acc.peak_value;
+            if untouched && acc.value < baseline {
+                acc.value = baseline;
+                acc.peak_value = acc.peak_value.max(baseline);
+                lifted += 1;
+            }
+        }
+        lifted
+    }
+
     // ── CCF-001: asymmetric min-gate ───────────────────────────────────────
 
     /// Compute effective coherence using the asymmetric gate (CCF-001).
     ///
-    /// - **Unfamiliar** (ctx < 0.3): `min(instant, ctx)` — earn trust first.
-    /// - **Familiar** (ctx ≥ 0.3): `0.3 × instant + 0.7 × ctx` — history buffers noise.
+    /// - **Unfamiliar** (ctx < threshold): `min(instant, ctx)` — earn trust first.
+    /// - **Familiar** (ctx ≥ threshold): `w × instant + (1 − w) × ctx` — history buffers noise.
+    ///
+    /// The threshold and instant weight `w` come from the field's [`GateConfig`]
+    /// (default 0.3 and 0.3); see [`Self::set_gate_config`].
     pub fn effective_coherence(&self, instant: f32, key: &ContextKey<V, N>) -> f32 {
         let ctx = self.context_coherence(key);
-        if ctx < 0.3 {
+        let gate = &self.config.gate;
+        if ctx < gate.familiarity_threshold {
             if instant < ctx { instant } else { ctx }
         } else {
-            (0.3 * instant + 0.7 * ctx).clamp(0.0, 1.0)
+            (gate.instant_weight * instant + (1.0 - gate.instant_weight) * ctx).clamp(0.0, 1.0)
         }
     }
 
+    /// Replace the CCF-001 gate thresholds (clamped as in [`GateConfig::new`]).
+    pub fn set_gate_config(&mut self, gate: GateConfig) {
+        self.config.gate = GateConfig::new(gate.familiarity_threshold, gate.instant_weight);
+    }
+
     // ── Interaction API (CCF-003: Personality modulates deltas, not structure) ─
 
     /// Record a positive interaction for a context, modulated by `personality`.
     ///
+    /// Growth scales with `recovery_speed` and, independently, with
+    /// [`Personality::curiosity_growth_factor`].
     /// Creates the accumulator at the personality baseline if the context is unseen.
     pub fn positive_interaction(
         &mut self,
@@ -194,8 +850,9 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
         tick: u64,
         alone: bool,
     ) {
-        self.get_or_create(key)
-            .positive_interaction(personality.recovery_speed, tick, alone);
+        let boost = self.growth_boost(key) * personality.curiosity_growth_factor();
+        let threshold = self.config.gate.familiarity_threshold;
+        self.apply_interaction(key, tick, |acc| acc.grow(personality.recovery_speed, tick, alone, boost, threshold));
     }
 
     /// Record a negative interaction for a context, modulated by `personality`.
@@ -207,8 +864,136 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
         personality: &Personality,
         tick: u64,
     ) {
-        self.get_or_create(key)
-            .negative_interaction(personality.startle_sensitivity, tick);
+        self.negative_interaction_drop(key, personality, tick);
+    }
+
+    /// [`Self::negative_interaction`], returning the coherence actually lost.
+    ///
+    /// The result is `0.0` when the earned floor absorbs the whole drop, so a
+    /// caller can tell a real setback from one the context shrugged off.
+    pub fn negative_interaction_drop(
+        &mut self,
+        key: &ContextKey<V, N>,
+        personality: &Personality,
+        tick: u64,
+    ) -> f32 {
+        let (min, max) = (self.config.min_negative_delta, self.config.max_negative_delta);
+        -self.apply_interaction(key, tick, |acc| {
+            acc.negative_interaction_bounded(personality.startle_sensitivity, tick, 1.0, min, max);
+        })
+    }
+
+    /// Record a graded, signed interaction from a single `valence` in [-1.0, 1.0].
+    ///
+    /// - `valence > 0`: positive interaction with its delta scaled by `valence`
+    ///   (`1.0` ≡ [`Self::positive_interaction`]).
+    /// - `valence < 0`: negative interaction with its drop scaled by `|valence|`
+    ///   (`-1.0` ≡ [`Self::negative_interaction`]).
+    /// - `|valence| < 1e-3`: the context is only touched (freshness updated).
+    ///
+    /// Creates the accumulator at the personality baseline if the context is unseen.
+    pub fn interaction(
+        &mut self,
+        key: &ContextKey<V, N>,
+        personality: &Personality,
+        valence: f32,
+        tick: u64,
+        alone: bool,
+    ) {
+        let valence = valence.clamp(-1.0, 1.0);
+        let (min, max) = (self.config.min_negative_delta, self.config.max_negative_delta);
+        let threshold = self.config.gate.familiarity_threshold;
+        let boost = if valence >= VALENCE_EPSILON {
+            self.growth_boost(key) * personality.curiosity_growth_factor()
+        } else {
+            1.0
+        };
+        self.apply_interaction(key, tick, |acc| {
+            if valence >= VALENCE_EPSILON {
+                acc.grow(personality.recovery_speed, tick, alone, valence * boost, threshold);
+            } else if valence <= -VALENCE_EPSILON {
+                acc.negative_interaction_bounded(
+                    personality.startle_sensitivity,
+                    tick,
+                    -valence,
+                    min,
+                    max,
+                );
+            } else {
+                acc.touch(tick);
+            }
+        });
+    }
+
+    // ── Batch interactions ─────────────────────────────────────────────────
+
+    /// Record one positive interaction per entry of `ticks`, in order.
+    ///
+    /// Equivalent to calling [`Self::positive_interaction`] once per tick, but
+    /// looks the context up only once.
+    pub fn positive_interactions(
+        &mut self,
+        key: &ContextKey<V, N>,
+        personality: &Personality,
+        ticks: &[u64],
+        alone: bool,
+    ) {
+        let boost = self.growth_boost(key) * personality.curiosity_growth_factor();
+        let threshold = self.config.gate.familiarity_threshold;
+        self.apply_run(key, ticks.first().copied().unwrap_or(0), ticks, |acc, &t| {
+            acc.grow(personality.recovery_speed, t, alone, boost, threshold);
+            t
+        });
+    }
+
+    /// Replay a log of `(context, kind, tick)` events in order.
+    ///
+    /// Equivalent to calling the per-event methods in sequence:
+    /// [`InteractionKind::Positive`] → [`Self::positive_interaction`],
+    /// [`InteractionKind::Negative`] → [`Self::negative_interaction`],
+    /// [`InteractionKind::Decay`] → [`Self::decay_all_to`] (its key is ignored).
+    /// Consecutive interactions with the same context share one map lookup.
+    pub fn replay(
+        &mut self,
+        personality: &Personality,
+        events: &[(ContextKey<V, N>, InteractionKind, u64)],
+    ) {
+        let (min, max) = (self.config.min_negative_delta, self.config.max_negative_delta);
+        let threshold = self.config.gate.familiarity_threshold;
+        let mut i = 0;
+        while i < events.len() {
+            let (key, kind, tick) = &events[i];
+            if *kind == InteractionKind::Decay {
+                self.decay_all_to(*tick);
+                i += 1;
+                continue;
+            }
+            let run = events[i..]
+                .iter()
+                .position(|(k, kind, _)| k != key || *kind == InteractionKind::Decay)
+                .unwrap_or(events.len() - i);
+            // Only other contexts feed the boost, and they are untouched during the run.
+            let boost = self.growth_boost(key) * personality.curiosity_growth_factor();
+            self.apply_run(key, *tick, &events[i..i + run], |acc, (_, kind, t)| {
+                match *kind {
+                    InteractionKind::Positive { alone } => {
+                        acc.grow(personality.recovery_speed, *t, alone, boost, threshold)
+                    }
+                    InteractionKind::Negative => {
+                        acc.negative_interaction_bounded(
+                            personality.startle_sensitivity,
+                            *t,
+                            1.0,
+                            min,
+                            max,
+                        );
+                    }
+                    InteractionKind::Decay => {}
+                }
+                *t
+            });
+            i += run;
+        }
     }
 
     // ── Read accessors ─────────────────────────────────────────────────────
@@ -228,13 +1013,119 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
         self.accumulators.get(key).map_or(0, |a| a.interaction_count)
     }
 
+    /// Earned portion of a context's coherence above the field's personality baseline.
+    ///
+    /// See [`CoherenceAccumulator::earned_component`]. Returns 0.0 for unseen contexts.
+    pub fn context_earned_coherence(&self, key: &ContextKey<V, N>) -> f32 {
+        self.accumulators
+            .get(key)
+            .map_or(0.0, |a| a.earned_component(self.personality_baseline))
+    }
+
+    /// Further positive interactions a context needs before its earned floor
+    /// reaches `target_floor` — "how long until this room is startle-proof?".
+    ///
+    /// `Some(0)` if already there; `None` if the target is unreachable (at or
+    /// above the floor asymptote). Unseen contexts count from zero under
+    /// [`FieldConfig::floor_params`]. See [`FloorParams::interactions_for`].
+    pub fn interactions_until_resilient(&self, key: &ContextKey<V, N>, target_floor: f32) -> Option<u32> {
+        let params = self.accumulators.get(key).map_or(self.config.floor_params, |a| a.floor_params);
+        let needed = params.interactions_for(target_floor)?;
+        Some(needed.saturating_sub(self.context_interaction_count(key)))
+    }
+
+    /// Tick at which a context first became familiar, or `None` if it never has
+    /// (or is unseen). See [`CoherenceAccumulator::first_familiar_tick`].
+    pub fn context_first_familiar_tick(&self, key: &ContextKey<V, N>) -> Option<u64> {
+        self.accumulators.get(key).and_then(|a| a.first_familiar_tick)
+    }
+
+    /// The most trusted context and its coherence, or `None` for an empty field.
+    ///
+    /// Ties on coherence go to the context with more interactions. Single pass,
+    /// no allocation.
+    pub fn strongest_context(&self) -> Option<(&ContextKey<V, N>, f32)> {
+        self.accumulators
+            .iter()
+            .max_by(|(_, a), (_, b)| {
+                a.value.total_cmp(&b.value).then(a.interaction_count.cmp(&b.interaction_count))
+            })
+            .map(|(k, a)| (k, a.value))
+    }
+
+    /// The least trusted context and its coherence, or `None` for an empty field.
+    ///
+    /// Ties on coherence go to the context with more interactions. Single pass,
+    /// no allocation.
+    pub fn weakest_context(&self) -> Option<(&ContextKey<V, N>, f32)> {
+        self.accumulators
+            .iter()
+            .min_by(|(_, a), (_, b)| {
+                a.value.total_cmp(&b.value).then(b.interaction_count.cmp(&a.interaction_count))
+            })
+            .map(|(k, a)| (k, a.value))
+    }
+
+    /// Context count and strongest coherence in one pass, for logging.
+    pub fn stats(&self) -> FieldStats {
+        FieldStats {
+            context_count: self.accumulators.len(),
+            strongest_coherence: self.strongest_context().map_or(0.0, |(_, c)| c),
+        }
+    }
+
     // ── Decay ──────────────────────────────────────────────────────────────
 
     /// Apply time-based decay to all accumulators.
+    ///
+    /// Contexts resting within [`FieldConfig::decay_dead_zone`] of their floor
+    /// are left untouched (or snapped, per [`FieldConfig::snap_to_floor`]).
     pub fn decay_all(&mut self, elapsed_ticks: u64) {
+        let (dead_zone, snap) = (self.config.decay_dead_zone, self.config.snap_to_floor);
+        for acc in self.accumulators.values_mut() {
+            acc.decay_with_dead_zone(elapsed_ticks, dead_zone, snap);
+        }
+    }
+
+    /// Decay all accumulators by the wall-clock time elapsed `since`, converted
+    /// to ticks at `ticks_per_sec` (rounded to the nearest tick).
+    ///
+    /// Saves callers from converting seconds to ticks by hand. Like
+    /// [`Self::decay_all`], this does not move [`Self::last_decay_tick`].
+    #[cfg(feature = "std")]
+    pub fn decay_elapsed(&mut self, since: std::time::Instant, ticks_per_sec: f32) {
+        self.decay_all(elapsed_ticks(since.elapsed(), ticks_per_sec));
+    }
+
+    /// Decay all accumulators by the ticks elapsed since the last tick-based
+    /// decay, up to `current_tick`.
+    pub fn decay_all_to(&mut self, current_tick: u64) {
+        let elapsed = current_tick.saturating_sub(self.last_decay_tick);
+        self.decay_all(elapsed);
+        self.last_decay_tick = self.last_decay_tick.max(current_tick);
+    }
+
+    /// Decay all accumulators up to `current_tick`, weighting each elapsed tick
+    /// by `schedule` (e.g. slower forgetting overnight).
+    ///
+    /// A uniform schedule reproduces [`Self::decay_all_to`].
+    pub fn decay_scheduled(&mut self, schedule: &DecaySchedule, current_tick: u64) {
+        let effective = schedule.effective_ticks(self.last_decay_tick, current_tick);
+        let (dead_zone, snap) = (self.config.decay_dead_zone, self.config.snap_to_floor);
         for acc in self.accumulators.values_mut() {
-            acc.decay(elapsed_ticks);
+            acc.decay_effective(effective, dead_zone, snap);
         }
+        self.last_decay_tick = self.last_decay_tick.max(current_tick);
+    }
+
+    /// Tick up to which [`Self::decay_all_to`] / [`Self::decay_scheduled`] have applied decay.
+    pub fn last_decay_tick(&self) -> u64 {
+        self.last_decay_tick
+    }
+
+    /// Set the tick from which the next tick-based decay is measured (e.g. after restore).
+    pub fn set_last_decay_tick(&mut self, tick: u64) {
+        self.last_decay_tick = tick;
     }
 
     // ── Collection helpers ─────────────────────────────────────────────────
@@ -249,6 +1140,130 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
         self.accumulators.iter()
     }
 
+    /// Iterate over all (context key, accumulator) pairs with mutable access,
+    /// for custom field-wide maintenance passes.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&ContextKey<V, N>, &mut CoherenceAccumulator)> {
+        self.accumulators.iter_mut()
+    }
+
+    /// Keep only the contexts for which `f` returns `true`, mirroring
+    /// `HashMap::retain`. `f` may also modify the accumulators it keeps.
+    ///
+    /// Removed contexts lose their history under [`HistoryPolicy::All`];
+    /// pinned histories persist until unpinned.
+    pub fn retain(&mut self, mut f: impl FnMut(&ContextKey<V, N>, &mut CoherenceAccumulator) -> bool) {
+        self.accumulators.retain(|k, acc| f(k, acc));
+        if self.config.history == HistoryPolicy::All {
+            let accumulators = &self.accumulators;
+            self.histories.retain(|k, _| accumulators.contains_key(k));
+        }
+    }
+
+    /// Explicitly forget one context, returning its accumulator if it was tracked.
+    ///
+    /// Its recorded history is dropped too; a pinned context stays pinned with
+    /// an empty history. The next interaction re-creates it at the cold-start baseline.
+    pub fn remove_context(&mut self, key: &ContextKey<V, N>) -> Option<CoherenceAccumulator> {
+        let removed = self.accumulators.remove(key)?;
+        match self.config.history {
+            HistoryPolicy::All => {
+                self.histories.remove(key);
+            }
+            _ => {
+                if let Some(h) = self.histories.get_mut(key) {
+                    h.clear();
+                }
+            }
+        }
+        Some(removed)
+    }
+
+    /// Forget every context, e.g. after the robot moves house.
+    ///
+    /// Keeps the personality baseline, fallback coherence, configuration and
+    /// history pins; only accumulated trust and recorded history are cleared.
+    pub fn forget_all(&mut self) {
+        self.accumulators.clear();
+        if self.config.history == HistoryPolicy::All {
+            self.histories.clear();
+        } else {
+            for h in self.histories.values_mut() {
+                h.clear();
+            }
+        }
+    }
+
+    /// Move a context's earned trust to a new key — the same place after its
+    /// sensor signature changed (e.g. furniture moved).
+    ///
+    /// The accumulator and any recorded history are transferred and `from` is
+    /// removed. If `to` already exists the two accumulators are combined with
+    /// [`CoherenceAccumulator::merged`] (lower trust, summed history). Returns
+    /// `false` (and does nothing) if `from` is unknown or equals `to`.
+    ///
+    /// Call [`MinCutBoundary::remap_context`](crate::boundary::MinCutBoundary::remap_context)
+    /// with the same keys to keep the boundary graph in step.
+    pub fn remap_context(&mut self, from: &ContextKey<V, N>, to: &ContextKey<V, N>) -> bool {
+        if from == to {
+            return false;
+        }
+        let Some(acc) = self.accumulators.remove(from) else {
+            return false;
+        };
+        let acc = match self.accumulators.get(to) {
+            Some(existing) => existing.merged(&acc),
+            None => acc,
+        };
+        self.accumulators.insert(to.clone(), acc);
+
+        if let Some(history) = self.histories.remove(from) {
+            match self.histories.get_mut(to) {
+                Some(existing) => existing.merge(&history),
+                None => {
+                    self.histories.insert(to.clone(), history);
+                }
+            }
+        }
+        true
+    }
+
+    /// Fold another field's trust into this one, e.g. to seed a new robot from
+    /// an experienced one.
+    ///
+    /// Contexts known to both are combined with [`CoherenceAccumulator::merged`]
+    /// — the honesty-preserving rule of `cardinality::merge_accumulators`
+    /// (lower coherence, summed interaction count, latest tick). Contexts only
+    /// in `other` are copied. Beyond [`MAX_CONTEXTS`] the least recently seen
+    /// contexts of the union are dropped, as with LRU eviction. Recorded
+    /// history is merged for contexts this field records.
+    pub fn merge(&mut self, other: &Self) {
+        for (key, theirs) in &other.accumulators {
+            if let Some(ours) = self.accumulators.get_mut(key) {
+                *ours = ours.merged(theirs);
+            } else {
+                if self.accumulators.len() >= MAX_CONTEXTS {
+                    let oldest = self.accumulators.values().map(|a| a.last_interaction_tick).min();
+                    if oldest.is_some_and(|t| theirs.last_interaction_tick <= t) {
+                        continue;
+                    }
+                    self.evict_oldest();
+                }
+                self.accumulators.insert(key.clone(), theirs.clone());
+            }
+
+            let Some(history) = other.histories.get(key) else { continue };
+            match self.config.history {
+                HistoryPolicy::Disabled => {}
+                HistoryPolicy::Pinned => {
+                    if let Some(h) = self.histories.get_mut(key) {
+                        h.merge(history);
+                    }
+                }
+                HistoryPolicy::All => self.histories.entry(key.clone()).or_default().merge(history),
+            }
+        }
+    }
+
     /// All tracked contexts with their coherence value and interaction count,
     /// sorted by interaction count descending.
     ///
@@ -261,10 +1276,157 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
             .iter()
             .map(|(k, acc)| (k.clone(), acc.value, acc.interaction_count))
             .collect();
-        entries.sort_by(|a, b| b.2.cmp(&a.2));
+        entries.sort_by_key(|e| core::cmp::Reverse(e.2));
         entries
     }
 
+    /// Contexts whose trust has fallen more than `min_drop` below its peak.
+    ///
+    /// Returns `(context_hash, peak_value − value)` sorted by drop descending,
+    /// then by hash. A context at its peak, or one that never rose above its
+    /// current value, reports no decline.
+    ///
+    /// Available only when the `std` feature is enabled (requires heap allocation).
+    #[cfg(feature = "std")]
+    pub fn declining_contexts(&self, min_drop: f32) -> std::vec::Vec<(u32, f32)> {
+        let mut out: std::vec::Vec<(u32, f32)> = self
+            .accumulators
+            .iter()
+            .filter_map(|(key, acc)| {
+                let drop = acc.peak_value - acc.value;
+                (drop > min_drop).then(|| (key.context_hash_u32(), drop))
+            })
+            .collect();
+        out.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        out
+    }
+
+    /// Geometric extent of the comfort zone around `center`.
+    ///
+    /// Maximum [`ContextKey::euclidean_distance`] from `center` to any familiar
+    /// context (coherence ≥ the gate's familiarity threshold). Returns 0.0 when fewer than two contexts are
+    /// familiar — a single trusted place has no extent.
+    pub fn comfort_radius(&self, center: &ContextKey<V, N>) -> f32 {
+        let mut familiar = 0usize;
+        let mut radius = 0.0_f32;
+        let threshold = self.config.gate.familiarity_threshold;
+        for (key, acc) in self.accumulators.iter() {
+            if acc.value >= threshold {
+                familiar += 1;
+                radius = radius.max(center.euclidean_distance(key));
+            }
+        }
+        if familiar < 2 { 0.0 } else { radius }
+    }
+
+    /// Whether `key` lies within [`Self::comfort_radius`] of `center`.
+    ///
+    /// Works for unseen keys too, interpolating comfort to nearby contexts.
+    pub fn is_within_comfort_radius(&self, key: &ContextKey<V, N>, center: &ContextKey<V, N>) -> bool {
+        center.euclidean_distance(key) <= self.comfort_radius(center)
+    }
+
+    /// Similarity-weighted *prediction* of how trusted an unseen context would be.
+    ///
+    /// Computes `Σ(simᵢ · valueᵢ) / Σ simᵢ` over known contexts whose
+    /// [`ContextKey::cosine_similarity`] to `key` exceeds `similarity_threshold`,
+    /// excluding `key` itself. Returns 0.0 when none qualify.
+    ///
+    /// **Non-authoritative.** This is a guess, not earned trust: it is never used
+    /// by [`Self::effective_coherence`], and the robot should not act trusting on
+    /// it. Use [`Self::context_coherence`] for the honest value.
+    pub fn predicted_coherence(&self, key: &ContextKey<V, N>, similarity_threshold: f32) -> f32 {
+        let mut weighted = 0.0_f32;
+        let mut total = 0.0_f32;
+        for (other, acc) in self.accumulators.iter() {
+            if other == key {
+                continue;
+            }
+            let sim = key.cosine_similarity(other);
+            if sim > similarity_threshold {
+                weighted += sim * acc.value;
+                total += sim;
+            }
+        }
+        if total > 0.0 { weighted / total } else { 0.0 }
+    }
+
+    /// How unlike everything known `key` is: `1 − max cosine similarity` to any
+    /// tracked context.
+    ///
+    /// 0.0 for a known context (it matches itself), 1.0 for a context
+    /// orthogonal to all known ones — and for an empty field, where everything
+    /// is novel. High novelty means [`Self::predicted_coherence`] is a weak guess.
+    pub fn novelty(&self, key: &ContextKey<V, N>) -> f32 {
+        let best = self
+            .accumulators
+            .keys()
+            .map(|other| key.cosine_similarity(other))
+            .fold(0.0_f32, f32::max);
+        1.0 - best
+    }
+
+    /// The known context most cosine-similar to `key`, as `(context, similarity,
+    /// coherence)`, if its similarity is at least `min_similarity`.
+    ///
+    /// `key` itself is excluded, so this answers "what familiar place does this
+    /// resemble?" even for a tracked context. Ties on similarity go to the
+    /// higher coherence. Read-only: no trust is transferred — warm-start
+    /// policies are left to the caller.
+    pub fn nearest_context(
+        &self,
+        key: &ContextKey<V, N>,
+        min_similarity: f32,
+    ) -> Option<(&ContextKey<V, N>, f32, f32)> {
+        self.accumulators
+            .iter()
+            .filter(|(other, _)| *other != key)
+            .map(|(other, acc)| (other, key.cosine_similarity(other), acc.value))
+            .filter(|&(_, sim, _)| sim >= min_similarity)
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+    }
+
+    /// Contexts on which this field and `other` disagree by more than `threshold`.
+    ///
+    /// Read-only comparison (not a merge). Returns `(context_hash, self_value,
+    /// other_value)`; a context tracked by only one field is reported with the
+    /// absent side as 0.0. Sorted by `|self_value − other_value|` descending,
+    /// then by hash, so `a.disagreement(b)` and `b.disagreement(a)` list the
+    /// same contexts in the same order.
+    ///
+    /// Available only when the `std` feature is enabled (requires heap allocation).
+    #[cfg(feature = "std")]
+    pub fn disagreement(
+        &self,
+        other: &CoherenceField<V, N>,
+        threshold: f32,
+    ) -> std::vec::Vec<(u32, f32, f32)> {
+        let mut out: std::vec::Vec<(u32, f32, f32)> = std::vec::Vec::new();
+        for (key, acc) in self.accumulators.iter() {
+            let theirs = other.accumulators.get(key).map_or(0.0, |a| a.value);
+            if (acc.value - theirs).abs() > threshold {
+                out.push((key.context_hash_u32(), acc.value, theirs));
+            }
+        }
+        for (key, acc) in other.accumulators.iter() {
+            if !self.accumulators.contains_key(key) && acc.value > threshold {
+                out.push((key.context_hash_u32(), 0.0, acc.value));
+            }
+        }
+        out.sort_by(|a, b| {
+            (b.1 - b.2)
+                .abs()
+                .partial_cmp(&(a.1 - a.2).abs())
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        out
+    }
+
     // ── Degraded-mode fallback ─────────────────────────────────────────────
 
     /// Set the fallback coherence returned for unseen contexts in degraded mode.
@@ -274,27 +1436,149 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
         self.fallback_coherence = value;
     }
 
+    // ── Restore ────────────────────────────────────────────────────────────
+
+    /// Insert (or replace) the accumulator for `key` verbatim.
+    ///
+    /// Used when restoring a persisted field. Evicts the oldest entry when the
+    /// field is at [`MAX_CONTEXTS`] capacity and `key` is new.
+    pub fn insert_accumulator(&mut self, key: ContextKey<V, N>, acc: CoherenceAccumulator) {
+        if !self.accumulators.contains_key(&key) && self.accumulators.len() >= MAX_CONTEXTS {
+            self.evict_oldest();
+        }
+        self.accumulators.insert(key, acc);
+    }
+
+    // ── Interaction history ────────────────────────────────────────────────
+
+    /// Recent interaction outcomes for `key`, oldest first.
+    ///
+    /// Empty unless history is enabled for this context via [`FieldConfig::history`].
+    pub fn context_history(&self, key: &ContextKey<V, N>) -> &[InteractionRecord] {
+        self.histories.get(key).map_or(&[], |h| h.as_slice())
+    }
+
+    /// Start recording history for `key` under [`HistoryPolicy::Pinned`].
+    pub fn pin_history(&mut self, key: &ContextKey<V, N>) {
+        self.histories.entry(key.clone()).or_default();
+    }
+
+    /// Stop recording history for `key` and discard what was recorded.
+    pub fn unpin_history(&mut self, key: &ContextKey<V, N>) {
+        self.histories.remove(key);
+    }
+
     // ── Internal helpers ───────────────────────────────────────────────────
 
-    /// Get or create the accumulator for `key`, initialising at the personality baseline.
+    /// Growth-rate multiplier for `key` under [`FieldConfig::similarity_boost`]
+    /// (1.0 when disabled or no similar context is known).
+    fn growth_boost(&self, key: &ContextKey<V, N>) -> f32 {
+        let Some(cfg) = self.config.similarity_boost else {
+            return 1.0;
+        };
+        let best = self
+            .accumulators
+            .iter()
+            .filter(|(other, _)| *other != key && key.cosine_similarity(other) > cfg.threshold)
+            .map(|(_, acc)| acc.value)
+            .fold(0.0_f32, f32::max);
+        1.0 + cfg.max_boost.max(0.0) * best.clamp(0.0, 1.0)
+    }
+
+    /// Run one interaction on `key`'s accumulator, then apply the slew limit
+    /// and record history according to the field configuration.
     ///
+    /// Returns the net change in coherence.
+    fn apply_interaction(
+        &mut self,
+        key: &ContextKey<V, N>,
+        tick: u64,
+        mut f: impl FnMut(&mut CoherenceAccumulator),
+    ) -> f32 {
+        self.apply_run(key, tick, &[tick], |acc, &t| {
+            f(acc);
+            t
+        })
+    }
+
+    /// Apply a run of interactions to one context with a single map lookup.
+    ///
+    /// `step` applies one item and returns its tick; `first_tick` (the tick of
+    /// the first item) stamps a newly created context. Each step is slew-limited
+    /// and recorded exactly as a separate [`Self::apply_interaction`] call would be.
+    /// Returns the net change in coherence over the whole run.
+    fn apply_run<T>(
+        &mut self,
+        key: &ContextKey<V, N>,
+        first_tick: u64,
+        items: &[T],
+        mut step: impl FnMut(&mut CoherenceAccumulator, &T) -> u64,
+    ) -> f32 {
+        if items.is_empty() {
+            return 0.0;
+        }
+        let max_delta = self.config.max_delta_per_tick;
+        let threshold = self.config.gate.familiarity_threshold;
+        self.get_or_create(key, first_tick);
+        let Some(acc) = self.accumulators.get_mut(key) else { return 0.0; };
+        let start = acc.value;
+        let mut history = match self.config.history {
+            HistoryPolicy::Disabled => None,
+            HistoryPolicy::Pinned => self.histories.get_mut(key),
+            HistoryPolicy::All => Some(self.histories.entry(key.clone()).or_default()),
+        };
+
+        for item in items {
+            let before = (acc.value, acc.peak_value);
+            let first_familiar_before = acc.first_familiar_tick;
+            let tick = step(acc, item);
+            slew_limit(acc, before, max_delta);
+            // A crossing undone by the slew limit is not a crossing.
+            if acc.value < threshold {
+                acc.first_familiar_tick = first_familiar_before;
+            }
+            if let Some(h) = history.as_deref_mut() {
+                let after = acc.value;
+                h.push(InteractionRecord { tick, delta: after - before.0, value: after });
+            }
+        }
+        acc.value - start
+    }
+
+    /// Get or create the accumulator for `key`, initialising it on the cold-start curve.
+    ///
+    /// A new accumulator is stamped with `tick` as its last interaction, so a
+    /// context created now is never mistaken for the least recently seen.
     /// Evicts the oldest entry when the field is at [`MAX_CONTEXTS`] capacity.
-    pub fn get_or_create(&mut self, key: &ContextKey<V, N>) -> &mut CoherenceAccumulator {
+    pub fn get_or_create(&mut self, key: &ContextKey<V, N>, tick: u64) -> &mut CoherenceAccumulator {
         if !self.accumulators.contains_key(key) {
             if self.accumulators.len() >= MAX_CONTEXTS {
                 self.evict_oldest();
             }
-            let curiosity = if self.personality_baseline > 0.0 {
-                (self.personality_baseline / 0.15).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
-            self.accumulators
-                .insert(key.clone(), CoherenceAccumulator::new_with_baseline(curiosity));
+            let mut acc = CoherenceAccumulator::cold_at(self.cold_start_value());
+            acc.floor_params = FloorParams::new(self.config.floor_params.asymptote, self.config.floor_params.half_count);
+            acc.dynamics = self.config.dynamics;
+            acc.last_interaction_tick = tick;
+            self.accumulators.insert(key.clone(), acc);
         }
         self.accumulators.get_mut(key).unwrap()
     }
 
+    /// Curiosity recovered from the personality baseline (0.15 × curiosity_drive).
+    fn curiosity(&self) -> f32 {
+        if self.personality_baseline > 0.0 {
+            (self.personality_baseline / BASELINE_PER_CURIOSITY).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Value a brand-new context would start at under the current personality,
+    /// kept below the gate's familiarity threshold.
+    fn cold_start_value(&self) -> f32 {
+        self.config.cold_start.baseline_below(self.curiosity(), self.config.gate.familiarity_threshold)
+    }
+
     fn evict_oldest(&mut self) {
         if let Some(oldest_key) = self
             .accumulators
@@ -303,10 +1587,20 @@ impl<V: SensorVocabulary<N>, const N: usize> CoherenceField<V, N> {
             .map(|(k, _)| k.clone())
         {
             self.accumulators.remove(&oldest_key);
+            if self.config.history == HistoryPolicy::All {
+                self.histories.remove(&oldest_key);
+            }
         }
     }
 }
 
+/// Whole ticks in `elapsed` at `ticks_per_sec`, rounded to nearest.
+/// Non-positive or NaN rates give zero.
+#[cfg(feature = "std")]
+fn elapsed_ticks(elapsed: std::time::Duration, ticks_per_sec: f32) -> u64 {
+    (elapsed.as_secs_f64() * f64::from(ticks_per_sec)).round() as u64
+}
+
 impl<V: SensorVocabulary<N>, const N: usize> Default for CoherenceField<V, N> {
     fn default() -> Self {
         Self::new()
@@ -319,6 +1613,9 @@ impl<V: SensorVocabulary<N>, const N: usize> core::fmt::Debug for CoherenceField
             .field("context_count", &self.accumulators.len())
             .field("personality_baseline", &self.personality_baseline)
             .field("fallback_coherence", &self.fallback_coherence)
+            .field("config", &self.config)
+            .field("history_count", &self.histories.len())
+            .field("last_decay_tick", &self.last_decay_tick)
             .finish()
     }
 }
@@ -359,6 +1656,23 @@ mod tests {
         make_key(BrightnessBand::Dark, NoiseBand::Loud, PresenceSignature::Close)
     }
 
+    /// Distinct key for each `i` in `0..81`.
+    fn nth_key(i: usize) -> ContextKey<MbotSensors, 6> {
+        let three = |n: usize| n % 3;
+        ContextKey::new(MbotSensors {
+            brightness: [BrightnessBand::Dark, BrightnessBand::Dim, BrightnessBand::Bright][three(i)],
+            noise: [NoiseBand::Quiet, NoiseBand::Moderate, NoiseBand::Loud][three(i / 3)],
+            presence: [PresenceSignature::Absent, PresenceSignature::Far, PresenceSignature::Close][three(i / 9)],
+            motion: [MotionContext::Static, MotionContext::Slow, MotionContext::Fast][three(i / 27)],
+            orientation: Orientation::Upright,
+            time_period: TimePeriod::Day,
+        })
+    }
+
+    fn acc_at(value: f32) -> CoherenceAccumulator {
+        CoherenceAccumulator { value, peak_value: value, ..CoherenceAccumulator::new() }
+    }
+
     fn neutral_personality() -> Personality {
         Personality {
             curiosity_drive: 0.5,
@@ -443,6 +1757,44 @@ mod tests {
         assert!(acc.value < before);
     }
 
+    #[test]
+    fn test_negative_interaction_reports_actual_drop() {
+        let mut acc = acc_at(0.8);
+        let dropped = acc.negative_interaction(0.5, 1);
+        assert!((dropped - (0.8 - acc.value)).abs() < 1e-6);
+        assert!(dropped > 0.0);
+
+        // Fully protected: already at the earned floor, nothing is lost.
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        for tick in 0..100 {
+            field.positive_interaction(&key, &p, tick, false);
+        }
+        let acc = field.accumulators.get_mut(&key).unwrap();
+        acc.value = acc.earned_floor();
+        assert_eq!(field.negative_interaction_drop(&key, &p, 100), 0.0);
+        assert_eq!(field.accumulators.get_mut(&key).unwrap().negative_interaction(1.0, 101), 0.0);
+    }
+
+    #[test]
+    fn test_trend_follows_recent_interactions_not_value() {
+        let mut acc = CoherenceAccumulator::new();
+        assert_eq!(acc.trend(), Trend::Stable);
+        for i in 0..100 {
+            acc.positive_interaction(0.5, i, false);
+        }
+        assert_eq!(acc.trend(), Trend::Rising);
+
+        // A short burst of mild startles.
+        for i in 100..108 {
+            acc.negative_interaction(0.0, i);
+        }
+        assert_eq!(acc.trend(), Trend::Falling);
+        assert!(acc.value > acc.earned_floor(), "value={} floor={}", acc.value, acc.earned_floor());
+        assert!(acc.value > FAMILIARITY_THRESHOLD);
+    }
+
     #[test]
     fn test_accumulator_decay_toward_floor() {
         let mut acc = CoherenceAccumulator::new();
@@ -461,6 +1813,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decay_dead_zone_rests_and_resumes() {
+        let mut acc = acc_at(0.6);
+        acc.interaction_count = 20;
+        let floor = acc.earned_floor();
+
+        acc.value = floor + 0.5 * DECAY_DEAD_ZONE;
+        assert!(!acc.decay_with_dead_zone(1, DECAY_DEAD_ZONE, false));
+        assert_eq!(acc.value, floor + 0.5 * DECAY_DEAD_ZONE);
+        assert!(acc.decay_with_dead_zone(1, DECAY_DEAD_ZONE, true));
+        assert_eq!(acc.value, floor);
+        assert!(!acc.decay_with_dead_zone(1, DECAY_DEAD_ZONE, true));
+
+        // Recovering above the dead-zone resumes normal decay.
+        acc.value = floor + 0.1;
+        assert!(acc.decay_with_dead_zone(1, DECAY_DEAD_ZONE, false));
+        assert!(acc.value < floor + 0.1);
+    }
+
     #[test]
     fn test_cold_start_baseline() {
         let acc = CoherenceAccumulator::new_with_baseline(1.0);
@@ -473,6 +1844,46 @@ mod tests {
         assert_eq!(acc.value, 0.0);
     }
 
+    #[test]
+    fn test_cold_start_curve_default_matches_linear() {
+        let curve = ColdStartCurve::default();
+        for &c in &[0.0_f32, 0.2, 0.5, 1.0] {
+            let expected = CoherenceAccumulator::new_with_baseline(c).value;
+            assert_eq!(curve.baseline(c), expected, "curiosity={}", c);
+        }
+    }
+
+    #[test]
+    fn test_cold_start_curve_non_linear() {
+        // Quadratic, bolder ceiling: bold robots warm, timid ones essentially cold.
+        let curve = ColdStartCurve::new(0.25, 2);
+        assert!((curve.baseline(1.0) - 0.25).abs() < 1e-6);
+        assert!(curve.baseline(0.2) < 0.011, "timid={}", curve.baseline(0.2));
+        assert!(curve.baseline(0.9) > ColdStartCurve::default().baseline(0.9));
+    }
+
+    #[test]
+    fn test_cold_start_curve_below_familiarity_threshold() {
+        let curve = ColdStartCurve::linear(5.0);
+        let b = curve.baseline(1.0);
+        assert!((0.0..FAMILIARITY_THRESHOLD).contains(&b), "baseline={}", b);
+        assert_eq!(ColdStartCurve::linear(-1.0).baseline(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_peak_value_tracks_maximum() {
+        let mut acc = CoherenceAccumulator::new_with_baseline(1.0);
+        assert_eq!(acc.peak_value, acc.value);
+        for i in 0..30 {
+            acc.positive_interaction(0.5, i, false);
+        }
+        let peak = acc.value;
+        acc.negative_interaction(1.0, 31);
+        acc.decay(500);
+        assert_eq!(acc.peak_value, peak);
+        assert!(acc.value < acc.peak_value);
+    }
+
     #[test]
     fn test_alone_boost() {
         let mut alone_acc = CoherenceAccumulator::new();
@@ -521,7 +1932,7 @@ mod tests {
 
         // Build a little coherence (stay under 0.3 threshold)
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             for i in 0..10 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -550,7 +1961,7 @@ mod tests {
 
         // Build up enough coherence to cross 0.3 threshold
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             for i in 0..80 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -595,7 +2006,7 @@ mod tests {
         let key_b = dark_loud_close();
 
         {
-            let acc = field.get_or_create(&key_a);
+            let acc = field.get_or_create(&key_a, 0);
             for i in 0..50 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -646,19 +2057,351 @@ mod tests {
                 BrightnessBand::Dark
             };
             let key = make_key(brightness, noise, presence);
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             acc.last_interaction_tick = i as u64;
         }
         assert!(field.context_count() <= MAX_CONTEXTS);
     }
 
+    #[test]
+    fn test_coherence_field_uses_cold_start_curve() {
+        let config = FieldConfig { cold_start: ColdStartCurve::new(0.25, 2), ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        field.set_personality_baseline(0.15); // curiosity = 1.0
+        let key = bright_quiet_static();
+        let value = field.get_or_create(&key, 0).value;
+        assert!((value - 0.25).abs() < 1e-6, "value={}", value);
+        assert!(field.effective_coherence(1.0, &key) < FAMILIARITY_THRESHOLD);
+    }
+
+    #[test]
+    fn test_update_personality_preserves_existing_trust() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let earned = bright_quiet_static();
+        let p = neutral_personality();
+        for tick in 0..40 {
+            field.positive_interaction(&earned, &p, tick, false);
+        }
+        let before = field.context_coherence(&earned);
+
+        let bold = Personality { curiosity_drive: 1.0, ..neutral_personality() };
+        field.update_personality(&bold);
+        assert_eq!(field.context_coherence(&earned), before);
+
+        // Future cold starts use the new curiosity.
+        let fresh = dark_loud_close();
+        assert!((field.get_or_create(&fresh, 0).value - 0.15).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rebaseline_unfamiliar_lifts_only_cold_contexts() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let earned = bright_quiet_static();
+        let cold = dark_loud_close();
+        let p = neutral_personality();
+        field.positive_interaction(&earned, &p, 0, false);
+        field.get_or_create(&cold, 0);
+        let earned_before = field.context_coherence(&earned);
+
+        let bold = Personality { curiosity_drive: 1.0, ..neutral_personality() };
+        assert_eq!(field.rebaseline_unfamiliar(&bold), 1);
+        assert!((field.context_coherence(&cold) - 0.15).abs() < 1e-6);
+        assert_eq!(field.context_coherence(&earned), earned_before);
+
+        // A timid personality never pulls a context down.
+        let timid = Personality { curiosity_drive: 0.0, ..neutral_personality() };
+        assert_eq!(field.rebaseline_unfamiliar(&timid), 0);
+        assert!((field.context_coherence(&cold) - 0.15).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rebaseline_unfamiliar_keeps_startled_context() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let p = neutral_personality();
+        field.update_personality(&Personality { curiosity_drive: 0.6, ..p.clone() });
+        let (startled, cold) = (bright_quiet_static(), dark_loud_close());
+        field.get_or_create(&cold, 0);
+        // No positive interactions, but a startle has earned distrust.
+        field.negative_interaction(&startled, &p, 1);
+        let distrust = field.context_coherence(&startled);
+        assert!(distrust < field.context_coherence(&cold));
+
+        let bold = Personality { curiosity_drive: 1.0, ..p };
+        assert_eq!(field.rebaseline_unfamiliar(&bold), 1);
+        assert_eq!(field.context_coherence(&startled), distrust);
+        assert!((field.context_coherence(&cold) - 0.15).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_elapsed_ticks_rounds_to_nearest() {
+        use std::time::Duration;
+        assert_eq!(elapsed_ticks(Duration::from_secs(2), 10.0), 20);
+        assert_eq!(elapsed_ticks(Duration::from_millis(1_249), 10.0), 12);
+        assert_eq!(elapsed_ticks(Duration::from_millis(1_250), 10.0), 13);
+        assert_eq!(elapsed_ticks(Duration::from_millis(500), 0.5), 0);
+        assert_eq!(elapsed_ticks(Duration::from_secs(90), 1.0 / 60.0), 2);
+        assert_eq!(elapsed_ticks(Duration::from_secs(5), -1.0), 0);
+        assert_eq!(elapsed_ticks(Duration::from_secs(5), f32::NAN), 0);
+
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.get_or_create(&bright_quiet_static(), 0).value = 0.5;
+        field.decay_elapsed(std::time::Instant::now(), 0.0);
+        assert_eq!(field.context_coherence(&bright_quiet_static()), 0.5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_disagreement_symmetric_and_sorted() {
+        let mut a: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut b: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let shared = bright_quiet_static();
+        let only_a = dark_loud_close();
+        let agreed = make_key(BrightnessBand::Dim, NoiseBand::Moderate, PresenceSignature::Far);
+        a.get_or_create(&shared, 0).value = 0.8;
+        b.get_or_create(&shared, 0).value = 0.2;
+        a.get_or_create(&only_a, 0).value = 0.4;
+        a.get_or_create(&agreed, 0).value = 0.5;
+        b.get_or_create(&agreed, 0).value = 0.45;
+
+        let ab = a.disagreement(&b, 0.1);
+        let ba = b.disagreement(&a, 0.1);
+        assert_eq!(ab.len(), 2);
+        assert_eq!(ab[0], (shared.context_hash_u32(), 0.8, 0.2));
+        assert_eq!(ab[1], (only_a.context_hash_u32(), 0.4, 0.0));
+        let ab_hashes: std::vec::Vec<u32> = ab.iter().map(|e| e.0).collect();
+        let ba_hashes: std::vec::Vec<u32> = ba.iter().map(|e| e.0).collect();
+        assert_eq!(ab_hashes, ba_hashes);
+        assert_eq!(ba[1], (only_a.context_hash_u32(), 0.0, 0.4));
+    }
+
+    #[test]
+    fn test_signed_interaction_matches_binary_api() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut graded: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut binary: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        for tick in 0..30 {
+            graded.interaction(&key, &p, 1.0, tick, false);
+            binary.positive_interaction(&key, &p, tick, false);
+        }
+        assert_eq!(graded.context_coherence(&key), binary.context_coherence(&key));
+
+        graded.interaction(&key, &p, -1.0, 30, false);
+        binary.negative_interaction(&key, &p, 30);
+        assert_eq!(graded.context_coherence(&key), binary.context_coherence(&key));
+    }
+
+    #[test]
+    fn test_signed_interaction_proportional_and_touch() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.interaction(&key, &p, 0.5, 0, false);
+        let half = field.context_coherence(&key);
+        let mut full = CoherenceAccumulator::new();
+        full.positive_interaction(0.5, 0, false);
+        assert!((half - 0.5 * full.value).abs() < 1e-6, "half={}", half);
+
+        let before = field.context_coherence(&key);
+        field.interaction(&key, &p, 0.0, 7, false);
+        assert_eq!(field.context_coherence(&key), before);
+        assert_eq!(field.context_interaction_count(&key), 1);
+        assert_eq!(field.get_or_create(&key, 0).last_interaction_tick, 7);
+    }
+
+    #[test]
+    fn test_comfort_radius() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let center = bright_quiet_static();
+        let near = make_key(BrightnessBand::Bright, NoiseBand::Moderate, PresenceSignature::Absent);
+        let far = dark_loud_close();
+
+        assert_eq!(field.comfort_radius(&center), 0.0);
+        field.get_or_create(&center, 0).value = 0.8;
+        assert_eq!(field.comfort_radius(&center), 0.0, "single familiar context");
+
+        // Unfamiliar contexts never extend the radius.
+        field.get_or_create(&far, 0).value = 0.2;
+        assert_eq!(field.comfort_radius(&center), 0.0);
+
+        field.get_or_create(&near, 0).value = 0.5;
+        let r = field.comfort_radius(&center);
+        assert!((r - center.euclidean_distance(&near)).abs() < 1e-6, "r={}", r);
+        assert!(field.is_within_comfort_radius(&near, &center));
+        assert!(!field.is_within_comfort_radius(&far, &center));
+    }
+
+    #[test]
+    fn test_max_delta_per_tick_limits_both_directions() {
+        let config = FieldConfig { max_delta_per_tick: Some(0.005), ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let key = bright_quiet_static();
+        let bold = Personality { recovery_speed: 1.0, startle_sensitivity: 1.0, ..neutral_personality() };
+
+        field.positive_interaction(&key, &bold, 0, true);
+        assert!((field.context_coherence(&key) - 0.005).abs() < 1e-6);
+
+        field.get_or_create(&key, 0).value = 0.6;
+        field.negative_interaction(&key, &bold, 1);
+        assert!((field.context_coherence(&key) - 0.595).abs() < 1e-6);
+
+        // Composes with graded interactions.
+        field.interaction(&key, &bold, -0.5, 2, false);
+        assert!((field.context_coherence(&key) - 0.590).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_max_delta_per_tick_respects_earned_floor() {
+        let config = FieldConfig { max_delta_per_tick: Some(0.5), ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let key = bright_quiet_static();
+        let p = neutral_personality();
+        for tick in 0..100 {
+            field.positive_interaction(&key, &p, tick, false);
+        }
+        let floor = field.get_or_create(&key, 0).earned_floor();
+        for tick in 100..200 {
+            field.negative_interaction(&key, &p, tick);
+        }
+        assert!(field.context_coherence(&key) >= floor);
+
+        // Unlimited by default.
+        assert_eq!(FieldConfig::default().max_delta_per_tick, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_declining_contexts() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let p = neutral_personality();
+        let eroding = bright_quiet_static();
+        let steady = dark_loud_close();
+        let never_rose = make_key(BrightnessBand::Dim, NoiseBand::Quiet, PresenceSignature::Far);
+        for tick in 0..60 {
+            field.positive_interaction(&eroding, &p, tick, false);
+            field.positive_interaction(&steady, &p, tick, false);
+        }
+        for tick in 60..65 {
+            field.negative_interaction(&eroding, &p, tick);
+        }
+        field.negative_interaction(&never_rose, &p, 65);
+
+        let declining = field.declining_contexts(0.05);
+        assert_eq!(declining.len(), 1);
+        assert_eq!(declining[0].0, eroding.context_hash_u32());
+        assert!(declining[0].1 > 0.05);
+        assert!(field.declining_contexts(0.0).iter().all(|e| e.0 != steady.context_hash_u32()));
+    }
+
+    #[test]
+    fn test_max_negative_delta_caps_every_startle() {
+        let jumpy = Personality { startle_sensitivity: 1.0, ..Personality::new() };
+        let key = bright_quiet_static();
+        let config = FieldConfig { max_negative_delta: 0.01, ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        field.insert_accumulator(key.clone(), acc_at(0.9));
+        for tick in 1..6 {
+            let before = field.context_coherence(&key);
+            field.negative_interaction(&key, &jumpy, tick);
+            let mid = field.context_coherence(&key);
+            field.interaction(&key, &jumpy, -1.0, tick, false);
+            let after = field.context_coherence(&key);
+            assert!(before - mid <= 0.01 + 1e-6, "startle dropped {}", before - mid);
+            assert!(mid - after <= 0.01 + 1e-6, "valence dropped {}", mid - after);
+            assert!(after < before);
+        }
+    }
+
+    #[test]
+    fn test_default_negative_delta_clamp_is_unchanged() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut clamped: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut acc = acc_at(0.8);
+        clamped.insert_accumulator(key.clone(), acc.clone());
+        clamped.negative_interaction(&key, &p, 1);
+        acc.negative_interaction(p.startle_sensitivity, 1);
+        assert_eq!(clamped.context_coherence(&key), acc.value);
+        assert!(acc.value < 0.8);
+    }
+
+    #[test]
+    fn test_predicted_coherence_weights_similar_contexts() {
+        let near = bright_quiet_static();
+        let far = dark_loud_close();
+        let unseen = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(field.predicted_coherence(&unseen, 0.0), 0.0);
+
+        field.insert_accumulator(near.clone(), acc_at(0.8));
+        field.insert_accumulator(far.clone(), acc_at(0.2));
+        let sim_near = unseen.cosine_similarity(&near);
+        let sim_far = unseen.cosine_similarity(&far);
+        let expected = (sim_near * 0.8 + sim_far * 0.2) / (sim_near + sim_far);
+        assert!((field.predicted_coherence(&unseen, 0.0) - expected).abs() < 1e-6);
+        assert!((field.predicted_coherence(&unseen, sim_far) - 0.8).abs() < 1e-6);
+        assert_eq!(field.predicted_coherence(&unseen, 1.0), 0.0);
+
+        // Prediction never leaks into the honest value or the gate.
+        assert_eq!(field.context_coherence(&unseen), 0.0);
+        // The context itself is excluded even when known.
+        field.insert_accumulator(unseen.clone(), acc_at(0.0));
+        assert!((field.predicted_coherence(&unseen, 0.0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_history_is_pure_observation() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let config = FieldConfig { history: HistoryPolicy::All, ..FieldConfig::default() };
+        let mut recorded: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let mut plain: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        for tick in 0..40 {
+            recorded.positive_interaction(&key, &p, tick, false);
+            plain.positive_interaction(&key, &p, tick, false);
+        }
+        recorded.negative_interaction(&key, &p, 40);
+        plain.negative_interaction(&key, &p, 40);
+        assert_eq!(recorded.context_coherence(&key), plain.context_coherence(&key));
+        assert!(plain.context_history(&key).is_empty());
+
+        let h = recorded.context_history(&key);
+        assert_eq!(h.len(), HISTORY_CAPACITY);
+        let last = h[h.len() - 1];
+        assert_eq!(last.tick, 40);
+        assert!(last.delta < 0.0);
+        assert_eq!(last.value, recorded.context_coherence(&key));
+        assert_eq!(h[0].tick, 41 - HISTORY_CAPACITY as u64);
+    }
+
+    #[test]
+    fn test_history_pinned_only() {
+        let p = neutral_personality();
+        let pinned = bright_quiet_static();
+        let other = dark_loud_close();
+        let config = FieldConfig { history: HistoryPolicy::Pinned, ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        field.pin_history(&pinned);
+        for tick in 0..3 {
+            field.positive_interaction(&pinned, &p, tick, false);
+            field.positive_interaction(&other, &p, tick, false);
+        }
+        assert_eq!(field.context_history(&pinned).len(), 3);
+        assert!(field.context_history(&other).is_empty());
+
+        field.unpin_history(&pinned);
+        field.positive_interaction(&pinned, &p, 3, false);
+        assert!(field.context_history(&pinned).is_empty());
+    }
+
     #[test]
     fn test_coherence_field_decay_all() {
         let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
         let key = bright_quiet_static();
 
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             for i in 0..50 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -671,6 +2414,557 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decay_scheduled_uniform_matches_decay_all_to() {
+        let key = bright_quiet_static();
+        let make = || {
+            let mut f: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+            f.insert_accumulator(key.clone(), acc_at(0.8));
+            f
+        };
+        let (mut a, mut b, mut night) = (make(), make(), make());
+        let half = DecaySchedule::new(1_000).with_segment(0, 0.5).unwrap();
+
+        for &t in &[300u64, 700, 2_500] {
+            a.decay_all_to(t);
+            b.decay_scheduled(&DecaySchedule::uniform(), t);
+            night.decay_scheduled(&half, t);
+        }
+        assert_eq!(a.context_coherence(&key), b.context_coherence(&key));
+        assert_eq!(a.last_decay_tick(), 2_500);
+        assert!(night.context_coherence(&key) > a.context_coherence(&key));
+    }
+
+    #[test]
+    fn test_earned_coherence_excludes_baseline() {
+        let bold = Personality { curiosity_drive: 1.0, ..Personality::new() };
+        let key = bright_quiet_static();
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.update_personality(&bold);
+        field.get_or_create(&key, 0);
+        assert!(field.context_coherence(&key) > 0.0);
+        assert_eq!(field.context_earned_coherence(&key), 0.0);
+
+        for tick in 0..10 {
+            field.positive_interaction(&key, &bold, tick, false);
+        }
+        let earned = field.context_earned_coherence(&key);
+        assert!((earned - (field.context_coherence(&key) - 0.15)).abs() < 1e-6);
+        assert_eq!(field.context_earned_coherence(&dark_loud_close()), 0.0);
+    }
+
+    #[test]
+    fn test_gate_config_custom_arms() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let key = bright_quiet_static();
+        field.insert_accumulator(key.clone(), acc_at(0.4));
+        // Default gate: 0.4 is familiar.
+        assert!((field.effective_coherence(0.1, &key) - (0.3 * 0.1 + 0.7 * 0.4)).abs() < 1e-6);
+
+        field.set_gate_config(GateConfig::new(0.45, 0.2));
+        assert_eq!(field.config().gate, GateConfig { familiarity_threshold: 0.45, instant_weight: 0.2 });
+        // Unfamiliar arm under the raised threshold: min(instant, ctx).
+        assert_eq!(field.effective_coherence(0.9, &key), 0.4);
+        assert_eq!(field.effective_coherence(0.1, &key), 0.1);
+
+        // Familiar arm: 0.2 × instant + 0.8 × ctx.
+        field.insert_accumulator(key.clone(), acc_at(0.6));
+        assert!((field.effective_coherence(0.1, &key) - (0.2 * 0.1 + 0.8 * 0.6)).abs() < 1e-6);
+
+        let clamped = GateConfig::new(2.0, -1.0);
+        assert_eq!((clamped.familiarity_threshold, clamped.instant_weight), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_out_of_range_personality_is_bounded_in_field() {
+        let wild = Personality { curiosity_drive: 0.5, startle_sensitivity: 5.0, recovery_speed: -3.0 };
+        let edge = Personality { curiosity_drive: 0.5, startle_sensitivity: 1.0, recovery_speed: 0.0 };
+        let key = bright_quiet_static();
+        let run = |p: &Personality| {
+            let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+            field.insert_accumulator(key.clone(), acc_at(0.5));
+            field.positive_interaction(&key, p, 1, false);
+            let grown = field.context_coherence(&key);
+            let lost = field.negative_interaction_drop(&key, p, 2);
+            (grown, lost)
+        };
+
+        let (grown, lost) = run(&wild);
+        assert!(grown > 0.5, "negative recovery_speed must not shrink coherence: {grown}");
+        // Identical to the in-range extremes the fields clamp to.
+        assert_eq!((grown, lost), run(&edge));
+        assert!(lost <= NEGATIVE_DELTA_BASE * 1.5 + 1e-6, "lost={lost}");
+    }
+
+    #[test]
+    fn test_custom_gate_threshold_agrees_across_familiarity_checks() {
+        let config = FieldConfig { gate: GateConfig::new(0.45, 0.3), ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let p = Personality::new();
+        let (a, b) = (bright_quiet_static(), dark_loud_close());
+        let familiar_tick = |field: &CoherenceField<MbotSensors, 6>, k| field.accumulators[k].first_familiar_tick;
+
+        // Past the default 0.3 but short of the configured 0.45: still unfamiliar everywhere.
+        let mut tick = 0;
+        while field.context_coherence(&b) < 0.35 {
+            field.positive_interaction(&a, &p, tick, false);
+            field.positive_interaction(&b, &p, tick, false);
+            tick += 1;
+        }
+        for k in [&a, &b] {
+            assert!(field.context_coherence(k) < 0.45);
+            assert_eq!(familiar_tick(&field, k), None);
+            assert_eq!(field.effective_coherence(1.0, k), field.context_coherence(k), "unfamiliar arm");
+        }
+        assert_eq!(field.comfort_radius(&a), 0.0);
+
+        // Crossing 0.45 stamps the tick and the gate switches arms together.
+        while field.context_coherence(&b) < 0.45 {
+            field.positive_interaction(&a, &p, tick, false);
+            field.positive_interaction(&b, &p, tick, false);
+            tick += 1;
+        }
+        assert_eq!(familiar_tick(&field, &b), Some(tick - 1));
+        assert!(field.effective_coherence(1.0, &b) > field.context_coherence(&b), "familiar arm");
+        assert!(field.comfort_radius(&a) > 0.0);
+
+        // A threshold below the cold-start curve still leaves new contexts unfamiliar.
+        let low = FieldConfig {
+            gate: GateConfig::new(0.1, 0.3),
+            cold_start: ColdStartCurve::linear(0.25),
+            ..FieldConfig::default()
+        };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(low);
+        field.update_personality(&Personality { curiosity_drive: 1.0, ..Personality::new() });
+        let start = field.get_or_create(&a, 0).value;
+        assert!(start > 0.0 && start < 0.1, "start={start}");
+        assert_eq!(field.effective_coherence(1.0, &a), start);
+    }
+
+    #[test]
+    fn test_dynamics_default_matches_constants() {
+        let mut a = CoherenceAccumulator::new();
+        let mut b = CoherenceAccumulator::with_dynamics(CoherenceDynamics::default());
+        a.positive_interaction(0.5, 1, false);
+        b.positive_interaction(0.5, 1, false);
+        assert_eq!(a, b);
+        assert!((a.value - POSITIVE_DELTA_BASE).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_doubled_positive_base_halves_ticks_to_target() {
+        fn ticks_to(base: f32, target: f32) -> u32 {
+            let dynamics = CoherenceDynamics { positive_delta_base: base, ..CoherenceDynamics::default() };
+            let mut acc = CoherenceAccumulator::with_dynamics(dynamics);
+            let mut ticks = 0;
+            while acc.value < target {
+                acc.positive_interaction(0.5, ticks as u64, false);
+                ticks += 1;
+            }
+            ticks
+        }
+        // 1 − 0.98ⁿ ≥ 0.3 at n = 18; 1 − 0.96ⁿ ≥ 0.3 at n = 9.
+        assert_eq!(ticks_to(0.02, 0.3), 18);
+        assert_eq!(ticks_to(0.04, 0.3), 9);
+
+        let fast = CoherenceDynamics { negative_delta_base: 0.1, decay_per_tick: 0.001, ..CoherenceDynamics::default() };
+        let mut acc = CoherenceAccumulator { value: 0.5, ..CoherenceAccumulator::with_dynamics(fast) };
+        acc.negative_interaction(0.5, 0);
+        assert!((acc.value - 0.4).abs() < 1e-6);
+        acc.decay(100);
+        assert!((acc.value - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_floor_params_default_matches_original_curve() {
+        let acc = CoherenceAccumulator { interaction_count: 20, ..CoherenceAccumulator::new() };
+        assert!((acc.earned_floor() - 0.25).abs() < 1e-6);
+        assert_eq!(CoherenceAccumulator::new().floor_params, FloorParams::default());
+        assert_eq!(
+            CoherenceAccumulator::with_floor_params(FloorParams::default()),
+            CoherenceAccumulator::new()
+        );
+    }
+
+    #[test]
+    fn test_floor_params_converge_to_configured_asymptote() {
+        let params = FloorParams::new(0.6, 10.0);
+        let mut acc = CoherenceAccumulator::with_floor_params(params);
+        acc.interaction_count = 10;
+        assert!((acc.earned_floor() - 0.3).abs() < 1e-6, "half the asymptote at half_count");
+        acc.interaction_count = 100_000;
+        let floor = acc.earned_floor();
+        assert!(floor > 0.59 && floor < 0.6, "floor={}", floor);
+        assert_eq!(params.interactions_for(0.3), Some(10));
+        assert_eq!(params.interactions_for(0.6), None);
+
+        // Construction clamps the asymptote below 1.0.
+        let wild = CoherenceAccumulator::with_floor_params(FloorParams { asymptote: 3.0, half_count: -1.0 });
+        assert!(wild.floor_params.asymptote < 1.0);
+        assert!(wild.floor_params.half_count > 0.0);
+
+        // Fields hand their configured floor to new contexts.
+        let config = FieldConfig { floor_params: params, ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let key = bright_quiet_static();
+        assert_eq!(field.get_or_create(&key, 0).floor_params, params);
+        assert_eq!(field.interactions_until_resilient(&key, 0.3), Some(10));
+    }
+
+    #[test]
+    fn test_interactions_for_resilient_floor_inverts_curve() {
+        assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(0.3), Some(30));
+        assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(0.25), Some(20));
+        assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(0.0), Some(0));
+        assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(0.5), None);
+        assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(0.9), None);
+
+        // Matches the empirical count from repeated interactions.
+        for &target in &[0.1_f32, 0.3, 0.42, 0.49] {
+            let mut acc = CoherenceAccumulator::new();
+            let mut n = 0;
+            while acc.earned_floor() < target {
+                acc.positive_interaction(0.5, n as u64, false);
+                n += 1;
+            }
+            assert_eq!(CoherenceAccumulator::interactions_for_resilient_floor(target), Some(n), "target={}", target);
+        }
+    }
+
+    #[test]
+    fn test_interactions_until_resilient_counts_remaining() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(field.interactions_until_resilient(&key, 0.3), Some(30));
+        for tick in 0..12 {
+            field.positive_interaction(&key, &p, tick, false);
+        }
+        assert_eq!(field.interactions_until_resilient(&key, 0.3), Some(18));
+        assert_eq!(field.interactions_until_resilient(&key, 0.1), Some(0));
+        assert_eq!(field.interactions_until_resilient(&key, 0.5), None);
+    }
+
+    #[test]
+    fn test_first_familiar_tick_set_once() {
+        let p = neutral_personality();
+        let key = bright_quiet_static();
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.positive_interaction(&key, &p, 0, false);
+        assert_eq!(field.context_first_familiar_tick(&key), None);
+        assert_eq!(field.context_first_familiar_tick(&dark_loud_close()), None);
+
+        let mut tick = 1;
+        while field.context_coherence(&key) < FAMILIARITY_THRESHOLD {
+            field.positive_interaction(&key, &p, tick, false);
+            tick += 1;
+        }
+        let crossed = tick - 1;
+        assert_eq!(field.context_first_familiar_tick(&key), Some(crossed));
+
+        // Dip below and re-cross: the milestone is not reset.
+        while field.context_coherence(&key) >= FAMILIARITY_THRESHOLD {
+            field.negative_interaction(&key, &p, tick);
+            tick += 1;
+        }
+        while field.context_coherence(&key) < FAMILIARITY_THRESHOLD {
+            field.positive_interaction(&key, &p, tick, false);
+            tick += 1;
+        }
+        assert_eq!(field.context_first_familiar_tick(&key), Some(crossed));
+    }
+
+    #[test]
+    fn test_similarity_boost_speeds_growth_not_start() {
+        let p = neutral_personality();
+        let known = bright_quiet_static();
+        let similar = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        let boost = SimilarityBoost { threshold: 0.5, max_boost: 1.0 };
+        let config = FieldConfig { similarity_boost: Some(boost), ..FieldConfig::default() };
+        let mut boosted: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config.clone());
+        let mut plain: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+
+        // No similar context known: identical to an unboosted field.
+        boosted.positive_interaction(&similar, &p, 0, false);
+        plain.positive_interaction(&similar, &p, 0, false);
+        assert_eq!(boosted.context_coherence(&similar), plain.context_coherence(&similar));
+
+        let mut boosted: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        boosted.insert_accumulator(known.clone(), acc_at(0.8));
+        plain.insert_accumulator(known, acc_at(0.8));
+        let fresh = make_key(BrightnessBand::Bright, NoiseBand::Moderate, PresenceSignature::Absent);
+        assert_eq!(boosted.get_or_create(&fresh, 0).value, plain.get_or_create(&fresh, 0).value);
+        boosted.positive_interaction(&fresh, &p, 1, false);
+        plain.positive_interaction(&fresh, &p, 1, false);
+        let (b, q) = (boosted.context_coherence(&fresh), plain.context_coherence(&fresh));
+        // Capped at 1 + max_boost × 0.8 times the normal delta.
+        assert!((b - 1.8 * q).abs() < 1e-6, "boosted={} plain={}", b, q);
+    }
+
+    #[test]
+    fn test_iter_mut_and_retain() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.insert_accumulator(bright_quiet_static(), acc_at(0.6));
+        field.insert_accumulator(dark_loud_close(), acc_at(0.1));
+        for (_, acc) in field.iter_mut() {
+            acc.value *= 0.5;
+        }
+        assert!((field.context_coherence(&bright_quiet_static()) - 0.3).abs() < 1e-6);
+
+        field.retain(|_, acc| acc.value >= 0.1);
+        assert_eq!(field.context_count(), 1);
+        assert_eq!(field.context_coherence(&dark_loud_close()), 0.0);
+    }
+
+    #[test]
+    fn test_merge_fields_overlapping_and_disjoint() {
+        let (shared, ours_only, theirs_only) = (nth_key(0), nth_key(1), nth_key(2));
+        let mut ours: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut theirs: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let acc = |value, interaction_count, last_interaction_tick| CoherenceAccumulator {
+            interaction_count,
+            last_interaction_tick,
+            ..acc_at(value)
+        };
+        ours.insert_accumulator(shared.clone(), acc(0.8, 10, 100));
+        ours.insert_accumulator(ours_only.clone(), acc(0.5, 3, 50));
+        theirs.insert_accumulator(shared.clone(), acc(0.4, 30, 300));
+        theirs.insert_accumulator(theirs_only.clone(), acc(0.6, 7, 70));
+
+        ours.merge(&theirs);
+        let merged = &ours.accumulators[&shared];
+        assert_eq!(merged.value, 0.4);
+        assert_eq!(merged.interaction_count, 40);
+        assert_eq!(merged.last_interaction_tick, 300);
+        assert_eq!(ours.accumulators[&ours_only], acc(0.5, 3, 50));
+        assert_eq!(ours.accumulators[&theirs_only], acc(0.6, 7, 70));
+        assert_eq!(ours.context_count(), 3);
+    }
+
+    #[test]
+    fn test_eviction_at_capacity_drops_genuinely_oldest() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let p = Personality::new();
+        for i in 0..MAX_CONTEXTS - 1 {
+            field.positive_interaction(&nth_key(i), &p, 10 + i as u64, false);
+        }
+        // Created at tick 500 without an interaction yet: the newest, not the oldest.
+        let fresh = nth_key(MAX_CONTEXTS - 1);
+        field.get_or_create(&fresh, 500);
+        assert_eq!(field.context_count(), MAX_CONTEXTS);
+
+        field.positive_interaction(&nth_key(MAX_CONTEXTS), &p, 501, false);
+        assert_eq!(field.context_count(), MAX_CONTEXTS);
+        assert!(field.accumulators.contains_key(&fresh), "fresh context kept");
+        assert!(!field.accumulators.contains_key(&nth_key(0)), "oldest context evicted");
+        assert!(field.accumulators.contains_key(&nth_key(1)));
+    }
+
+    #[test]
+    fn test_merge_fields_evicts_least_recent_at_capacity() {
+        let mut ours: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        for i in 0..MAX_CONTEXTS {
+            let acc = CoherenceAccumulator { last_interaction_tick: 100 + i as u64, ..acc_at(0.5) };
+            ours.insert_accumulator(nth_key(i), acc);
+        }
+        let mut theirs: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let (recent, stale) = (nth_key(MAX_CONTEXTS), nth_key(MAX_CONTEXTS + 1));
+        theirs.insert_accumulator(recent.clone(), CoherenceAccumulator { last_interaction_tick: 1_000, ..acc_at(0.9) });
+        theirs.insert_accumulator(stale.clone(), CoherenceAccumulator { last_interaction_tick: 5, ..acc_at(0.9) });
+
+        ours.merge(&theirs);
+        assert_eq!(ours.context_count(), MAX_CONTEXTS);
+        assert!(ours.accumulators.contains_key(&recent));
+        assert!(!ours.accumulators.contains_key(&stale), "older than everything kept");
+        assert!(!ours.accumulators.contains_key(&nth_key(0)), "least recent evicted");
+        assert!(ours.accumulators.contains_key(&nth_key(1)));
+    }
+
+    #[test]
+    fn test_nearest_context_finds_close_variant() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let bright_quiet = bright_quiet_static();
+        let near = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        assert_eq!(field.nearest_context(&near, 0.0), None);
+
+        field.insert_accumulator(bright_quiet.clone(), acc_at(0.6));
+        field.insert_accumulator(dark_loud_close(), acc_at(0.9));
+        let (found, sim, value) = field.nearest_context(&near, 0.5).unwrap();
+        assert_eq!(found, &bright_quiet);
+        assert_eq!(sim, near.cosine_similarity(&bright_quiet));
+        assert_eq!(value, 0.6);
+        // Read-only: the variant is still unseen.
+        assert_eq!(field.context_count(), 2);
+        assert_eq!(field.context_coherence(&near), 0.0);
+
+        // Below the threshold, and never the key itself.
+        assert_eq!(field.nearest_context(&near, 0.99), None);
+        assert_ne!(field.nearest_context(&bright_quiet, 0.0).map(|n| n.0), Some(&bright_quiet));
+    }
+
+    #[test]
+    fn test_strongest_and_weakest_context() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert!(field.strongest_context().is_none());
+        assert!(field.weakest_context().is_none());
+
+        let (a, b) = (bright_quiet_static(), dark_loud_close());
+        let c = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        field.insert_accumulator(a.clone(), acc_at(0.7));
+        field.insert_accumulator(b.clone(), acc_at(0.1));
+        field.insert_accumulator(c.clone(), acc_at(0.4));
+        assert_eq!(field.strongest_context(), Some((&a, 0.7)));
+        assert_eq!(field.weakest_context(), Some((&b, 0.1)));
+
+        // Equal coherence: the better-known context wins both ways.
+        let d = make_key(BrightnessBand::Dark, NoiseBand::Quiet, PresenceSignature::Far);
+        let e = make_key(BrightnessBand::Dark, NoiseBand::Loud, PresenceSignature::Far);
+        field.insert_accumulator(d.clone(), CoherenceAccumulator { interaction_count: 9, ..acc_at(0.7) });
+        field.insert_accumulator(e.clone(), CoherenceAccumulator { interaction_count: 9, ..acc_at(0.1) });
+        assert_eq!(field.strongest_context(), Some((&d, 0.7)));
+        assert_eq!(field.weakest_context(), Some((&e, 0.1)));
+    }
+
+    #[test]
+    fn test_field_stats() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(field.stats(), FieldStats { context_count: 0, strongest_coherence: 0.0 });
+        field.insert_accumulator(bright_quiet_static(), acc_at(0.6));
+        field.insert_accumulator(dark_loud_close(), acc_at(0.2));
+        assert_eq!(field.stats(), FieldStats { context_count: 2, strongest_coherence: 0.6 });
+    }
+
+    #[test]
+    fn test_batch_interactions_match_per_tick_calls() {
+        let p = neutral_personality();
+        let config = FieldConfig {
+            history: HistoryPolicy::All,
+            max_delta_per_tick: Some(0.015),
+            similarity_boost: Some(SimilarityBoost { threshold: 0.5, max_boost: 1.0 }),
+            ..FieldConfig::default()
+        };
+        let make = || -> CoherenceField<MbotSensors, 6> { CoherenceField::with_config(config.clone()) };
+        let (a, b) = (bright_quiet_static(), dark_loud_close());
+        let near = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+
+        let ticks: [u64; 40] = core::array::from_fn(|i| i as u64);
+        let (mut batch, mut looped) = (make(), make());
+        batch.positive_interactions(&a, &p, &ticks, true);
+        for &t in &ticks {
+            looped.positive_interaction(&a, &p, t, true);
+        }
+        assert_eq!(batch.accumulators, looped.accumulators);
+        assert_eq!(batch.histories, looped.histories);
+
+        let events = [
+            (near.clone(), InteractionKind::Positive { alone: false }, 41),
+            (near.clone(), InteractionKind::Positive { alone: false }, 42),
+            (near.clone(), InteractionKind::Negative, 43),
+            (b.clone(), InteractionKind::Negative, 44),
+            (b.clone(), InteractionKind::Decay, 500),
+            (a.clone(), InteractionKind::Positive { alone: true }, 501),
+            (b.clone(), InteractionKind::Positive { alone: false }, 502),
+        ];
+        batch.replay(&p, &events);
+        for (key, kind, tick) in &events {
+            match *kind {
+                InteractionKind::Positive { alone } => looped.positive_interaction(key, &p, *tick, alone),
+                InteractionKind::Negative => looped.negative_interaction(key, &p, *tick),
+                InteractionKind::Decay => looped.decay_all_to(*tick),
+            }
+        }
+        assert_eq!(batch.accumulators, looped.accumulators);
+        assert_eq!(batch.histories, looped.histories);
+        assert_eq!(batch.last_decay_tick(), 500);
+    }
+
+    #[test]
+    fn test_with_personality_seeds_cold_start() {
+        let bold = Personality { curiosity_drive: 1.0, ..Personality::new() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_personality(&bold);
+        assert!((field.personality_baseline() - 0.15).abs() < 1e-6);
+        let key = bright_quiet_static();
+        field.get_or_create(&key, 0);
+        assert!((field.context_coherence(&key) - 0.15).abs() < 1e-6);
+
+        field.set_personality_baseline(0.06);
+        field.get_or_create(&dark_loud_close(), 0);
+        assert!((field.context_coherence(&dark_loud_close()) - 0.06).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_remove_context_and_forget_all() {
+        let p = Personality { curiosity_drive: 1.0, ..Personality::new() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.update_personality(&p);
+        field.set_fallback(Some(0.2));
+        let (a, b) = (bright_quiet_static(), dark_loud_close());
+        for tick in 0..20 {
+            field.positive_interaction(&a, &p, tick, false);
+            field.positive_interaction(&b, &p, tick, false);
+        }
+
+        let removed = field.remove_context(&a).expect("tracked");
+        assert_eq!(removed.interaction_count, 20);
+        assert_eq!(field.context_count(), 1);
+        assert!(field.remove_context(&a).is_none());
+
+        field.forget_all();
+        assert_eq!(field.context_count(), 0);
+        assert_eq!(field.personality_baseline(), 0.15);
+        assert_eq!(field.context_coherence(&b), 0.2, "fallback preserved");
+
+        field.positive_interaction(&a, &p, 30, false);
+        let fresh = field.accumulators.get(&a).unwrap();
+        assert_eq!(fresh.interaction_count, 1);
+        assert!(fresh.value < 0.15 + 0.05, "re-created from baseline: {}", fresh.value);
+    }
+
+    #[test]
+    fn test_remap_context_moves_trust_and_history() {
+        let p = neutral_personality();
+        let config = FieldConfig { history: HistoryPolicy::All, ..FieldConfig::default() };
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::with_config(config);
+        let old = bright_quiet_static();
+        let new = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        for tick in 0..5 {
+            field.positive_interaction(&old, &p, tick, false);
+        }
+        let before = field.accumulators.get(&old).cloned().unwrap();
+
+        assert!(!field.remap_context(&old, &old), "self-remap is a no-op");
+        assert!(field.remap_context(&old, &new));
+        assert_eq!(field.accumulators.get(&new), Some(&before));
+        assert_eq!(field.accumulators.get(&old), None, "no ghost left behind");
+        assert_eq!(field.context_history(&new).len(), 5);
+        assert!(field.context_history(&old).is_empty());
+        assert!(!field.remap_context(&old, &new), "unknown source");
+
+        // Remapping onto an existing context merges conservatively.
+        let other = dark_loud_close();
+        field.insert_accumulator(other.clone(), acc_at(0.9));
+        assert!(field.remap_context(&other, &new));
+        let merged = field.accumulators.get(&new).unwrap();
+        assert_eq!(merged.value, before.value.min(0.9));
+        assert_eq!(merged.interaction_count, 5);
+        assert_eq!(field.context_count(), 1);
+    }
+
+    #[test]
+    fn test_novelty() {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let known = bright_quiet_static();
+        let near = make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Far);
+        assert_eq!(field.novelty(&known), 1.0);
+
+        field.insert_accumulator(known.clone(), acc_at(0.5));
+        assert!(field.novelty(&known) < 1e-5);
+        let n_near = field.novelty(&near);
+        let n_far = field.novelty(&dark_loud_close());
+        assert!(n_near > 0.0 && n_near < n_far, "near={} far={}", n_near, n_far);
+        assert!((n_near - (1.0 - known.cosine_similarity(&near))).abs() < 1e-6);
+    }
+
     #[test]
     fn test_coherence_field_fallback() {
         let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
@@ -685,7 +2979,7 @@ mod tests {
 
         // Seen context still uses its actual value
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             acc.value = 0.6;
         }
         assert!((field.context_coherence(&key) - 0.6).abs() < 0.001);
@@ -701,7 +2995,7 @@ mod tests {
         let key = bright_quiet_static();
 
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             for i in 0..100 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -721,7 +3015,7 @@ mod tests {
         let key = bright_quiet_static();
 
         {
-            let acc = field.get_or_create(&key);
+            let acc = field.get_or_create(&key, 0);
             for i in 0..5 {
                 acc.positive_interaction(0.5, i, false);
             }
@@ -0,0 +1,217 @@
+//! Batteries-included facade over the whole CCF pipeline.
+//!
+//! [`CcfAgent`] owns a [`CoherenceField`], a [`Personality`], a [`PhaseSpace`]
+//! and a [`PhaseTracker`], and runs one full pipeline step per call to
+//! [`CcfAgent::tick`]:
+//!
+//! ```text
+//! sensors → ContextKey → interaction → effective_coherence → SocialPhase → outputs
+//! ```
+//!
+//! It adds no math of its own — every step delegates to the public lower-level
+//! types, which remain available through the accessors for anything the facade
+//! does not cover (boundary discovery, snapshots, weighted interactions).
+//!
+//! ```rust
+//! use ccf_core::agent::CcfAgent;
+//! use ccf_core::mbot::*;
+//!
+//! let mut agent: CcfAgent<MbotSensors, 6> = CcfAgent::default();
+//! let sensors = MbotSensors {
+//!     brightness: BrightnessBand::Bright, noise: NoiseBand::Quiet,
+//!     presence: PresenceSignature::Close, motion: MotionContext::Static,
+//!     orientation: Orientation::Upright, time_period: TimePeriod::Day,
+//! };
+//! let out = agent.tick(sensors, 0.8, 0.1, true, 0);
+//! println!("{:?} permeability={:.2}", out.phase, out.permeability);
+//! ```
+//!
+//! # Invariants
+//! - **I-DIST-001** — no_std compatible
+//! - **I-DIST-005** — Zero unsafe code
+
+use crate::accumulator::CoherenceField;
+use crate::phase::{permeability, Personality, PhaseSpace, PhaseTracker, SocialPhase};
+use crate::vocabulary::{ContextKey, SensorVocabulary};
+
+// ─── AgentOutput ────────────────────────────────────────────────────────────
+
+/// Everything a robot needs to drive its outputs for one tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AgentOutput {
+    /// Social phase after hysteresis (CCF-004).
+    pub phase: SocialPhase,
+    /// Output of the minimum gate (CCF-001) for the observed context.
+    pub effective_coherence: f32,
+    /// LED colour for the phase — see [`SocialPhase::led_tint`].
+    pub led_tint: [u8; 3],
+    /// Expression scale in [0.0, 1.0] — see [`permeability`].
+    pub permeability: f32,
+}
+
+// ─── CcfAgent ───────────────────────────────────────────────────────────────
+
+/// Single-call CCF pipeline: feed an observation, get back the phase and outputs.
+///
+/// All state (per-context trust, personality, hysteresis) is held internally.
+pub struct CcfAgent<V: SensorVocabulary<N>, const N: usize> {
+    field: CoherenceField<V, N>,
+    personality: Personality,
+    phase_space: PhaseSpace,
+    tracker: PhaseTracker,
+}
+
+impl<V: SensorVocabulary<N>, const N: usize> CcfAgent<V, N> {
+    /// Create an agent with the given personality and phase thresholds.
+    ///
+    /// The field's cold-start baseline is derived from `personality`.
+    pub fn new(personality: Personality, phase_space: PhaseSpace) -> Self {
+        let mut field = CoherenceField::new();
+        field.update_personality(&personality);
+        Self { field, personality, phase_space, tracker: PhaseTracker::new() }
+    }
+
+    /// Run one pipeline step.
+    ///
+    /// 1. Builds the [`ContextKey`] for `sensors`.
+    /// 2. Records a positive interaction if `positive`, otherwise a negative one.
+    /// 3. Gates `instant` against the accumulated context trust.
+    /// 4. Classifies the phase with hysteresis against the previous tick.
+    ///
+    /// Positive interactions are recorded with `alone = false`; use
+    /// [`Self::field_mut`] directly for the alone-context bootstrap.
+    pub fn tick(&mut self, sensors: V, instant: f32, tension: f32, positive: bool, tick: u64) -> AgentOutput {
+        let key = ContextKey::new(sensors);
+        if positive {
+            self.field.positive_interaction(&key, &self.personality, tick, false);
+        } else {
+            self.field.negative_interaction(&key, &self.personality, tick);
+        }
+
+        let effective_coherence = self.field.effective_coherence(instant, &key);
+        let phase = self.tracker.update(effective_coherence, tension, &self.phase_space);
+        AgentOutput {
+            phase,
+            effective_coherence,
+            led_tint: phase.led_tint(),
+            permeability: permeability(effective_coherence, tension, phase),
+        }
+    }
+
+    /// Current social phase (as of the last tick).
+    pub fn phase(&self) -> SocialPhase {
+        self.tracker.phase()
+    }
+
+    /// The underlying coherence field.
+    pub fn field(&self) -> &CoherenceField<V, N> {
+        &self.field
+    }
+
+    /// Mutable access to the underlying coherence field.
+    pub fn field_mut(&mut self) -> &mut CoherenceField<V, N> {
+        &mut self.field
+    }
+
+    /// The personality modulating interactions.
+    pub fn personality(&self) -> &Personality {
+        &self.personality
+    }
+
+    /// Replace the personality and update the field's cold-start baseline.
+    pub fn set_personality(&mut self, personality: Personality) {
+        self.field.update_personality(&personality);
+        self.personality = personality;
+    }
+
+    /// The phase thresholds used for classification.
+    pub fn phase_space(&self) -> &PhaseSpace {
+        &self.phase_space
+    }
+
+    /// The hysteresis state (current phase and dwell time).
+    pub fn tracker(&self) -> &PhaseTracker {
+        &self.tracker
+    }
+}
+
+impl<V: SensorVocabulary<N>, const N: usize> Default for CcfAgent<V, N> {
+    fn default() -> Self {
+        Self::new(Personality::new(), PhaseSpace::new())
+    }
+}
+
+impl<V: SensorVocabulary<N>, const N: usize> core::fmt::Debug for CcfAgent<V, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CcfAgent")
+            .field("field", &self.field)
+            .field("personality", &self.personality)
+            .field("phase_space", &self.phase_space)
+            .field("tracker", &self.tracker)
+            .finish()
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mbot::{
+        BrightnessBand, MbotSensors, MotionContext, NoiseBand, Orientation,
+        PresenceSignature, TimePeriod,
+    };
+
+    fn living_room() -> MbotSensors {
+        MbotSensors {
+            brightness: BrightnessBand::Bright,
+            noise: NoiseBand::Quiet,
+            presence: PresenceSignature::Close,
+            motion: MotionContext::Static,
+            orientation: Orientation::Upright,
+            time_period: TimePeriod::Day,
+        }
+    }
+
+    #[test]
+    fn test_agent_starts_shy_and_earns_beloved() {
+        let mut agent: CcfAgent<MbotSensors, 6> = CcfAgent::default();
+        let first = agent.tick(living_room(), 0.9, 0.1, true, 0);
+        assert_eq!(first.phase, SocialPhase::ShyObserver);
+
+        let mut out = first;
+        for tick in 1..200 {
+            out = agent.tick(living_room(), 0.9, 0.1, true, tick);
+        }
+        assert_eq!(out.phase, SocialPhase::QuietlyBeloved);
+        assert_eq!(out.led_tint, SocialPhase::QuietlyBeloved.led_tint());
+        assert_eq!(agent.phase(), SocialPhase::QuietlyBeloved);
+    }
+
+    #[test]
+    fn test_agent_matches_manual_pipeline() {
+        let p = Personality::new();
+        let ps = PhaseSpace::new();
+        let mut agent: CcfAgent<MbotSensors, 6> = CcfAgent::new(p.clone(), ps.clone());
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        field.update_personality(&p);
+        let mut tracker = PhaseTracker::new();
+        let key = ContextKey::new(living_room());
+
+        for tick in 0..50 {
+            let positive = tick % 7 != 0;
+            let out = agent.tick(living_room(), 0.7, 0.2, positive, tick);
+            if positive {
+                field.positive_interaction(&key, &p, tick, false);
+            } else {
+                field.negative_interaction(&key, &p, tick);
+            }
+            let eff = field.effective_coherence(0.7, &key);
+            let phase = tracker.update(eff, 0.2, &ps);
+            assert_eq!(out.effective_coherence, eff);
+            assert_eq!(out.phase, phase);
+            assert_eq!(out.permeability, permeability(eff, 0.2, phase));
+        }
+    }
+}
@@ -5,105 +5,431 @@
 //!
 //! # Two-graph architecture
 //!
-//! **Graph A — World Shape** (cosine similarity only, stable)
-//! Edge weight = `cosine_similarity(vocab_A, vocab_B)` ∈ [0.0, 1.0]
+//! **Graph A — World Shape** (context similarity only, stable)
+//! Edge weight = `M::similarity(vocab_A, vocab_B)` ∈ [0.0, 1.0], where the
+//! [`ContextMetric`] `M` defaults to cosine ([`CosineMetric`])
 //!
 //! **Graph B — Trust Shape** (patent-faithful, dynamic)
 //! Activates once both endpoints have ≥ `MIN_TRUST_OBSERVATIONS` interactions.
 //! Edge weight = `sim × tanh(coh_A × TRUST_SCALE) × tanh(coh_B × TRUST_SCALE)`
+//! Each tanh factor can additionally fade with the ticks since that context's
+//! last trust update — see [`BoundaryConfig::recency_lambda`].
 //!
 //! # Algorithm
 //!
 //! Stoer-Wagner global minimum cut, O(V·E + V²·log V).
-//! Exact for any graph ≤ MAX_CONTEXTS (64) nodes.
+//! Exact for any graph up to the boundary's node capacity `C` (default
+//! [`MAX_CONTEXTS`] = 64, at most [`MAX_BOUNDARY_CAPACITY`]).
+//! [`MinCutBoundary::refresh`] caches the whole-graph cut until the graph
+//! changes (see [`MinCutBoundary::is_dirty`]); the `&self` queries reuse a
+//! cached cut but never fill the cache, so a boundary stays `Send + Sync`.
+//!
+//! # Capacity
+//!
+//! [`MinCutBoundary<V, N, C>`] stores two `C × C` `f32` adjacency matrices, so
+//! memory grows with `C²`: 8 nodes need 512 bytes, the default 64 needs
+//! 32 KiB, 128 needs 128 KiB. `MinCutBoundary<V, N>` keeps the default of 64.
 //!
 //! # Invariants
 //! - **I-BNDRY-001** — Min-cut on context-key graph, not episode graph
 //! - **I-BNDRY-002** — Edge weight ∈ [0.0, 1.0]
-//! - **I-BNDRY-003** — Edges inserted only when cosine similarity > EDGE_THRESHOLD (0.1)
+//! - **I-BNDRY-003** — Edges inserted only when similarity > EDGE_THRESHOLD (0.1)
 //! - **I-TRUST-001** — Trust component activates only after MIN_TRUST_OBSERVATIONS (50)
+//!
+//! The three numbers above are defaults; [`BoundaryConfig`] overrides them per
+//! instance for parameter sweeps.
 //! - **I-DIST-001** — no_std compatible; uses hashbrown HashMap
 //! - **I-DIST-005** — Zero unsafe code
 
-use crate::vocabulary::{ContextKey, SensorVocabulary};
+#[cfg(feature = "serde")]
+extern crate alloc;
+
+use crate::vocabulary::{ContextKey, ContextMetric, CosineMetric, SensorVocabulary};
 
-/// Maximum number of contexts tracked in the boundary graph.
+/// Default node capacity `C` of the boundary graph.
 pub const MAX_CONTEXTS: usize = 64;
 
-/// Minimum cosine similarity for an edge to be inserted (I-BNDRY-003).
-const EDGE_THRESHOLD: f32 = 0.1;
+/// Largest supported node capacity `C` of a [`MinCutBoundary`].
+pub const MAX_BOUNDARY_CAPACITY: usize = 256;
+
+use crate::defaults::{BOUNDARY_RECENCY_LAMBDA, EDGE_THRESHOLD, TRUST_SCALE};
 
 /// Minimum positive interactions before the trust component activates (I-TRUST-001).
-pub const MIN_TRUST_OBSERVATIONS: u32 = 50;
+pub use crate::defaults::MIN_TRUST_OBSERVATIONS;
+
+/// Tunable parameters of a [`MinCutBoundary`].
+///
+/// Defaults are [`EDGE_THRESHOLD`] (0.1), [`TRUST_SCALE`] (2.0),
+/// [`MIN_TRUST_OBSERVATIONS`] (50) and [`BOUNDARY_RECENCY_LAMBDA`] (0.0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundaryConfig {
+    /// Minimum context similarity for a Graph A edge to be inserted.
+    pub edge_threshold: f32,
+    /// Trust scale factor in the Graph B edge weight formula.
+    pub trust_scale: f32,
+    /// Interactions both endpoints need before Graph B weighting applies.
+    pub min_trust_observations: u32,
+    /// Staleness decay: each endpoint's Graph B trust factor is scaled by
+    /// `exp(−recency_lambda × ticks since its last trust update)`.
+    /// 0.0 (default) disables it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recency_lambda: f32,
+}
 
-/// Trust scale factor in the Graph B edge weight formula.
-const TRUST_SCALE: f32 = 2.0;
+impl Default for BoundaryConfig {
+    fn default() -> Self {
+        Self {
+            edge_threshold: EDGE_THRESHOLD,
+            trust_scale: TRUST_SCALE,
+            min_trust_observations: MIN_TRUST_OBSERVATIONS,
+            recency_lambda: BOUNDARY_RECENCY_LAMBDA,
+        }
+    }
+}
 
-/// Result of a minimum cut computation.
+/// Result of a minimum cut computation on a graph of capacity `C`.
 #[derive(Clone, Debug)]
-pub struct MinCutResult {
+pub struct MinCutResult<const C: usize = MAX_CONTEXTS> {
     /// Weight of the minimum cut (thinnest bridge in the trust manifold).
     pub min_cut_value: f32,
     /// Number of entries in `partition_s`.
     pub partition_s_count: usize,
     /// Context hashes on the "safe" (high-trust) side.
-    pub partition_s: [u32; MAX_CONTEXTS],
+    pub partition_s: [u32; C],
     /// Number of entries in `partition_complement`.
     pub partition_complement_count: usize,
     /// Context hashes on the "unfamiliar" side.
-    pub partition_complement: [u32; MAX_CONTEXTS],
+    pub partition_complement: [u32; C],
+}
+
+/// One side of a [`MinCutResult`] partition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartitionSide {
+    /// The "safe" (high-trust) side, `partition_s`.
+    S,
+    /// The "unfamiliar" side, `partition_complement`.
+    Complement,
+}
+
+/// Which comfort zone a context falls in — see [`MinCutBoundary::side_of`].
+///
+/// Unlike [`PartitionSide`], which follows the arbitrary S/complement labelling
+/// of the cut, this is labelled by trust: `Inside` is the side with the higher
+/// mean coherence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Partition {
+    /// The familiar, higher-trust comfort zone.
+    Inside,
+    /// The unfamiliar side of the thin bridge.
+    Outside,
+}
+
+impl<const C: usize> MinCutResult<C> {
+    /// An empty result with the given cut value.
+    fn empty(min_cut_value: f32) -> Self {
+        Self {
+            min_cut_value,
+            partition_s_count: 0,
+            partition_s: [0; C],
+            partition_complement_count: 0,
+            partition_complement: [0; C],
+        }
+    }
+
+    /// Context hashes on `side`, in discovery order.
+    pub fn side(&self, side: PartitionSide) -> &[u32] {
+        match side {
+            PartitionSide::S => &self.partition_s[..self.partition_s_count],
+            PartitionSide::Complement => {
+                &self.partition_complement[..self.partition_complement_count]
+            }
+        }
+    }
+
+    /// Context hashes on `side`, sorted ascending (no_std).
+    ///
+    /// Returns the backing array and the number of valid entries.
+    pub fn sorted_side(&self, side: PartitionSide) -> ([u32; C], usize) {
+        let src = self.side(side);
+        let mut out = [0u32; C];
+        out[..src.len()].copy_from_slice(src);
+        out[..src.len()].sort_unstable();
+        (out, src.len())
+    }
+
+    /// Context hashes on `side` as an ordered set.
+    #[cfg(feature = "std")]
+    pub fn partition_set(&self, side: PartitionSide) -> std::collections::BTreeSet<u32> {
+        self.side(side).iter().copied().collect()
+    }
+
+    /// Whether `self` and `other` describe the same partition of contexts.
+    ///
+    /// A cut is the same partition whichever side is labelled S, so the
+    /// relabelled pairing also counts as equal. Compares topology only —
+    /// `min_cut_value` is ignored.
+    pub fn partitions_equal(&self, other: &MinCutResult<C>) -> bool {
+        let (s, s_len) = self.sorted_side(PartitionSide::S);
+        let (c, c_len) = self.sorted_side(PartitionSide::Complement);
+        let (os, os_len) = other.sorted_side(PartitionSide::S);
+        let (oc, oc_len) = other.sorted_side(PartitionSide::Complement);
+        let (s, c, os, oc) = (&s[..s_len], &c[..c_len], &os[..os_len], &oc[..oc_len]);
+        (s == os && c == oc) || (s == oc && c == os)
+    }
+}
+
+/// The thin bridge found by the minimum cut (Patent Claim 11).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BridgeInfo {
+    /// Severed edges `(s_hash, complement_hash, weight)` — same as
+    /// [`MinCutBoundary::cut_edges`].
+    pub edges: std::vec::Vec<(u32, u32, f32)>,
+    /// The context isolated by the cut, if one side is a single node —
+    /// "this room is the fragile link". `None` for a balanced cut.
+    pub weakest_node: Option<u32>,
+}
+
+/// Clustering of the boundary graph into up to `k` comfort zones, from
+/// [`MinCutBoundary::partition_k_bounded`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct KPartition<const C: usize = MAX_CONTEXTS> {
+    /// Context hashes grouped by cluster; cluster `i` runs from `offsets[i]`
+    /// to the next cluster's offset (or `count` for the last).
+    hashes: [u32; C],
+    /// Cluster start offsets into `hashes`.
+    offsets: [usize; C],
+    /// Number of hashes across all clusters.
+    count: usize,
+    /// Number of clusters.
+    len: usize,
+    /// `true` if the iteration budget ran out before `k` clusters were reached.
+    /// The clusters are then the best clustering found so far.
+    pub truncated: bool,
+}
+
+impl<const C: usize> KPartition<C> {
+    /// Number of clusters (0 for an empty graph).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no clusters.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Context hashes in cluster `i`. Panics if `i >= len()`.
+    pub fn cluster(&self, i: usize) -> &[u32] {
+        assert!(i < self.len, "cluster index out of range");
+        let end = if i + 1 < self.len { self.offsets[i + 1] } else { self.count };
+        &self.hashes[self.offsets[i]..end]
+    }
+
+    /// Iterate over the clusters' context hashes.
+    pub fn iter(&self) -> impl Iterator<Item = &[u32]> {
+        (0..self.len).map(move |i| self.cluster(i))
+    }
 }
 
 /// Per-context node data stored in the boundary graph.
 #[derive(Clone, Debug)]
-struct NodeData {
+struct NodeData<V: SensorVocabulary<N>, const N: usize> {
     /// FNV hash of the context key (used as stable node ID).
     hash: u32,
+    /// The context key itself. `None` for nodes restored from a snapshot,
+    /// which records hashes only.
+    key: Option<ContextKey<V, N>>,
     /// Current coherence value [0.0, 1.0].
     coherence: f32,
     /// Positive interactions in this context.
     observations: u32,
+    /// Tick of the last trust update.
+    last_tick: u64,
+}
+
+/// Bitset over node indices `0..MAX_BOUNDARY_CAPACITY`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct NodeMask([u64; MAX_BOUNDARY_CAPACITY / 64]);
+
+impl NodeMask {
+    /// Nodes `0..n`.
+    fn first(n: usize) -> Self {
+        let mut mask = Self::default();
+        for i in 0..n {
+            mask.toggle(i);
+        }
+        mask
+    }
+
+    /// Node `i` alone.
+    fn single(i: usize) -> Self {
+        let mut mask = Self::default();
+        mask.toggle(i);
+        mask
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn toggle(&mut self, i: usize) {
+        self.0[i / 64] ^= 1 << (i % 64);
+    }
+
+    fn toggled(mut self, i: usize) -> Self {
+        self.toggle(i);
+        self
+    }
+
+    fn count(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn union(mut self, other: Self) -> Self {
+        for (w, o) in self.0.iter_mut().zip(other.0) {
+            *w |= o;
+        }
+        self
+    }
+
+    fn intersect(mut self, other: Self) -> Self {
+        for (w, o) in self.0.iter_mut().zip(other.0) {
+            *w &= o;
+        }
+        self
+    }
+
+    fn minus(mut self, other: Self) -> Self {
+        for (w, o) in self.0.iter_mut().zip(other.0) {
+            *w &= !o;
+        }
+        self
+    }
+}
+
+/// Compile-time check that a boundary capacity is supported.
+struct Capacity<const C: usize>;
+
+impl<const C: usize> Capacity<C> {
+    const CHECK: () = assert!(
+        C >= 1 && C <= MAX_BOUNDARY_CAPACITY,
+        "MinCutBoundary capacity must be in 1..=MAX_BOUNDARY_CAPACITY"
+    );
 }
 
 /// Comfort-zone boundary via global minimum cut on the trust-weighted context graph.
 ///
+/// `C` is the node capacity (default [`MAX_CONTEXTS`] = 64, at most
+/// [`MAX_BOUNDARY_CAPACITY`]); contexts reported beyond it are ignored.
+/// `M` is the [`ContextMetric`] that weights Graph A edges (default
+/// [`CosineMetric`]).
+///
 /// Patent Claims 9–12.
-pub struct MinCutBoundary<V: SensorVocabulary<N>, const N: usize> {
-    /// Node list (up to MAX_CONTEXTS).
-    nodes: [Option<NodeData>; MAX_CONTEXTS],
+pub struct MinCutBoundary<
+    V: SensorVocabulary<N>,
+    const N: usize,
+    const C: usize = MAX_CONTEXTS,
+    M: ContextMetric = CosineMetric,
+> {
+    /// Node list (up to C).
+    nodes: [Option<NodeData<V, N>>; C],
     /// Number of active nodes.
     node_count: usize,
     /// Adjacency matrix: edge weights between node indices.
     /// `adj[i][j]` is the Graph B (or Graph A fallback) weight between nodes i and j.
-    adj: [[f32; MAX_CONTEXTS]; MAX_CONTEXTS],
+    adj: [[f32; C]; C],
+    /// Graph A (context similarity) weights, written once at node insertion.
+    /// `update_trust` always recomputes Graph B from this baseline.
+    base_adj: [[f32; C]; C],
+    /// Edge threshold, trust scale, observation gate and recency decay.
+    config: BoundaryConfig,
+    /// Latest tick passed to [`Self::update_trust_at`].
+    current_tick: u64,
+    /// Last whole-graph minimum cut; `None` once the graph has changed since.
+    cache: Option<MinCutResult<C>>,
+    /// Number of whole-graph Stoer-Wagner runs by [`Self::refresh`], to observe cache hits.
+    #[cfg(test)]
+    recomputes: u32,
     /// Phantom for the vocabulary type.
     _vocab: core::marker::PhantomData<V>,
+    /// Phantom for the Graph A similarity metric.
+    _metric: core::marker::PhantomData<M>,
 }
 
-impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
-    /// Create an empty boundary graph.
+impl<V: SensorVocabulary<N>, const N: usize, const C: usize, M: ContextMetric> MinCutBoundary<V, N, C, M> {
+    /// Create an empty boundary graph with the default [`BoundaryConfig`].
+    ///
+    /// Fails to compile if `C` is 0 or above [`MAX_BOUNDARY_CAPACITY`].
     pub fn new() -> Self {
+        Self::with_config(BoundaryConfig::default())
+    }
+
+    /// Create an empty boundary graph with the given parameters.
+    pub fn with_config(config: BoundaryConfig) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Capacity::<C>::CHECK;
         Self {
-            nodes: [
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-                None, None, None, None, None, None, None, None,
-            ],
+            nodes: core::array::from_fn(|_| None),
             node_count: 0,
-            adj: [[0.0; MAX_CONTEXTS]; MAX_CONTEXTS],
+            adj: [[0.0; C]; C],
+            base_adj: [[0.0; C]; C],
+            config,
+            current_tick: 0,
+            cache: None,
+            #[cfg(test)]
+            recomputes: 0,
             _vocab: core::marker::PhantomData,
+            _metric: core::marker::PhantomData,
         }
     }
 
+    /// The boundary parameters.
+    pub fn config(&self) -> &BoundaryConfig {
+        &self.config
+    }
+
+    /// Whether the graph changed since the minimum cut was last cached.
+    ///
+    /// [`Self::refresh`] caches the cut; [`Self::min_cut_value`],
+    /// [`Self::partition`] and everything built on them reuse it until a node
+    /// is added or removed, or trust or edge weights change.
+    pub fn is_dirty(&self) -> bool {
+        self.cache.is_none()
+    }
+
+    /// Minimum cut of the current graph, recomputed only if the graph changed
+    /// since the last call.
+    ///
+    /// Caching needs `&mut self`; the `&self` queries reuse the cached cut
+    /// but compute from scratch while the boundary [`Self::is_dirty`]. Call
+    /// this once per tick after updating the graph to make them cheap.
+    pub fn refresh(&mut self) -> &MinCutResult<C> {
+        let result = match self.cache.take() {
+            Some(cached) => cached,
+            None => {
+                #[cfg(test)]
+                {
+                    self.recomputes += 1;
+                }
+                self.partition()
+            }
+        };
+        self.cache.insert(result)
+    }
+
+    /// Drop the cached minimum cut after a graph change.
+    fn invalidate(&mut self) {
+        self.cache = None;
+    }
+
     /// Register a context key as a node, providing all existing keys for edge insertion.
     ///
-    /// If the context is already known, this is O(1). If new, inserts edges to all
-    /// existing nodes with cosine similarity > EDGE_THRESHOLD (I-BNDRY-003).
+    /// If the context is already known, this is O(1) and only re-attaches the
+    /// key to a node restored from a snapshot without it. If new, inserts edges
+    /// to all existing nodes with `M` similarity above the configured edge
+    /// threshold (I-BNDRY-003).
     pub fn report_context_with_key(
         &mut self,
         key: &ContextKey<V, N>,
@@ -112,16 +438,28 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
         let hash = key.context_hash_u32();
 
         // Linear scan to check if already known (no HashMap needed for ≤64 nodes)
-        if self.find_idx(hash).is_some() {
+        if let Some(idx) = self.find_idx(hash) {
+            // Nodes restored from a hash-only snapshot get their key back here.
+            if let Some(ref mut node) = self.nodes[idx] {
+                if node.key.is_none() {
+                    node.key = Some(key.clone());
+                }
+            }
             return;
         }
 
-        if self.node_count >= MAX_CONTEXTS {
+        if self.node_count >= C {
             return;
         }
 
         let new_idx = self.node_count;
-        self.nodes[new_idx] = Some(NodeData { hash, coherence: 0.0, observations: 0 });
+        self.nodes[new_idx] = Some(NodeData {
+            hash,
+            key: Some(key.clone()),
+            coherence: 0.0,
+            observations: 0,
+            last_tick: self.current_tick,
+        });
 
         // Insert Graph A edges to all existing nodes
         for (other_key, other_hash) in all_keys {
@@ -129,22 +467,46 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
                 continue;
             }
             if let Some(other_idx) = self.find_idx(*other_hash) {
-                let sim = key.cosine_similarity(other_key);
-                if sim > EDGE_THRESHOLD {
+                let sim = key.similarity::<M>(other_key);
+                if sim > self.config.edge_threshold {
                     self.adj[new_idx][other_idx] = sim;
                     self.adj[other_idx][new_idx] = sim;
+                    self.base_adj[new_idx][other_idx] = sim;
+                    self.base_adj[other_idx][new_idx] = sim;
                 }
             }
         }
 
         self.node_count += 1;
+        self.invalidate();
     }
 
     /// Update trust-weighted edges for a context after a coherence change.
     ///
-    /// Recomputes Graph B weights for all edges incident to this context.
-    /// If either endpoint has fewer than MIN_TRUST_OBSERVATIONS, keeps Graph A weight.
+    /// Recomputes Graph B weights for all edges incident to this context from
+    /// the stored Graph A baseline, so repeated calls with the same inputs are
+    /// idempotent and trust weighting can never raise an edge above its Graph A
+    /// weight. If either endpoint has fewer than `min_trust_observations`, the
+    /// edge carries its Graph A weight.
+    ///
+    /// Counts as an update at the latest tick seen by [`Self::update_trust_at`].
     pub fn update_trust(&mut self, key: &ContextKey<V, N>, coherence: f32, observations: u32) {
+        self.update_trust_at(key, coherence, observations, self.current_tick);
+    }
+
+    /// [`Self::update_trust`] at an explicit `tick`, for recency weighting.
+    ///
+    /// With a non-zero [`BoundaryConfig::recency_lambda`], each endpoint's
+    /// Graph B trust factor decays with the ticks since its own last update,
+    /// so comfort zones left untouched shrink. When `tick` advances the clock,
+    /// every edge is reweighted, since every other node grew staler.
+    pub fn update_trust_at(
+        &mut self,
+        key: &ContextKey<V, N>,
+        coherence: f32,
+        observations: u32,
+        tick: u64,
+    ) {
         let hash = key.context_hash_u32();
         let Some(idx) = self.find_idx(hash) else { return; };
 
@@ -152,58 +514,174 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
         if let Some(ref mut node) = self.nodes[idx] {
             node.coherence = coherence;
             node.observations = observations;
+            node.last_tick = tick;
         }
 
-        // Snapshot coherence/obs for the current node before iterating
-        let self_coh = coherence;
-        let self_obs = observations;
+        if tick > self.current_tick && self.config.recency_lambda > 0.0 {
+            self.current_tick = tick;
+            for i in 0..self.node_count {
+                self.reweight(i);
+            }
+        } else {
+            self.current_tick = self.current_tick.max(tick);
+            self.reweight(idx);
+        }
+    }
 
+    /// Staleness factor of `node` in `[0.0, 1.0]`; 1.0 with recency disabled.
+    fn recency(&self, node: &NodeData<V, N>) -> f32 {
+        let lambda = self.config.recency_lambda;
+        if lambda <= 0.0 {
+            return 1.0;
+        }
+        let age = self.current_tick.saturating_sub(node.last_tick);
+        exp_approx(-lambda * age as f32).min(1.0)
+    }
+
+    /// Move a node to a new context key after its sensor signature changed.
+    ///
+    /// Mirrors [`CoherenceField::remap_context`]: the node keeps its trust data
+    /// under `to`'s hash and its Graph A edges are recomputed from `to`'s
+    /// features against `all_keys`. If `to` is already a node, the two are
+    /// combined (lower coherence, summed observations) and `from`'s node is
+    /// removed. Returns `false` if `from` is not a node or equals `to`.
+    ///
+    /// [`CoherenceField::remap_context`]: crate::accumulator::CoherenceField::remap_context
+    pub fn remap_context(
+        &mut self,
+        from: &ContextKey<V, N>,
+        to: &ContextKey<V, N>,
+        all_keys: &[(ContextKey<V, N>, u32)],
+    ) -> bool {
+        let (from_hash, to_hash) = (from.context_hash_u32(), to.context_hash_u32());
+        if from_hash == to_hash {
+            return false;
+        }
+        let Some(from_idx) = self.find_idx(from_hash) else { return false; };
+        let Some(moved) = self.nodes[from_idx].clone() else { return false; };
+
+        let idx = match self.find_idx(to_hash) {
+            Some(to_idx) => {
+                if let Some(ref mut node) = self.nodes[to_idx] {
+                    node.coherence = node.coherence.min(moved.coherence);
+                    node.observations = node.observations.saturating_add(moved.observations);
+                    node.last_tick = node.last_tick.max(moved.last_tick);
+                }
+                let last = self.node_count - 1;
+                self.remove_idx(from_idx);
+                // remove_idx fills the hole with the last node.
+                if to_idx == last { from_idx } else { to_idx }
+            }
+            None => {
+                if let Some(ref mut node) = self.nodes[from_idx] {
+                    node.hash = to_hash;
+                    node.key = Some(to.clone());
+                }
+                self.clear_edges(from_idx);
+                for (other_key, other_hash) in all_keys {
+                    if *other_hash == to_hash || *other_hash == from_hash {
+                        continue;
+                    }
+                    if let Some(other_idx) = self.find_idx(*other_hash) {
+                        let sim = to.similarity::<M>(other_key);
+                        if sim > self.config.edge_threshold {
+                            self.base_adj[from_idx][other_idx] = sim;
+                            self.base_adj[other_idx][from_idx] = sim;
+                        }
+                    }
+                }
+                from_idx
+            }
+        };
+        self.reweight(idx);
+        true
+    }
+
+    /// Recompute the weights of all edges incident to node `idx` from the
+    /// Graph A baseline and the current trust data of both endpoints.
+    fn reweight(&mut self, idx: usize) {
+        self.invalidate();
+        let Some(ref node) = self.nodes[idx] else { return; };
+        let self_coh = node.coherence;
+        let self_obs = node.observations;
+        let self_recency = self.recency(node);
+        let BoundaryConfig { edge_threshold, trust_scale, min_trust_observations, .. } = self.config;
         // Reweight edges: for each neighbour with a Graph A edge, compute Graph B if eligible
         for other_idx in 0..self.node_count {
             if other_idx == idx {
                 continue;
             }
 
-            // We need the baseline similarity (Graph A weight).
-            // adj currently holds whichever weight was last written.
-            // To detect whether an edge exists, check if weight > 0 OR check via the
-            // original Graph A weight. We store Graph A weight initially, so if the
-            // current weight is > 0 after update_trust was called, the edge exists.
-            // However on repeated calls we may have overwritten with Graph B.
-            // Safe approach: only reweight if either Graph A or current weight > EDGE_THRESHOLD.
-            let current_weight = self.adj[idx][other_idx];
-            if current_weight <= EDGE_THRESHOLD {
+            // Graph A edges exist only where similarity exceeded the edge threshold.
+            let base_weight = self.base_adj[idx][other_idx];
+            if base_weight <= edge_threshold {
                 continue;
             }
 
             let other_coh;
             let other_obs;
+            let other_recency;
             if let Some(ref other) = self.nodes[other_idx] {
                 other_coh = other.coherence;
                 other_obs = other.observations;
+                other_recency = self.recency(other);
             } else {
                 continue;
             }
 
             // Use Graph B if both endpoints have sufficient observations (I-TRUST-001)
-            let weight = if self_obs >= MIN_TRUST_OBSERVATIONS
-                && other_obs >= MIN_TRUST_OBSERVATIONS
+            let weight = if self_obs >= min_trust_observations
+                && other_obs >= min_trust_observations
             {
-                // Graph B: trust-weighted
-                let t_self = boundary_tanh(self_coh * TRUST_SCALE);
-                let t_other = boundary_tanh(other_coh * TRUST_SCALE);
-                (current_weight * t_self * t_other).clamp(0.0, 1.0)
+                // Graph B: trust-weighted, faded by staleness
+                let t_self = boundary_tanh(self_coh * trust_scale) * self_recency;
+                let t_other = boundary_tanh(other_coh * trust_scale) * other_recency;
+                (base_weight * t_self * t_other).clamp(0.0, 1.0)
             } else {
-                // Graph A: similarity only — leave unchanged
-                current_weight
+                // Graph A: similarity only
+                base_weight
             };
+            // tanh ≤ 1, so trust can only attenuate the Graph A weight.
+            debug_assert!(weight <= base_weight);
+            let weight = weight.min(base_weight);
 
             self.adj[idx][other_idx] = weight;
             self.adj[other_idx][idx] = weight;
         }
     }
 
-    /// Linear scan to find the node index for a given hash (O(n), n ≤ 64).
+    /// Zero every current and baseline edge incident to node `idx`.
+    fn clear_edges(&mut self, idx: usize) {
+        self.invalidate();
+        for k in 0..C {
+            self.adj[idx][k] = 0.0;
+            self.adj[k][idx] = 0.0;
+            self.base_adj[idx][k] = 0.0;
+            self.base_adj[k][idx] = 0.0;
+        }
+    }
+
+    /// Remove node `idx`, moving the last node (and its edges) into the hole.
+    fn remove_idx(&mut self, idx: usize) {
+        let last = self.node_count - 1;
+        self.clear_edges(idx);
+        if idx != last {
+            self.nodes[idx] = self.nodes[last].take();
+            for k in 0..C {
+                self.adj[idx][k] = self.adj[last][k];
+                self.adj[k][idx] = self.adj[k][last];
+                self.base_adj[idx][k] = self.base_adj[last][k];
+                self.base_adj[k][idx] = self.base_adj[k][last];
+            }
+            self.adj[idx][idx] = 0.0;
+            self.base_adj[idx][idx] = 0.0;
+            self.clear_edges(last);
+        }
+        self.nodes[last] = None;
+        self.node_count = last;
+    }
+
+    /// Linear scan to find the node index for a given hash (O(n), n ≤ C).
     fn find_idx(&self, hash: u32) -> Option<usize> {
         for i in 0..self.node_count {
             if let Some(ref node) = self.nodes[i] {
@@ -229,92 +707,541 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
     /// Full minimum cut result: value and partition.
     ///
     /// Patent Claim 10: partition is observable.
-    pub fn partition(&self) -> MinCutResult {
+    pub fn partition(&self) -> MinCutResult<C> {
         if self.node_count < 2 {
-            let mut complement = [0u32; MAX_CONTEXTS];
-            for i in 0..self.node_count {
-                if let Some(ref n) = self.nodes[i] {
-                    complement[i] = n.hash;
+            let mut result = MinCutResult::empty(0.0);
+            for (slot, node) in result.partition_complement.iter_mut().zip(self.nodes.iter()).take(self.node_count) {
+                if let Some(ref n) = node {
+                    *slot = n.hash;
                 }
             }
-            return MinCutResult {
-                min_cut_value: 0.0,
-                partition_s_count: 0,
-                partition_s: [0; MAX_CONTEXTS],
-                partition_complement_count: self.node_count,
-                partition_complement: complement,
-            };
+            result.partition_complement_count = self.node_count;
+            return result;
         }
         self.stoer_wagner()
     }
 
+    /// Context keys of the nodes on `side` of `result`, in partition order.
+    ///
+    /// `result` should come from this boundary's [`Self::partition`] (or a
+    /// variant). Hashes with no registered node, and nodes restored from a
+    /// snapshot whose key has not been re-reported via
+    /// [`Self::report_context_with_key`], are skipped.
+    pub fn side_keys<'a>(
+        &'a self,
+        result: &'a MinCutResult<C>,
+        side: PartitionSide,
+    ) -> impl Iterator<Item = &'a ContextKey<V, N>> + 'a {
+        result
+            .side(side)
+            .iter()
+            .filter_map(move |&h| self.find_idx(h).and_then(|i| self.nodes[i].as_ref()))
+            .filter_map(|node| node.key.as_ref())
+    }
+
+    /// [`Self::partition`] as context keys: `(S side, complement side)`.
+    ///
+    /// See [`Self::side_keys`] for which nodes are skipped.
+    #[cfg(feature = "std")]
+    pub fn partition_with_keys(
+        &self,
+    ) -> (std::vec::Vec<ContextKey<V, N>>, std::vec::Vec<ContextKey<V, N>>) {
+        let result = self.partition();
+        let s = self.side_keys(&result, PartitionSide::S).cloned().collect();
+        let c = self.side_keys(&result, PartitionSide::Complement).cloned().collect();
+        (s, c)
+    }
+
+    /// Which side of the current partition `key`'s node is on.
+    ///
+    /// Computes [`Self::partition`] once and labels the side with the higher
+    /// mean node coherence `Inside` (S on a tie). With a single node, that
+    /// node is `Inside`. Returns `None` if `key` is not a registered node.
+    pub fn side_of(&self, key: &ContextKey<V, N>) -> Option<Partition> {
+        let hash = key.context_hash_u32();
+        self.find_idx(hash)?;
+        let result = self.partition();
+        let s = result.side(PartitionSide::S);
+        let c = result.side(PartitionSide::Complement);
+        let inside = if self.mean_coherence(s) >= self.mean_coherence(c) { s } else { c };
+        Some(if inside.contains(&hash) { Partition::Inside } else { Partition::Outside })
+    }
+
+    /// Mean coherence of the nodes in `hashes`; −1.0 for an empty side so any
+    /// non-empty side outranks it.
+    fn mean_coherence(&self, hashes: &[u32]) -> f32 {
+        if hashes.is_empty() {
+            return -1.0;
+        }
+        let total: f32 = hashes
+            .iter()
+            .filter_map(|&h| self.find_idx(h).and_then(|i| self.nodes[i].as_ref()))
+            .map(|n| n.coherence)
+            .sum();
+        total / hashes.len() as f32
+    }
+
+    /// Minimum cut restricted to the registered nodes whose hashes are in `include`.
+    ///
+    /// Unknown hashes are ignored. Uses the existing adjacency between included
+    /// nodes only, so partition hashes are always drawn from the included set.
+    /// Fewer than 2 included nodes yields the trivial zero-cut result, with the
+    /// included nodes in `partition_complement`.
+    pub fn partition_subset(&self, include: &[u32]) -> MinCutResult<C> {
+        let mut mask = [false; C];
+        for &hash in include {
+            if let Some(idx) = self.find_idx(hash) {
+                mask[idx] = true;
+            }
+        }
+        let included = mask.iter().filter(|&&b| b).count();
+        if included < 2 {
+            let mut result = MinCutResult::empty(0.0);
+            for (slot, &inc) in self.nodes.iter().zip(mask.iter()).take(self.node_count) {
+                if let (Some(ref n), true) = (slot, inc) {
+                    result.partition_complement[result.partition_complement_count] = n.hash;
+                    result.partition_complement_count += 1;
+                }
+            }
+            return result;
+        }
+        self.stoer_wagner_over(&mask)
+    }
+
+    /// Edges severed by the current minimum cut, as `(s_hash, complement_hash, weight)`.
+    ///
+    /// Sorted by weight descending, then by hash pair. Their weights sum to
+    /// [`Self::min_cut_value`]. Empty with fewer than 2 nodes or when the
+    /// graph is already disconnected.
+    #[cfg(feature = "std")]
+    pub fn cut_edges(&self) -> std::vec::Vec<(u32, u32, f32)> {
+        let result = self.partition();
+        let mut edges = std::vec::Vec::new();
+        for &a in result.side(PartitionSide::S) {
+            for &b in result.side(PartitionSide::Complement) {
+                if let (Some(i), Some(j)) = (self.find_idx(a), self.find_idx(b)) {
+                    let w = self.adj[i][j];
+                    if w > 0.0 {
+                        edges.push((a, b, w));
+                    }
+                }
+            }
+        }
+        edges.sort_by(|x, y| {
+            y.2.partial_cmp(&x.2)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then((x.0, x.1).cmp(&(y.0, y.1)))
+        });
+        edges
+    }
+
+    /// The thin bridge between the two comfort clusters (Patent Claim 11).
+    ///
+    /// Returns `None` when there is no bridge (fewer than 2 nodes, or the
+    /// clusters are already disconnected). `weakest_node` names the context
+    /// when the cut isolates a single node; if both sides are single nodes,
+    /// the one with lower coherence (then lower hash) is named.
+    #[cfg(feature = "std")]
+    pub fn bridge(&self) -> Option<BridgeInfo> {
+        let edges = self.cut_edges();
+        if edges.is_empty() {
+            return None;
+        }
+        let result = self.partition();
+        let s = result.side(PartitionSide::S);
+        let c = result.side(PartitionSide::Complement);
+        let coherence = |h: u32| {
+            self.find_idx(h)
+                .and_then(|i| self.nodes[i].as_ref())
+                .map_or(0.0, |n| n.coherence)
+        };
+        let weakest_node = match (s, c) {
+            ([a], [b]) => {
+                let (ca, cb) = (coherence(*a), coherence(*b));
+                Some(if ca < cb || (ca == cb && a < b) { *a } else { *b })
+            }
+            ([a], _) | (_, [a]) => Some(*a),
+            _ => None,
+        };
+        Some(BridgeInfo { edges, weakest_node })
+    }
+
     /// Number of registered context nodes.
     pub fn node_count(&self) -> usize {
         self.node_count
     }
 
+    /// Every edge with positive weight, as `(hash_a, hash_b, weight)`.
+    ///
+    /// Each undirected edge appears once, with `hash_a` the endpoint inserted
+    /// first. Read-only and allocation-free.
+    pub fn edges(&self) -> impl Iterator<Item = (u32, u32, f32)> + '_ {
+        let n = self.node_count;
+        (0..n)
+            .flat_map(move |i| ((i + 1)..n).map(move |j| (i, j)))
+            .filter_map(move |(i, j)| {
+                let w = self.adj[i][j];
+                match (&self.nodes[i], &self.nodes[j]) {
+                    (Some(a), Some(b)) if w > 0.0 => Some((a.hash, b.hash, w)),
+                    _ => None,
+                }
+            })
+    }
+
+    /// The graph in Graphviz DOT format, for visual debugging.
+    ///
+    /// Nodes are labelled with their hash and coherence; edges with their
+    /// current weight. Render with e.g. `dot -Tsvg`.
+    #[cfg(feature = "serde")]
+    pub fn to_dot(&self) -> alloc::string::String {
+        use core::fmt::Write;
+        let mut out = alloc::string::String::from("graph ccf_boundary {\n");
+        for node in self.nodes.iter().take(self.node_count).flatten() {
+            let _ = writeln!(
+                out,
+                "  \"{:08x}\" [label=\"{:08x}\\ncoh={:.2}\"];",
+                node.hash, node.hash, node.coherence
+            );
+        }
+        for (a, b, w) in self.edges() {
+            let _ = writeln!(out, "  \"{:08x}\" -- \"{:08x}\" [label=\"{:.3}\"];", a, b, w);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    // ─── Snapshot support ────────────────────────────────────────────────────
+
+    /// `(hash, coherence, observations, last_tick)` for node `idx`, if registered.
+    #[cfg(feature = "serde")]
+    pub(crate) fn node_entry(&self, idx: usize) -> Option<(u32, f32, u32, u64)> {
+        if idx >= self.node_count {
+            return None;
+        }
+        self.nodes[idx].as_ref().map(|n| (n.hash, n.coherence, n.observations, n.last_tick))
+    }
+
+    /// Latest tick seen by [`Self::update_trust_at`].
+    #[cfg(feature = "serde")]
+    pub(crate) fn current_tick(&self) -> u64 {
+        self.current_tick
+    }
+
+    /// Set the recency clock of a boundary being restored.
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_current_tick(&mut self, tick: u64) {
+        self.current_tick = tick;
+    }
+
+    /// The cached minimum cut, if the graph has not changed since it was computed.
+    #[cfg(feature = "serde")]
+    pub(crate) fn cached_partition(&self) -> Option<MinCutResult<C>> {
+        self.cache.clone()
+    }
+
+    /// Install a previously computed minimum cut for the current graph.
+    #[cfg(feature = "serde")]
+    pub(crate) fn seed_partition(&mut self, result: MinCutResult<C>) {
+        self.cache = Some(result);
+    }
+
+    /// Current edge weight between node indices `i` and `j`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn edge_weight(&self, i: usize, j: usize) -> f32 {
+        self.adj[i][j]
+    }
+
+    /// Append a node with the given trust data. Returns `false` if full or a duplicate.
+    #[cfg(feature = "serde")]
+    pub(crate) fn push_node(
+        &mut self,
+        hash: u32,
+        coherence: f32,
+        observations: u32,
+        last_tick: u64,
+    ) -> bool {
+        if self.node_count >= C || self.find_idx(hash).is_some() {
+            return false;
+        }
+        self.nodes[self.node_count] = Some(NodeData {
+            hash,
+            key: None,
+            coherence,
+            observations,
+            last_tick,
+        });
+        self.node_count += 1;
+        self.invalidate();
+        true
+    }
+
+    /// Attach `key` to the restored node with its hash, if that node has none.
+    ///
+    /// Never inserts a node, so the graph and any cached partition are untouched.
+    /// Returns `false` if no node has this hash.
+    #[cfg(feature = "serde")]
+    pub(crate) fn attach_key(&mut self, key: &ContextKey<V, N>) -> bool {
+        let Some(idx) = self.find_idx(key.context_hash_u32()) else { return false; };
+        if let Some(ref mut node) = self.nodes[idx] {
+            if node.key.is_none() {
+                node.key = Some(key.clone());
+            }
+        }
+        true
+    }
+
+    /// Graph A (similarity) baseline weight between node indices `i` and `j`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn base_edge_weight(&self, i: usize, j: usize) -> f32 {
+        self.base_adj[i][j]
+    }
+
+    /// Set the symmetric current and Graph A baseline weights between node indices `i` and `j`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_edge_weights(&mut self, i: usize, j: usize, weight: f32, base: f32) {
+        self.adj[i][j] = weight;
+        self.adj[j][i] = weight;
+        self.base_adj[i][j] = base;
+        self.base_adj[j][i] = base;
+        self.invalidate();
+    }
+
     // ─── Stoer-Wagner algorithm ──────────────────────────────────────────────
 
     /// Stoer-Wagner global minimum cut.
     ///
     /// Returns the minimum cut value and the partition (S, V\S).
-    /// O(V·E + V²·log V), exact for all inputs.
-    fn stoer_wagner(&self) -> MinCutResult {
+    /// O(V·E + V²·log V), exact for all inputs. Reuses the cut cached by
+    /// [`Self::refresh`] while it is still current.
+    fn stoer_wagner(&self) -> MinCutResult<C> {
+        if let Some(ref cached) = self.cache {
+            return cached.clone();
+        }
+        let mut include = [false; C];
+        for a in include.iter_mut().take(self.node_count) {
+            *a = true;
+        }
+        self.stoer_wagner_over(&include)
+    }
+
+    /// Heuristic comfort-zone split that favours two similar-sized sides.
+    ///
+    /// Minimises `cut_value + balance_penalty × |size(S) − size(complement)|`
+    /// instead of the cut value alone, so a single weakly attached outlier no
+    /// longer wins just because its cut is cheapest. This is a heuristic, not
+    /// the exact minimum cut of Claim 9: candidates are the Stoer–Wagner
+    /// cuts-of-the-phase, followed by single-node moves while they lower the
+    /// objective. The result always covers every node, and its
+    /// `min_cut_value` is the true weight of the chosen cut.
+    ///
+    /// `balance_penalty ≤ 0` returns exactly [`Self::partition`].
+    pub fn balanced_partition(&self, balance_penalty: f32) -> MinCutResult<C> {
         let n = self.node_count;
+        if balance_penalty <= 0.0 || n < 3 {
+            return self.partition();
+        }
+        let imbalance = |mask: &NodeMask| (2 * mask.count() as i32 - n as i32).unsigned_abs() as f32;
+        let objective = |cut: f32, mask: &NodeMask| cut + balance_penalty * imbalance(mask);
 
-        // Working copy of adjacency weights
-        let mut w = [[0.0_f32; MAX_CONTEXTS]; MAX_CONTEXTS];
-        for i in 0..n {
-            for j in 0..n {
-                w[i][j] = self.adj[i][j];
+        let mut include = [false; C];
+        include[..n].fill(true);
+        let (mut cut, mut mask) = self.min_cut_mask_by(&include, objective);
+
+        // Local refinement: move single nodes across while the objective drops.
+        for _pass in 0..n {
+            let mut improved = false;
+            for i in 0..n {
+                let moved = mask.toggled(i);
+                if moved.count() == 0 || moved.count() == n {
+                    continue;
+                }
+                // Edges to i's own side become cut; edges across stop being cut.
+                let mut delta = 0.0;
+                for j in (0..n).filter(|&j| j != i) {
+                    let same_side = mask.contains(j) == mask.contains(i);
+                    delta += if same_side { self.adj[i][j] } else { -self.adj[i][j] };
+                }
+                if objective(cut + delta, &moved) < objective(cut, &mask) - 1e-6 {
+                    cut = (cut + delta).max(0.0);
+                    mask = moved;
+                    improved = true;
+                }
+            }
+            if !improved {
+                break;
             }
         }
 
-        // Track which original nodes are merged into each super-node via bitmask.
-        // u64 supports up to 64 bits, matching MAX_CONTEXTS = 64.
-        let mut merged = [0u64; MAX_CONTEXTS];
-        for i in 0..n {
-            merged[i] = 1u64 << i;
+        let mut result = MinCutResult::empty(cut);
+        for (i, slot) in self.nodes.iter().enumerate().take(n) {
+            if let Some(ref node) = slot {
+                if mask.contains(i) {
+                    result.partition_s[result.partition_s_count] = node.hash;
+                    result.partition_s_count += 1;
+                } else {
+                    result.partition_complement[result.partition_complement_count] = node.hash;
+                    result.partition_complement_count += 1;
+                }
+            }
         }
+        result
+    }
 
-        let mut active = [false; MAX_CONTEXTS];
-        for i in 0..n {
-            active[i] = true;
+    /// Split the graph into up to `k` comfort zones, e.g. upstairs, downstairs
+    /// and garage.
+    ///
+    /// Greedy recursive bisection: the min cut splits the graph in two, then
+    /// the larger remaining cluster is cut again until there are `k`
+    /// clusters (or every cluster is a single node). Each cut is optimal for
+    /// its cluster, but earlier cuts are never revisited, so the result is not
+    /// guaranteed to be the minimum-weight k-way cut — in particular a cheap
+    /// cut that isolates one outlier early is kept. Unbounded form of
+    /// [`Self::partition_k_bounded`]; always exactly `k − 1` min cuts.
+    pub fn partition_k(&self, k: usize) -> KPartition<C> {
+        self.partition_k_bounded(k, u32::MAX)
+    }
+
+    /// Split the graph into up to `k` clusters with at most `max_iterations`
+    /// min-cut computations.
+    ///
+    /// Starting from one cluster holding every node, each iteration bisects
+    /// the largest cluster (ties: earliest) with Stoer–Wagner restricted to
+    /// it. The work list is a fixed array of at most `C` cluster
+    /// masks — no recursion — so stack use is bounded regardless of `k`.
+    ///
+    /// Reaching `k` clusters takes exactly `k − 1` iterations, so any budget of
+    /// at least `k − 1` gives the unbounded result. A smaller budget returns
+    /// the clustering reached so far with `truncated` set. Fewer than `k`
+    /// clusters are also returned, untruncated, when every cluster is a
+    /// single node. `k = 0` is treated as 1.
+    pub fn partition_k_bounded(&self, k: usize, max_iterations: u32) -> KPartition<C> {
+        let n = self.node_count;
+        let k = k.clamp(1, C);
+        let mut clusters = [NodeMask::default(); C];
+        let mut len = 0;
+        if n > 0 {
+            clusters[0] = NodeMask::first(n);
+            len = 1;
+        }
+
+        let mut iterations = 0u32;
+        let mut truncated = false;
+        while len < k {
+            // Largest splittable cluster; earliest wins ties.
+            let mut target = None;
+            let mut best_size = 1;
+            for (c, mask) in clusters.iter().enumerate().take(len) {
+                let size = mask.count();
+                if size > best_size {
+                    best_size = size;
+                    target = Some(c);
+                }
+            }
+            let Some(c) = target else { break; };
+            if iterations >= max_iterations {
+                truncated = true;
+                break;
+            }
+            iterations += 1;
+
+            let mut include = [false; C];
+            for (i, inc) in include.iter_mut().enumerate().take(n) {
+                *inc = clusters[c].contains(i);
+            }
+            let (_, side) = self.min_cut_mask(&include);
+            let side = side.intersect(clusters[c]);
+            clusters[len] = clusters[c].minus(side);
+            clusters[c] = side;
+            len += 1;
         }
 
+        let mut out = KPartition { hashes: [0; C], offsets: [0; C], count: 0, len, truncated };
+        for (c, mask) in clusters.iter().enumerate().take(len) {
+            out.offsets[c] = out.count;
+            for (i, slot) in self.nodes.iter().enumerate().take(n) {
+                if let (Some(node), true) = (slot, mask.contains(i)) {
+                    out.hashes[out.count] = node.hash;
+                    out.count += 1;
+                }
+            }
+        }
+        out
+    }
+
+    /// Stoer–Wagner over the included nodes: `(cut value, node-index mask of one side)`.
+    ///
+    /// The cut value is `f32::MAX` when fewer than two nodes are included.
+    fn min_cut_mask(&self, include: &[bool; C]) -> (f32, NodeMask) {
+        self.min_cut_mask_by(include, |cut, _| cut)
+    }
+
+    /// Stoer–Wagner phases over the included nodes, keeping the cut-of-the-phase
+    /// with the lowest `score(cut value, side mask)`: `(cut value, side mask)`.
+    fn min_cut_mask_by(
+        &self,
+        include: &[bool; C],
+        score: impl Fn(f32, &NodeMask) -> f32,
+    ) -> (f32, NodeMask) {
+        let n = self.node_count;
+        let included = include.iter().take(n).filter(|&&b| b).count();
+
+        // Working copy of adjacency weights
+        let mut w = [[0.0_f32; C]; C];
+        for (w_row, adj_row) in w.iter_mut().zip(self.adj.iter()).take(n) {
+            w_row[..n].copy_from_slice(&adj_row[..n]);
+        }
+
+        // Track which original nodes are merged into each super-node via bitmask.
+        let mut merged = [NodeMask::default(); C];
+        for (i, m) in merged.iter_mut().enumerate().take(n) {
+            *m = NodeMask::single(i);
+        }
+
+        let mut active = *include;
+
         let mut best_cut = f32::MAX;
-        let mut best_partition_mask: u64 = 0;
+        let mut best_score = f32::MAX;
+        let mut best_partition_mask = NodeMask::default();
 
-        // Run n-1 phases
-        for _phase in 0..(n - 1) {
+        // Run (included - 1) phases
+        for _phase in 0..included.saturating_sub(1) {
             let (s, t, cut_val) = self.min_cut_phase(&w, &active, n);
-            if cut_val < best_cut {
+            let phase_score = score(cut_val, &merged[t]);
+            if phase_score < best_score {
+                best_score = phase_score;
                 best_cut = cut_val;
                 best_partition_mask = merged[t];
             }
             // Merge t into s
-            for i in 0..n {
-                if active[i] {
+            for (i, &is_active) in active.iter().enumerate().take(n) {
+                if is_active {
                     w[s][i] += w[t][i];
                     w[i][s] += w[i][t];
                 }
             }
-            merged[s] |= merged[t];
+            merged[s] = merged[s].union(merged[t]);
             active[t] = false;
         }
 
+        (best_cut, best_partition_mask)
+    }
+
+    /// Stoer-Wagner restricted to the nodes flagged in `include`.
+    ///
+    /// Edges to excluded nodes are ignored; the partition lists included nodes only.
+    fn stoer_wagner_over(&self, include: &[bool; C]) -> MinCutResult<C> {
+        let n = self.node_count;
+        let (best_cut, best_partition_mask) = self.min_cut_mask(include);
+
         // Build partition from best_partition_mask
-        let mut result = MinCutResult {
-            min_cut_value: if best_cut == f32::MAX { 0.0 } else { best_cut },
-            partition_s_count: 0,
-            partition_s: [0; MAX_CONTEXTS],
-            partition_complement_count: 0,
-            partition_complement: [0; MAX_CONTEXTS],
-        };
+        let mut result = MinCutResult::empty(if best_cut == f32::MAX { 0.0 } else { best_cut });
 
-        for i in 0..n {
-            if let Some(ref node) = self.nodes[i] {
-                if (best_partition_mask >> i) & 1 == 1 {
+        for (i, slot) in self.nodes.iter().enumerate().take(n) {
+            if !include[i] {
+                continue;
+            }
+            if let Some(ref node) = slot {
+                if best_partition_mask.contains(i) {
                     result.partition_s[result.partition_s_count] = node.hash;
                     result.partition_s_count += 1;
                 } else {
@@ -332,20 +1259,20 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
     /// Returns `(s_idx, t_idx, cut_value_of_t)`.
     fn min_cut_phase(
         &self,
-        w: &[[f32; MAX_CONTEXTS]; MAX_CONTEXTS],
-        active: &[bool; MAX_CONTEXTS],
+        w: &[[f32; C]; C],
+        active: &[bool; C],
         n: usize,
     ) -> (usize, usize, f32) {
-        let mut in_a = [false; MAX_CONTEXTS];
-        let mut key = [0.0_f32; MAX_CONTEXTS];
+        let mut in_a = [false; C];
+        let mut key = [0.0_f32; C];
 
         let mut prev = 0usize;
         let mut last = 0usize;
 
         // Find first active node to use as the starting point
         let mut initialised = false;
-        for i in 0..n {
-            if active[i] {
+        for (i, &is_active) in active.iter().enumerate().take(n) {
+            if is_active {
                 prev = i;
                 last = i;
                 initialised = true;
@@ -359,13 +1286,15 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
         let active_count = (0..n).filter(|&i| active[i]).count();
 
         for step in 0..active_count {
-            // Find active node not in A with maximum key
+            // Find active node not in A with maximum key; equal keys go to
+            // the lowest index so the partition is reproducible.
             let u_opt = (0..n)
                 .filter(|&i| active[i] && !in_a[i])
                 .max_by(|&a, &b| {
                     key[a]
                         .partial_cmp(&key[b])
                         .unwrap_or(core::cmp::Ordering::Equal)
+                        .then(b.cmp(&a))
                 });
 
             let u = match u_opt {
@@ -393,7 +1322,9 @@ impl<V: SensorVocabulary<N>, const N: usize> MinCutBoundary<V, N> {
     }
 }
 
-impl<V: SensorVocabulary<N>, const N: usize> Default for MinCutBoundary<V, N> {
+impl<V: SensorVocabulary<N>, const N: usize, const C: usize, M: ContextMetric> Default
+    for MinCutBoundary<V, N, C, M>
+{
     fn default() -> Self {
         Self::new()
     }
@@ -422,12 +1353,12 @@ fn boundary_tanh(x: f32) -> f32 {
 /// Uses range reduction: exp(x) = exp(k*ln2) * exp(r) = 2^k * exp(r)
 /// where r = x - k*ln2, |r| ≤ 0.5*ln2.
 /// The polynomial for exp(r) is accurate to < 1e-6 for |r| ≤ 0.347.
-fn exp_approx(x: f32) -> f32 {
+pub(crate) fn exp_approx(x: f32) -> f32 {
     // Clamp to avoid overflow: exp(88) > f32::MAX
     let x = x.clamp(-87.0, 88.0);
     // Range reduction: x = k*ln2 + r, k = round(x / ln2)
-    const LN2: f32 = 0.693_147_18;
-    const INV_LN2: f32 = 1.442_695_04;
+    const LN2: f32 = core::f32::consts::LN_2;
+    const INV_LN2: f32 = core::f32::consts::LOG2_E;
     let k = (x * INV_LN2 + 0.5) as i32 - (if x < 0.0 { 1 } else { 0 });
     let r = x - k as f32 * LN2;
     // Polynomial: exp(r) ≈ 1 + r + r²/2 + r³/6 + r⁴/24 + r⁵/120
@@ -570,6 +1501,597 @@ mod tests {
         // (exact values depend on tanh — just verify it ran without panic)
     }
 
+    #[test]
+    fn test_update_trust_is_idempotent_and_never_raises_weight() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let k1 = bright_quiet();
+        let k2 = bright_loud();
+        b.report_context_with_key(&k1, &[]);
+        b.report_context_with_key(&k2, &[(k1.clone(), k1.context_hash_u32())]);
+        let graph_a = b.adj[0][1];
+        assert!(graph_a > 0.0);
+
+        b.update_trust(&k1, 0.6, MIN_TRUST_OBSERVATIONS);
+        b.update_trust(&k2, 0.6, MIN_TRUST_OBSERVATIONS);
+        let once = b.adj[0][1];
+        b.update_trust(&k1, 0.6, MIN_TRUST_OBSERVATIONS);
+        b.update_trust(&k2, 0.6, MIN_TRUST_OBSERVATIONS);
+        assert_eq!(b.adj[0][1], once, "repeated update_trust must not compound");
+        assert!(once < graph_a);
+
+        b.update_trust(&k2, 1.0, MIN_TRUST_OBSERVATIONS);
+        assert!(b.adj[0][1] <= graph_a);
+        // Dropping below the observation gate restores the Graph A weight.
+        b.update_trust(&k2, 1.0, 0);
+        assert_eq!(b.adj[0][1], graph_a);
+    }
+
+    #[test]
+    fn test_trust_round_trip_recovers_edge_weight() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let k1 = bright_quiet();
+        let k2 = bright_loud();
+        b.report_context_with_key(&k1, &[]);
+        b.report_context_with_key(&k2, &[(k1.clone(), k1.context_hash_u32())]);
+        let graph_a = b.adj[0][1];
+        let graph_b = |c1: f32, c2: f32| {
+            graph_a * boundary_tanh(c1 * TRUST_SCALE) * boundary_tanh(c2 * TRUST_SCALE)
+        };
+
+        // Earn trust step by step, then lose it again: every weight depends only
+        // on the current coherences, never on the path taken.
+        b.update_trust(&k1, 0.9, MIN_TRUST_OBSERVATIONS);
+        for c in [0.2, 0.5, 0.9, 0.5, 0.2] {
+            b.update_trust(&k2, c, MIN_TRUST_OBSERVATIONS);
+            assert!((b.adj[0][1] - graph_b(0.9, c)).abs() < 1e-6, "c={}", c);
+        }
+        let lost = b.adj[0][1];
+        b.update_trust(&k2, 0.2, MIN_TRUST_OBSERVATIONS);
+        assert_eq!(b.adj[0][1], lost);
+
+        // Full trust on both sides approaches the original similarity.
+        b.update_trust(&k1, 1.0, MIN_TRUST_OBSERVATIONS);
+        b.update_trust(&k2, 1.0, MIN_TRUST_OBSERVATIONS);
+        assert!(b.adj[0][1] > 0.9 * graph_a);
+        assert_eq!(b.base_adj[0][1], graph_a);
+    }
+
+    #[test]
+    fn test_edge_threshold_config_drops_weak_edges() {
+        // Feature vectors [1, 0], [1, 0.5], [0.5, 1]: similarities 0.89, 0.80
+        // and 0.45 between the ends of the chain.
+        let keys = [
+            bright_quiet(),
+            make_key(BrightnessBand::Bright, NoiseBand::Moderate),
+            make_key(BrightnessBand::Dim, NoiseBand::Loud),
+        ];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 3] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        let build = |config: BoundaryConfig| {
+            let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::with_config(config);
+            for key in &keys {
+                b.report_context_with_key(key, &all);
+            }
+            b
+        };
+
+        let default = build(BoundaryConfig::default());
+        assert!(default.adj[0][2] > 0.0);
+        let strict = build(BoundaryConfig { edge_threshold: 0.5, ..BoundaryConfig::default() });
+        assert_eq!(strict.adj[0][2], 0.0);
+        assert!(strict.adj[0][1] > 0.0 && strict.adj[1][2] > 0.0);
+        // Isolating the Dim/Loud end no longer has to sever the weak edge.
+        let (loose_cut, strict_cut) = (default.min_cut_value(), strict.min_cut_value());
+        assert!((loose_cut - strict_cut - default.adj[0][2]).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_min_cut_is_cached_until_graph_changes() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let keys = [bright_quiet(), bright_loud(), dark_loud()];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 3] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        for key in &keys {
+            b.report_context_with_key(key, &all);
+        }
+        assert!(b.is_dirty());
+
+        // `&self` queries never fill the cache.
+        let first = b.min_cut_value();
+        assert!(b.is_dirty());
+        assert_eq!(b.recomputes, 0);
+
+        assert_eq!(b.refresh().min_cut_value, first);
+        assert!(!b.is_dirty());
+        assert_eq!(b.refresh().min_cut_value, first);
+        assert_eq!(b.min_cut_value(), first);
+        assert_eq!(b.partition().min_cut_value, first);
+        assert_eq!(b.recomputes, 1);
+
+        // Re-reporting a known context changes nothing.
+        b.report_context_with_key(&keys[0], &all);
+        assert!(!b.is_dirty());
+
+        b.update_trust(&keys[0], 0.9, MIN_TRUST_OBSERVATIONS);
+        assert!(b.is_dirty());
+        b.refresh();
+        assert_eq!(b.recomputes, 2);
+    }
+
+    #[test]
+    fn test_boundary_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MinCutBoundary<MbotSensors, 6>>();
+    }
+
+    #[test]
+    fn test_metric_parameter_sets_graph_a_weights() {
+        use crate::vocabulary::L1Metric;
+
+        let keys = [bright_quiet(), bright_loud(), dark_loud()];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 3] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        let mut cosine: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let mut l1: MinCutBoundary<MbotSensors, 6, MAX_CONTEXTS, L1Metric> = MinCutBoundary::new();
+        for key in &keys {
+            cosine.report_context_with_key(key, &all);
+            l1.report_context_with_key(key, &all);
+        }
+
+        let sim_cos = keys[0].cosine_similarity(&keys[1]);
+        let sim_l1 = keys[0].similarity::<L1Metric>(&keys[1]);
+        assert!(sim_cos > EDGE_THRESHOLD && sim_l1 > EDGE_THRESHOLD);
+        assert!((sim_cos - sim_l1).abs() > 1e-3, "cos={sim_cos} l1={sim_l1}");
+        assert_eq!(cosine.adj[0][1], sim_cos);
+        assert_eq!(l1.adj[0][1], sim_l1);
+        assert_eq!(l1.base_adj[1][0], sim_l1);
+        assert_ne!(l1.min_cut_value(), cosine.min_cut_value());
+    }
+
+    #[test]
+    fn test_edges_lists_each_positive_edge_once() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for h in 1..=4u32 {
+            push_bare_node(&mut b, h);
+        }
+        b.adj[0][1] = 0.9; b.adj[1][0] = 0.9;
+        b.adj[1][2] = 0.4; b.adj[2][1] = 0.4;
+        assert_eq!(b.edges().count(), 2);
+        let mut edges = b.edges();
+        assert_eq!(edges.next(), Some((1, 2, 0.9)));
+        assert_eq!(edges.next(), Some((2, 3, 0.4)));
+
+        #[cfg(feature = "serde")]
+        {
+            let dot = b.to_dot();
+            assert!(dot.starts_with("graph ccf_boundary {"));
+            assert_eq!(dot.matches(" -- ").count(), 2);
+            assert!(dot.contains("\"00000001\" -- \"00000002\" [label=\"0.900\"];"));
+            assert!(dot.contains("\"00000004\" [label="));
+        }
+    }
+
+    #[test]
+    fn test_side_keys_reconstruct_partition_hashes() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let keys = [bright_quiet(), bright_loud(), dark_quiet(), dark_loud()];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 4] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        for key in &keys {
+            b.report_context_with_key(key, &all);
+        }
+        let result = b.partition();
+        for side in [PartitionSide::S, PartitionSide::Complement] {
+            let hashes = result.side(side);
+            assert_eq!(b.side_keys(&result, side).count(), hashes.len());
+            for (key, &hash) in b.side_keys(&result, side).zip(hashes) {
+                assert_eq!(key.context_hash_u32(), hash);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let (s, c) = b.partition_with_keys();
+            let s: std::vec::Vec<u32> = s.iter().map(|k| k.context_hash_u32()).collect();
+            let c: std::vec::Vec<u32> = c.iter().map(|k| k.context_hash_u32()).collect();
+            assert_eq!(s, result.side(PartitionSide::S));
+            assert_eq!(c, result.side(PartitionSide::Complement));
+        }
+    }
+
+    #[test]
+    fn test_side_of_labels_zones_by_trust() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let (k1, k2, k3) = (bright_quiet(), bright_loud(), dark_quiet());
+        let all = [
+            (k1.clone(), k1.context_hash_u32()),
+            (k2.clone(), k2.context_hash_u32()),
+            (k3.clone(), k3.context_hash_u32()),
+        ];
+        b.report_context_with_key(&k1, &all);
+        assert_eq!(b.side_of(&k1), Some(Partition::Inside));
+        b.report_context_with_key(&k2, &all);
+        b.report_context_with_key(&k3, &all);
+        b.update_trust(&k1, 0.9, MIN_TRUST_OBSERVATIONS * 2);
+        b.update_trust(&k2, 0.8, MIN_TRUST_OBSERVATIONS * 2);
+
+        assert_eq!(b.side_of(&k1), Some(Partition::Inside));
+        assert_eq!(b.side_of(&k2), Some(Partition::Inside));
+        // Never interacted with and dissimilar to the bright rooms.
+        assert_eq!(b.side_of(&k3), Some(Partition::Outside));
+        assert_eq!(b.side_of(&dark_loud()), None);
+    }
+
+    #[test]
+    fn test_recency_fades_stale_trust_edges() {
+        let config = BoundaryConfig { recency_lambda: 0.001, ..BoundaryConfig::default() };
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::with_config(config);
+        let keys = [
+            bright_quiet(),
+            bright_loud(),
+            make_key(BrightnessBand::Bright, NoiseBand::Moderate),
+        ];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 3] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        for key in &keys {
+            b.report_context_with_key(key, &all);
+            b.update_trust_at(key, 0.8, MIN_TRUST_OBSERVATIONS, 0);
+        }
+        let trust = |b: &MinCutBoundary<MbotSensors, 6>, i: usize, j: usize| b.adj[i][j] / b.base_adj[i][j];
+        let fresh = trust(&b, 0, 1);
+        assert!((trust(&b, 0, 2) - fresh).abs() < 1e-6);
+
+        // Contexts 0 and 1 keep being visited; context 2 is left alone.
+        b.update_trust_at(&keys[0], 0.8, MIN_TRUST_OBSERVATIONS, 1_000);
+        b.update_trust_at(&keys[1], 0.8, MIN_TRUST_OBSERVATIONS, 1_000);
+        assert!((trust(&b, 0, 1) - fresh).abs() < 1e-6);
+        let stale = trust(&b, 0, 2);
+        assert!((stale / fresh - exp_approx(-1.0)).abs() < 1e-3, "stale {} fresh {}", stale, fresh);
+
+        // Without recency weighting, age is ignored.
+        let mut plain: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for key in &keys {
+            plain.report_context_with_key(key, &all);
+            plain.update_trust_at(key, 0.8, MIN_TRUST_OBSERVATIONS, 0);
+        }
+        plain.update_trust_at(&keys[0], 0.8, MIN_TRUST_OBSERVATIONS, 1_000);
+        assert!((trust(&plain, 0, 2) - fresh).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_remap_context_renames_or_merges_node() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let (k1, k2, k3) = (bright_quiet(), bright_loud(), dark_quiet());
+        let all = [
+            (k1.clone(), k1.context_hash_u32()),
+            (k2.clone(), k2.context_hash_u32()),
+            (k3.clone(), k3.context_hash_u32()),
+        ];
+        b.report_context_with_key(&k1, &all);
+        b.report_context_with_key(&k2, &all);
+        b.update_trust(&k1, 0.7, 80);
+        assert!(!b.remap_context(&k1, &k1, &all));
+        assert!(!b.remap_context(&k3, &k1, &all), "unknown source");
+
+        // Rename: trust follows, Graph A edges recomputed from the new features.
+        assert!(b.remap_context(&k1, &k3, &all));
+        assert_eq!(b.node_count(), 2);
+        assert_eq!(b.find_idx(k1.context_hash_u32()), None);
+        let idx = b.find_idx(k3.context_hash_u32()).unwrap();
+        assert_eq!(b.nodes[idx].as_ref().unwrap().observations, 80);
+        let other = b.find_idx(k2.context_hash_u32()).unwrap();
+        let sim = k3.cosine_similarity(&k2);
+        let expected = if sim > EDGE_THRESHOLD { sim } else { 0.0 };
+        assert_eq!(b.base_adj[idx][other], expected);
+
+        // Merge: onto an existing node, which absorbs the observations.
+        assert!(b.remap_context(&k3, &k2, &all));
+        assert_eq!(b.node_count(), 1);
+        let idx = b.find_idx(k2.context_hash_u32()).unwrap();
+        assert_eq!(idx, 0);
+        assert_eq!(b.nodes[0].as_ref().unwrap().observations, 80);
+        assert_eq!(b.adj[0][1], 0.0);
+    }
+
+    #[test]
+    fn test_small_capacity_boundary() {
+        let mut b: MinCutBoundary<MbotSensors, 6, 8> = MinCutBoundary::new();
+        assert!(core::mem::size_of::<MinCutBoundary<MbotSensors, 6, 8>>() < 1024);
+        let keys = [
+            bright_quiet(),
+            bright_loud(),
+            dark_quiet(),
+            dark_loud(),
+            make_key(BrightnessBand::Dim, NoiseBand::Quiet),
+            make_key(BrightnessBand::Dim, NoiseBand::Moderate),
+            make_key(BrightnessBand::Dim, NoiseBand::Loud),
+            make_key(BrightnessBand::Bright, NoiseBand::Moderate),
+            make_key(BrightnessBand::Dark, NoiseBand::Moderate),
+        ];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 9] = core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        for k in &keys {
+            b.report_context_with_key(k, &all);
+        }
+        assert_eq!(b.node_count(), 8, "contexts beyond capacity are ignored");
+        let result: MinCutResult<8> = b.partition();
+        assert_eq!(result.side(PartitionSide::S).len() + result.side(PartitionSide::Complement).len(), 8);
+        assert!(!result.side(PartitionSide::Complement).contains(&keys[8].context_hash_u32()));
+        assert_eq!(b.partition_k_bounded(3, 10).iter().map(|c| c.len()).sum::<usize>(), 8);
+    }
+
+    #[test]
+    fn test_large_capacity_boundary_splits_beyond_64_nodes() {
+        let mut b: MinCutBoundary<MbotSensors, 6, 128> = MinCutBoundary::new();
+        // Two dense zones of 50 nodes, the second straddling index 64, joined by one thin edge.
+        for hash in 1..=100 {
+            push_bare_node(&mut b, hash);
+        }
+        for i in 0..100 {
+            for j in (i + 1)..100 {
+                if (i < 50) == (j < 50) {
+                    b.adj[i][j] = 0.5;
+                    b.adj[j][i] = 0.5;
+                }
+            }
+        }
+        b.adj[10][90] = 0.05;
+        b.adj[90][10] = 0.05;
+
+        let result = b.partition();
+        assert!((result.min_cut_value - 0.05).abs() < 1e-6);
+        let mut sides = [result.side(PartitionSide::S), result.side(PartitionSide::Complement)];
+        sides.sort_by_key(|s| s.len());
+        assert_eq!(sides[0].len(), 50);
+        assert_eq!(sides[1].len(), 50);
+        let (low, high) = if sides[0].contains(&1) { (sides[0], sides[1]) } else { (sides[1], sides[0]) };
+        assert!(low.iter().all(|&h| h <= 50));
+        assert!(high.iter().all(|&h| h > 50));
+
+        let k = b.partition_k_bounded(2, 1);
+        assert_eq!(k.len(), 2);
+        assert!(!k.truncated);
+        assert_eq!(k.cluster(0).len() + k.cluster(1).len(), 100);
+    }
+
+    fn chain_boundary() -> MinCutBoundary<MbotSensors, 6> {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for hash in 1..=6 {
+            push_bare_node(&mut b, hash);
+        }
+        // Three tight pairs joined by thin bridges: (1,2) ~ (3,4) ~ (5,6).
+        let edges = [(0, 1, 0.9), (2, 3, 0.9), (4, 5, 0.9), (1, 2, 0.2), (3, 4, 0.15)];
+        for (i, j, w) in edges {
+            b.adj[i][j] = w;
+            b.adj[j][i] = w;
+        }
+        b
+    }
+
+    #[test]
+    fn test_balanced_partition_prefers_two_zones_over_outlier() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for hash in 1..=7 {
+            push_bare_node(&mut b, hash);
+        }
+        // Bright zone {1,2,3}, dark zone {4,5,6}, bridged at 0.3 in total;
+        // outlier 7 hangs off the bright zone by a 0.05 edge.
+        let edges = [
+            (0, 1, 0.9), (1, 2, 0.9), (0, 2, 0.9),
+            (3, 4, 0.9), (4, 5, 0.9), (3, 5, 0.9),
+            (2, 3, 0.3), (0, 6, 0.05),
+        ];
+        for (i, j, w) in edges {
+            b.adj[i][j] = w;
+            b.adj[j][i] = w;
+        }
+
+        let plain = b.partition();
+        assert!(plain.partitions_equal(&b.balanced_partition(0.0)));
+        let plain_small = plain.partition_s_count.min(plain.partition_complement_count);
+        assert_eq!(plain_small, 1, "plain min-cut shaves off the outlier");
+
+        let balanced = b.balanced_partition(0.1);
+        let small = balanced.partition_s_count.min(balanced.partition_complement_count);
+        assert_eq!(balanced.partition_s_count + balanced.partition_complement_count, 7);
+        assert_eq!(small, 3, "zones split: {:?}", balanced);
+        assert!((balanced.min_cut_value - 0.3).abs() < 1e-5);
+        let (dark, len) = balanced.sorted_side(if balanced.side(PartitionSide::S).contains(&4) {
+            PartitionSide::S
+        } else {
+            PartitionSide::Complement
+        });
+        assert_eq!(&dark[..len], &[4, 5, 6]);
+    }
+
+    #[test]
+    fn test_partition_k_bright_dark_and_bridge() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        // Bright rooms 1–3, dim hallway 4–5, dark rooms 6–8.
+        for hash in 1..=8 {
+            push_bare_node(&mut b, hash);
+        }
+        let zones: [&[usize]; 3] = [&[0, 1, 2], &[3, 4], &[5, 6, 7]];
+        for zone in zones {
+            for &i in zone {
+                for &j in zone {
+                    if i != j {
+                        b.adj[i][j] = 0.9;
+                    }
+                }
+            }
+        }
+        // The hallway bridges both sides thinly.
+        for (i, j, w) in [(2, 3, 0.2), (4, 5, 0.25)] {
+            b.adj[i][j] = w;
+            b.adj[j][i] = w;
+        }
+
+        let two = b.partition_k(2);
+        assert_eq!(two.len(), 2);
+        let three = b.partition_k(3);
+        assert!(!three.truncated);
+        let mut clusters: [[u32; 3]; 3] = [[0; 3]; 3];
+        for (out, cluster) in clusters.iter_mut().zip(three.iter()) {
+            out[..cluster.len()].copy_from_slice(cluster);
+            out[..cluster.len()].sort_unstable();
+        }
+        clusters.sort_unstable();
+        assert_eq!(clusters, [[1, 2, 3], [4, 5, 0], [6, 7, 8]]);
+        assert_eq!(three, b.partition_k_bounded(3, u32::MAX));
+    }
+
+    #[test]
+    fn test_partition_k_bounded_budget() {
+        let b = chain_boundary();
+        let full = b.partition_k_bounded(3, u32::MAX);
+        assert!(!full.truncated);
+        assert_eq!(full.len(), 3);
+        let mut sizes: [usize; 3] = core::array::from_fn(|i| full.cluster(i).len());
+        sizes.sort_unstable();
+        assert_eq!(sizes, [2, 2, 2]);
+        for cluster in full.iter() {
+            assert_eq!(cluster[1], cluster[0] + 1, "pairs stay together: {:?}", cluster);
+        }
+        // A budget of exactly k − 1 iterations matches the unbounded result.
+        assert_eq!(b.partition_k_bounded(3, 2), full);
+
+        // An exhausted budget degrades to the clustering found so far.
+        let partial = b.partition_k_bounded(3, 1);
+        assert!(partial.truncated);
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial.iter().map(|c| c.len()).sum::<usize>(), 6);
+
+        // More clusters than nodes: every node alone, not truncated.
+        let singles = b.partition_k_bounded(10, u32::MAX);
+        assert!(!singles.truncated);
+        assert_eq!(singles.len(), 6);
+        let empty: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        assert!(empty.partition_k_bounded(3, 5).is_empty());
+    }
+
+    #[test]
+    fn test_partition_subset_restricts_to_included_nodes() {
+        let keys = [bright_quiet(), bright_loud(), dark_quiet(), dark_loud()];
+        let all: [(ContextKey<MbotSensors, 6>, u32); 4] =
+            core::array::from_fn(|i| (keys[i].clone(), keys[i].context_hash_u32()));
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for (i, k) in keys.iter().enumerate() {
+            b.report_context_with_key(k, &all[..i]);
+        }
+
+        // Full set matches the global cut.
+        let every: [u32; 4] = core::array::from_fn(|i| all[i].1);
+        assert_eq!(b.partition_subset(&every).min_cut_value, b.min_cut_value());
+
+        // Three nodes plus an unknown hash: partition drawn only from the three.
+        let include = [all[0].1, all[1].1, all[2].1, 0xDEAD_BEEF];
+        let r = b.partition_subset(&include);
+        assert_eq!(r.partition_s_count + r.partition_complement_count, 3);
+        for h in r.partition_s[..r.partition_s_count]
+            .iter()
+            .chain(r.partition_complement[..r.partition_complement_count].iter())
+        {
+            assert!(include[..3].contains(h));
+        }
+
+        // Fewer than two known nodes: trivial zero cut.
+        let r = b.partition_subset(&[all[3].1, 0x1234]);
+        assert_eq!(r.min_cut_value, 0.0);
+        assert_eq!(r.partition_s_count, 0);
+        assert_eq!(r.partition_complement_count, 1);
+        assert_eq!(r.partition_complement[0], all[3].1);
+    }
+
+    fn result(s: &[u32], c: &[u32], value: f32) -> MinCutResult {
+        let mut r = MinCutResult {
+            min_cut_value: value,
+            partition_s_count: s.len(),
+            partition_s: [0; MAX_CONTEXTS],
+            partition_complement_count: c.len(),
+            partition_complement: [0; MAX_CONTEXTS],
+        };
+        r.partition_s[..s.len()].copy_from_slice(s);
+        r.partition_complement[..c.len()].copy_from_slice(c);
+        r
+    }
+
+    #[test]
+    fn test_partitions_equal_ignores_order_label_and_value() {
+        let a = result(&[3, 1], &[7, 5, 9], 0.4);
+        assert!(a.partitions_equal(&result(&[1, 3], &[9, 7, 5], 0.4)));
+        assert!(a.partitions_equal(&result(&[5, 9, 7], &[3, 1], 1.2)));
+        assert!(!a.partitions_equal(&result(&[1, 5], &[3, 7, 9], 0.4)));
+        let (sorted, len) = a.sorted_side(PartitionSide::Complement);
+        assert_eq!(&sorted[..len], &[5, 7, 9]);
+    }
+
+    fn push_bare_node<const C: usize>(b: &mut MinCutBoundary<MbotSensors, 6, C>, hash: u32) {
+        b.nodes[b.node_count] =
+            Some(NodeData { hash, key: None, coherence: 0.5, observations: 0, last_tick: 0 });
+        b.node_count += 1;
+        b.invalidate();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bridge_lists_cut_edges() {
+        let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        assert!(b.bridge().is_none());
+        for h in 1..=4u32 {
+            push_bare_node(&mut b, h);
+        }
+        // Two tight pairs {1,2} and {3,4} joined by weak links 2–3 and 1–4.
+        b.adj[0][1] = 0.9; b.adj[1][0] = 0.9;
+        b.adj[2][3] = 0.9; b.adj[3][2] = 0.9;
+        b.adj[1][2] = 0.1; b.adj[2][1] = 0.1;
+        b.adj[0][3] = 0.05; b.adj[3][0] = 0.05;
+
+        let bridge = b.bridge().expect("connected graph has a bridge");
+        assert_eq!(bridge.edges, b.cut_edges());
+        assert_eq!(bridge.edges.len(), 2);
+        let total: f32 = bridge.edges.iter().map(|e| e.2).sum();
+        assert!((total - b.min_cut_value()).abs() < 1e-6);
+        assert_eq!(bridge.weakest_node, None);
+
+        // A pendant node hanging off the cluster is named as the weakest link.
+        push_bare_node(&mut b, 5);
+        b.adj[3][4] = 0.02; b.adj[4][3] = 0.02;
+        let bridge = b.bridge().unwrap();
+        assert_eq!(bridge.weakest_node, Some(5));
+        assert_eq!(bridge.edges.len(), 1);
+        let (x, y, w) = bridge.edges[0];
+        assert_eq!((x.min(y), x.max(y), w), (4, 5, 0.02));
+    }
+
+    #[test]
+    fn test_symmetric_graph_partition_is_deterministic() {
+        let build = || {
+            let mut b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+            for h in 1..=4u32 {
+                push_bare_node(&mut b, h);
+            }
+            // Square 1–2–3–4–1 with equal weights: every 2+2 split of
+            // neighbours ties, as does cutting off any single node.
+            for (i, j) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+                b.adj[i][j] = 0.5;
+                b.adj[j][i] = 0.5;
+            }
+            b
+        };
+
+        let mut b = build();
+        let first = b.partition();
+        assert!((first.min_cut_value - 1.0).abs() < 1e-6);
+        for _ in 0..5 {
+            b.invalidate();
+            assert!(b.partition().partitions_equal(&first));
+            assert!(build().partition().partitions_equal(&first));
+        }
+        // Lowest-index tie-breaking isolates the last node added.
+        let (side, len) = first.sorted_side(PartitionSide::S);
+        assert_eq!(&side[..len], &[4]);
+    }
+
     #[test]
     fn test_empty_graph_returns_zero() {
         let b: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
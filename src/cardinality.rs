@@ -55,6 +55,7 @@
 //! - **I-CKM-003** — Eviction contributes coherence back to Tier 1 parent
 //! - **I-CKM-004** — Tier 1 class always exists; coarse history never silently lost
 //! - **I-CKM-005** — Tier 2 promotion requires interaction_count ≥ promotion_threshold
+//!   (or, when configured, coherence ≥ promotion_coherence)
 //! - **I-CKM-006** — Lookup falls through: Tier 2 first, then Tier 1
 //! - **I-CKM-007** — Total memory statically bounded; no heap allocation
 //! - **I-CKM-008** — Merge and eviction only in deliberative path; hot path zero-allocation
@@ -67,7 +68,8 @@
 //! - Claim C: eviction with coherence contribution
 //! - Claim D: merge-gate composition
 
-use crate::accumulator::CoherenceAccumulator;
+use crate::accumulator::{CoherenceAccumulator, ColdStartCurve};
+use crate::defaults::{BASELINE_PER_CURIOSITY, CONTEXT_WEIGHT, FAMILIARITY_THRESHOLD, INSTANT_WEIGHT};
 use crate::phase::Personality;
 use crate::vocabulary::{ContextKey, SensorVocabulary};
 use heapless::{FnvIndexMap, Vec as HVec};
@@ -87,10 +89,14 @@ pub type Tier1Key = u64;
 /// Bit i clear = zero out dimension i (coarse key ignores it).
 ///
 /// E.g. for a 6-dim vocabulary, `mask = 0b000111` uses dims 0, 1, 2 only.
+/// A mask covering every dimension yields [`ContextKey::context_hash_u64`].
 fn compute_tier1_key<V: SensorVocabulary<N>, const N: usize>(
     key: &ContextKey<V, N>,
     mask: u32,
 ) -> Tier1Key {
+    if N <= 32 && (0..N).all(|i| mask & (1 << i) != 0) {
+        return key.context_hash_u64();
+    }
     let features = key.vocabulary.to_feature_vec();
     // FNV-1a over the quantised, masked feature dimensions
     let mut h: u64 = 14_695_981_039_346_656_037;
@@ -116,6 +122,16 @@ pub struct TieredContextConfig {
     /// Default: 20.  At 20 interactions `earned_floor()` ≈ 0.25.
     pub promotion_threshold: u32,
 
+    /// Optional coherence at which a Tier 1 class promotes regardless of count.
+    ///
+    /// Promotion is an OR: a class activates Tier 2 once its coarse
+    /// accumulator reaches `promotion_threshold` interactions *or* (when set)
+    /// coherence `≥ promotion_coherence`, whichever comes first.  Lets a
+    /// context that earned trust quickly (e.g. alone bootstrap) promote early.
+    /// Default: `None` (count rule only).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub promotion_coherence: Option<f32>,
+
     /// Ticks of inactivity before a Tier 2 fine entry is eligible for eviction.
     /// Default: 50_000.
     pub eviction_staleness_ticks: u64,
@@ -133,16 +149,30 @@ pub struct TieredContextConfig {
     /// coherence back to the parent Tier 1 accumulator.
     /// Default: 0.1.  I-CKM-003.
     pub eviction_contribution_weight: f32,
+
+    /// Curve used to initialise new Tier 1 classes and Tier 2 entries.
+    /// Default: linear 0.15 × curiosity.
+    pub cold_start: ColdStartCurve,
+}
+
+impl TieredContextConfig {
+    /// Whether a coarse accumulator has earned Tier 2 promotion (I-CKM-005).
+    fn promotes(&self, acc: &CoherenceAccumulator) -> bool {
+        acc.interaction_count >= self.promotion_threshold
+            || self.promotion_coherence.is_some_and(|c| acc.value >= c)
+    }
 }
 
 impl Default for TieredContextConfig {
     fn default() -> Self {
         Self {
             promotion_threshold: 20,
+            promotion_coherence: None,
             eviction_staleness_ticks: 50_000,
             eviction_min_count: 3,
             tier1_feature_mask: 0xFFFF_FFFF,
             eviction_contribution_weight: 0.1,
+            cold_start: ColdStartCurve::default(),
         }
     }
 }
@@ -159,6 +189,7 @@ impl Default for TieredContextConfig {
 /// - `coherence = min(a, b)` — never grant unearned familiarity (I-CKM-001)
 /// - `interaction_count = a + b` — never erase relational history (I-CKM-002)
 /// - `last_interaction_tick = max(a, b)` — preserve freshness
+/// - `peak_value = min(a, b)` — the merged history never claims a higher peak
 ///
 /// This asymmetric treatment (conservative on trust, cumulative on history) is
 /// the unique combination that respects both the honesty invariant and the
@@ -173,11 +204,7 @@ pub fn merge_accumulators(
     a: &CoherenceAccumulator,
     b: &CoherenceAccumulator,
 ) -> CoherenceAccumulator {
-    CoherenceAccumulator {
-        value: a.value.min(b.value),
-        interaction_count: a.interaction_count.saturating_add(b.interaction_count),
-        last_interaction_tick: a.last_interaction_tick.max(b.last_interaction_tick),
-    }
+    a.merged(b)
 }
 
 // ─── Tier1Class ───────────────────────────────────────────────────────────────
@@ -198,7 +225,8 @@ where
     pub accumulator: CoherenceAccumulator,
 
     /// Whether fine-grained Tier 2 entries are active for this class.
-    /// Activated when `accumulator.interaction_count >= promotion_threshold`.
+    /// Activated when `accumulator.interaction_count >= promotion_threshold`
+    /// or `accumulator.value >= promotion_coherence` (if configured).
     pub tier2_active: bool,
 
     /// Fine Tier 2 entries keyed by full `ContextKey<V, N>`.
@@ -218,6 +246,47 @@ where
     }
 }
 
+// ─── Stats ────────────────────────────────────────────────────────────────────
+
+/// Summary of one tier, from [`TieredContextMap::stats`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TierStats {
+    /// Number of accumulators in the tier.
+    pub entries: usize,
+    /// Sum of their positive interaction counts.
+    pub total_interactions: u64,
+    /// Mean coherence across the tier (0.0 when empty).
+    pub mean_coherence: f32,
+}
+
+#[cfg(feature = "std")]
+impl TierStats {
+    fn from_accumulators<'a>(accs: impl Iterator<Item = &'a CoherenceAccumulator>) -> Self {
+        let mut stats = Self::default();
+        let mut sum = 0.0f32;
+        for acc in accs {
+            stats.entries += 1;
+            stats.total_interactions += u64::from(acc.interaction_count);
+            sum += acc.value;
+        }
+        if stats.entries > 0 {
+            stats.mean_coherence = sum / stats.entries as f32;
+        }
+        stats
+    }
+}
+
+/// Per-tier summary of a [`TieredContextMap`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TieredStats {
+    /// Tier 1 coarse classes.
+    pub tier1: TierStats,
+    /// Tier 2 fine entries across all classes.
+    pub tier2: TierStats,
+}
+
 // ─── TieredContextMap ─────────────────────────────────────────────────────────
 
 /// Two-tier cardinality-bounded context map.
@@ -282,10 +351,10 @@ where
     /// I-CKM-006: always resolves to a value, never "unknown."
     pub fn effective_coherence(&self, instant: f32, key: &ContextKey<V, N>) -> f32 {
         let ctx = self.context_coherence(key);
-        if ctx < 0.3 {
+        if ctx < FAMILIARITY_THRESHOLD {
             if instant < ctx { instant } else { ctx }
         } else {
-            (0.3 * instant + 0.7 * ctx).clamp(0.0, 1.0)
+            (INSTANT_WEIGHT * instant + CONTEXT_WEIGHT * ctx).clamp(0.0, 1.0)
         }
     }
 
@@ -328,6 +397,8 @@ where
 
     /// Record a positive interaction for a context.
     ///
+    /// Growth is scaled by [`Personality::curiosity_growth_factor`], as in
+    /// [`crate::accumulator::CoherenceField::positive_interaction`].
     /// Always updates the Tier 1 coarse accumulator (I-CKM-004).
     /// Also updates the Tier 2 fine entry if Tier 2 is active.
     /// May activate Tier 2 or insert a new fine entry.
@@ -343,15 +414,14 @@ where
         let t1k = compute_tier1_key(key, self.config.tier1_feature_mask);
         self.ensure_tier1_class(t1k);
 
+        let (speed, gain) = (personality.recovery_speed, personality.curiosity_growth_factor());
         let cls = self.classes.get_mut(&t1k).unwrap();
 
         // Always update coarse accumulator (I-CKM-004)
-        cls.accumulator.positive_interaction(personality.recovery_speed, tick, alone);
+        cls.accumulator.grow(speed, tick, alone, gain, FAMILIARITY_THRESHOLD);
 
-        // Maybe activate Tier 2
-        if !cls.tier2_active
-            && cls.accumulator.interaction_count >= self.config.promotion_threshold
-        {
+        // Maybe activate Tier 2: count threshold OR coherence threshold
+        if !cls.tier2_active && self.config.promotes(&cls.accumulator) {
             cls.tier2_active = true;
         }
 
@@ -360,17 +430,18 @@ where
                 cls.tier2_entries
                     .get_mut(key)
                     .unwrap()
-                    .positive_interaction(personality.recovery_speed, tick, alone);
+                    .grow(speed, tick, alone, gain, FAMILIARITY_THRESHOLD);
             } else {
                 // Ensure room in Tier 2
                 if cls.tier2_entries.len() >= T2 {
                     self.evict_weakest_tier2_entry(t1k);
                 }
                 if let Some(cls2) = self.classes.get_mut(&t1k) {
-                    let mut new_acc = CoherenceAccumulator::new_with_baseline(
-                        (self.personality_baseline / 0.15).clamp(0.0, 1.0),
+                    let mut new_acc = CoherenceAccumulator::new_with_curve(
+                        (self.personality_baseline / BASELINE_PER_CURIOSITY).clamp(0.0, 1.0),
+                        &self.config.cold_start,
                     );
-                    new_acc.positive_interaction(personality.recovery_speed, tick, alone);
+                    new_acc.grow(speed, tick, alone, gain, FAMILIARITY_THRESHOLD);
                     let _ = cls2.tier2_entries.insert(key.clone(), new_acc);
                 }
             }
@@ -437,6 +508,72 @@ where
         self.classes.values().map(|cls| cls.tier2_entries.len()).sum()
     }
 
+    /// The Tier 1 coarse key `key` belongs to under the configured feature mask.
+    pub fn tier1_key_of(&self, key: &ContextKey<V, N>) -> Tier1Key {
+        compute_tier1_key(key, self.config.tier1_feature_mask)
+    }
+
+    /// Iterate over every Tier 1 class with its coarse key.
+    pub fn iter_tier1(&self) -> impl Iterator<Item = (Tier1Key, &Tier1Class<V, N, T2>)> {
+        self.classes.iter().map(|(k, cls)| (*k, cls))
+    }
+
+    /// Iterate over the fine Tier 2 entries of the class `key` (empty if the
+    /// class is unknown or has none).
+    pub fn tier2_entries_of(
+        &self,
+        key: Tier1Key,
+    ) -> impl Iterator<Item = (&ContextKey<V, N>, &CoherenceAccumulator)> {
+        self.classes.get(&key).into_iter().flat_map(|cls| cls.tier2_entries.iter())
+    }
+
+    /// Per-tier totals for dashboards: entry count, summed interaction counts
+    /// and mean coherence of the Tier 1 classes and of all Tier 2 entries.
+    #[cfg(feature = "std")]
+    pub fn stats(&self) -> TieredStats {
+        let tier1 = TierStats::from_accumulators(self.classes.values().map(|cls| &cls.accumulator));
+        let tier2 = TierStats::from_accumulators(
+            self.classes.values().flat_map(|cls| cls.tier2_entries.values()),
+        );
+        TieredStats { tier1, tier2 }
+    }
+
+    // ── Restore ───────────────────────────────────────────────────────────
+
+    /// Install `acc` as the coarse accumulator of class `key`, creating the
+    /// class (evicting LRU if necessary) when it does not exist yet.
+    ///
+    /// Used to restore persisted state; live updates go through the
+    /// interaction API.
+    pub fn insert_tier1_accumulator(&mut self, key: Tier1Key, acc: CoherenceAccumulator) {
+        self.ensure_tier1_class(key);
+        if let Some(cls) = self.classes.get_mut(&key) {
+            cls.accumulator = acc;
+        }
+    }
+
+    /// Install `acc` as the fine Tier 2 entry for `key`, activating Tier 2 on
+    /// its class.  Evicts the weakest entry, with contribution, if the class
+    /// is full (I-CKM-003).
+    ///
+    /// Restore the class with [`Self::insert_tier1_accumulator`] first: a
+    /// missing class is created on the cold-start curve.
+    pub fn insert_tier2_accumulator(&mut self, key: &ContextKey<V, N>, acc: CoherenceAccumulator) {
+        let t1k = compute_tier1_key(key, self.config.tier1_feature_mask);
+        self.ensure_tier1_class(t1k);
+        let needs_room = self
+            .classes
+            .get(&t1k)
+            .is_some_and(|cls| !cls.tier2_entries.contains_key(key) && cls.tier2_entries.len() >= T2);
+        if needs_room {
+            self.evict_weakest_tier2_entry(t1k);
+        }
+        if let Some(cls) = self.classes.get_mut(&t1k) {
+            cls.tier2_active = true;
+            let _ = cls.tier2_entries.insert(key.clone(), acc);
+        }
+    }
+
     // ── Internal helpers ──────────────────────────────────────────────────
 
     /// Ensure a Tier 1 class exists for `t1k`, evicting LRU if necessary.
@@ -448,8 +585,9 @@ where
             self.evict_lru_tier1_class();
         }
         let mut cls = Tier1Class::new();
-        cls.accumulator = CoherenceAccumulator::new_with_baseline(
-            (self.personality_baseline / 0.15).clamp(0.0, 1.0),
+        cls.accumulator = CoherenceAccumulator::new_with_curve(
+            (self.personality_baseline / BASELINE_PER_CURIOSITY).clamp(0.0, 1.0),
+            &self.config.cold_start,
         );
         let _ = self.classes.insert(t1k, cls);
     }
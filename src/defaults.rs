@@ -0,0 +1,145 @@
+//! Default behavioural constants in one place.
+//!
+//! Every number that shapes CCF dynamics lives here under a name, and the
+//! [`accumulator`](crate::accumulator), [`boundary`](crate::boundary) and
+//! [`phase`](crate::phase) modules reference these constants rather than inline
+//! literals. Use them in your own code instead of hard-coding values:
+//!
+//! ```rust
+//! use ccf_core::defaults::FAMILIARITY_THRESHOLD;
+//!
+//! let ctx = 0.42_f32;
+//! let familiar = ctx >= FAMILIARITY_THRESHOLD;
+//! # assert!(familiar);
+//! ```
+//!
+//! [`TABLE`] lists every scalar by name for documentation and tuning tools.
+
+// ─── Accumulator dynamics ───────────────────────────────────────────────────
+
+/// Base coherence gain of one positive interaction, before personality and
+/// asymptotic `(1 − value)` scaling.
+pub const POSITIVE_DELTA_BASE: f32 = 0.02;
+
+/// Multiplier on the positive delta when the robot is alone (faster bootstrap).
+pub const ALONE_BOOST: f32 = 2.0;
+
+/// Base coherence drop of one negative interaction, before personality scaling.
+pub const NEGATIVE_DELTA_BASE: f32 = 0.05;
+
+/// Coherence lost per elapsed tick of disuse.
+pub const DECAY_PER_TICK: f32 = 0.0001;
+
+/// Distance above the earned floor within which a context is at rest and
+/// decay is a no-op.
+pub const DECAY_DEAD_ZONE: f32 = 1e-4;
+
+/// Asymptotic limit of the earned floor (CCF-002).
+pub const EARNED_FLOOR_MAX: f32 = 0.5;
+
+/// Interaction count at which the earned floor reaches half of [`EARNED_FLOOR_MAX`].
+pub const EARNED_FLOOR_HALF_COUNT: f32 = 20.0;
+
+/// Cold-start baseline per unit of `curiosity_drive` (baseline = 0.15 × curiosity).
+pub const BASELINE_PER_CURIOSITY: f32 = 0.15;
+
+/// Growth of the positive delta per unit of `curiosity_drive` away from 0.5
+/// (delta × `1 + 0.2 × (curiosity − 0.5)`, i.e. 0.9× to 1.1×).
+pub const CURIOSITY_GROWTH_WEIGHT: f32 = 0.2;
+
+/// Smoothing factor of the recent-interaction EMA behind the trust trend.
+pub const TREND_EMA_ALPHA: f32 = 0.1;
+
+/// Band around zero within which the recent-interaction EMA reads as a stable trend.
+pub const TREND_DEADBAND: f32 = 0.05;
+
+// ─── CCF-001 asymmetric gate ────────────────────────────────────────────────
+
+/// Coherence at which a context counts as familiar under the CCF-001 gate.
+pub const FAMILIARITY_THRESHOLD: f32 = 0.3;
+
+/// Weight of the instant reading in the familiar-context blend.
+pub const INSTANT_WEIGHT: f32 = 0.3;
+
+/// Weight of accumulated context trust in the familiar-context blend.
+pub const CONTEXT_WEIGHT: f32 = 0.7;
+
+// ─── Boundary graph ─────────────────────────────────────────────────────────
+
+/// Minimum cosine similarity for a boundary edge to be inserted (I-BNDRY-003).
+pub const EDGE_THRESHOLD: f32 = 0.1;
+
+/// Minimum positive interactions before the trust component activates (I-TRUST-001).
+pub const MIN_TRUST_OBSERVATIONS: u32 = 50;
+
+/// Trust scale factor in the Graph B edge weight formula.
+pub const TRUST_SCALE: f32 = 2.0;
+
+/// Per-tick staleness decay of Graph B edges (0.0 = recency ignored).
+pub const BOUNDARY_RECENCY_LAMBDA: f32 = 0.0;
+
+// ─── Phase thresholds (CCF-004) ─────────────────────────────────────────────
+
+/// Coherence to enter the high-coherence quadrants.
+pub const COHERENCE_HIGH_ENTER: f32 = 0.65;
+
+/// Coherence below which the high-coherence quadrants are left.
+pub const COHERENCE_HIGH_EXIT: f32 = 0.55;
+
+/// Tension to enter the high-tension quadrants.
+pub const TENSION_HIGH_ENTER: f32 = 0.45;
+
+/// Tension below which the high-tension quadrants are left.
+pub const TENSION_HIGH_EXIT: f32 = 0.35;
+
+// ─── Table ──────────────────────────────────────────────────────────────────
+
+/// Every default scalar by name, in declaration order.
+pub const TABLE: &[(&str, f32)] = &[
+    ("POSITIVE_DELTA_BASE", POSITIVE_DELTA_BASE),
+    ("ALONE_BOOST", ALONE_BOOST),
+    ("NEGATIVE_DELTA_BASE", NEGATIVE_DELTA_BASE),
+    ("DECAY_PER_TICK", DECAY_PER_TICK),
+    ("DECAY_DEAD_ZONE", DECAY_DEAD_ZONE),
+    ("EARNED_FLOOR_MAX", EARNED_FLOOR_MAX),
+    ("EARNED_FLOOR_HALF_COUNT", EARNED_FLOOR_HALF_COUNT),
+    ("BASELINE_PER_CURIOSITY", BASELINE_PER_CURIOSITY),
+    ("CURIOSITY_GROWTH_WEIGHT", CURIOSITY_GROWTH_WEIGHT),
+    ("TREND_EMA_ALPHA", TREND_EMA_ALPHA),
+    ("TREND_DEADBAND", TREND_DEADBAND),
+    ("FAMILIARITY_THRESHOLD", FAMILIARITY_THRESHOLD),
+    ("INSTANT_WEIGHT", INSTANT_WEIGHT),
+    ("CONTEXT_WEIGHT", CONTEXT_WEIGHT),
+    ("EDGE_THRESHOLD", EDGE_THRESHOLD),
+    ("MIN_TRUST_OBSERVATIONS", MIN_TRUST_OBSERVATIONS as f32),
+    ("TRUST_SCALE", TRUST_SCALE),
+    ("BOUNDARY_RECENCY_LAMBDA", BOUNDARY_RECENCY_LAMBDA),
+    ("COHERENCE_HIGH_ENTER", COHERENCE_HIGH_ENTER),
+    ("COHERENCE_HIGH_EXIT", COHERENCE_HIGH_EXIT),
+    ("TENSION_HIGH_ENTER", TENSION_HIGH_ENTER),
+    ("TENSION_HIGH_EXIT", TENSION_HIGH_EXIT),
+];
+
+/// Look up a default by its constant name, e.g. `lookup("TRUST_SCALE")`.
+pub fn lookup(name: &str) -> Option<f32> {
+    TABLE.iter().find(|(n, _)| *n == name).map(|&(_, v)| v)
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gate_weights_sum_to_one() {
+        assert_eq!(INSTANT_WEIGHT + CONTEXT_WEIGHT, 1.0);
+    }
+
+    #[test]
+    fn test_lookup_matches_constants() {
+        assert_eq!(lookup("FAMILIARITY_THRESHOLD"), Some(FAMILIARITY_THRESHOLD));
+        assert_eq!(lookup("MIN_TRUST_OBSERVATIONS"), Some(50.0));
+        assert_eq!(lookup("NOT_A_CONSTANT"), None);
+    }
+}
@@ -7,12 +7,13 @@
 //!
 //! ```bash
 //! pip install maturin
-//! maturin develop --features python-ffi
+//! maturin develop --features extension-module
 //! ```
 //!
 //! # Usage
 //!
 //! ```python
+//! import pickle
 //! from ccf_core import CoherenceField, Personality, SocialPhase, PhaseSpace
 //!
 //! personality = Personality(curiosity_drive=0.6, startle_sensitivity=0.5, recovery_speed=0.5)
@@ -24,22 +25,59 @@
 //! features = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]
 //! field.positive_interaction(features, personality, tick=0, alone=False)
 //! coherence = field.effective_coherence(0.9, features)
+//! field.decay_all(100)           # age every context by 100 ticks
+//! print(field.context_count(), field.context_coherence(features))
+//! restored = pickle.loads(pickle.dumps(field))  # checkpoint via CCF_SEG
 //! phase = SocialPhase.classify(coherence, 0.2, phase, ps)
 //! print(phase.led_tint())        # [r, g, b]
 //! print(phase.expression_scale()) # 0.0–1.0
 //! ```
+//!
+//! With the `python-numpy` feature, every `feature_vec` argument also accepts
+//! a 1-D float32 numpy array, read in place instead of copied into a list:
+//!
+//! ```python
+//! features = np.array([0.8, 0.0, 1.0, 0.0, 1.0, 0.5], dtype=np.float32)
+//! field.positive_interaction(features, personality, tick=1, alone=False)
+//! ```
+//!
+//! and `SinkhornKnopp.project` also takes float32 numpy matrices, returning
+//! the residual as well (a list of rows works with or without numpy):
+//!
+//! ```python
+//! import numpy as np
+//! from ccf_core import SinkhornKnopp
+//!
+//! rows, converged, iterations = SinkhornKnopp().project([[1.0, 2.0], [3.0, 4.0]])
+//! m = np.array([[1.0, 2.0], [3.0, 4.0]], dtype=np.float32)
+//! ds, converged, iterations, residual = SinkhornKnopp().project(m)
+//! assert np.allclose(ds.sum(axis=0), 1.0) and np.allclose(ds.sum(axis=1), 1.0)
+//! ```
 
 #![allow(non_snake_case)]
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 
 use crate::accumulator::CoherenceField;
 use crate::phase::{
     Personality as RustPersonality, PhaseSpace as RustPhaseSpace, SocialPhase as RustSocialPhase,
 };
+use crate::seg::{CcfSegSnapshot, ChecksumStatus};
+use crate::sinkhorn::SinkhornKnopp;
 use crate::vocabulary::{ContextKey, SensorVocabulary};
 
+#[cfg(feature = "python-numpy")]
+use numpy::{
+    ndarray::Array2, IntoPyArray, PyArray1, PyArray2, PyArrayMethods, PyReadonlyArray2,
+    PyUntypedArray, PyUntypedArrayMethods,
+};
+#[cfg(feature = "python-numpy")]
+use pyo3::exceptions::PyTypeError;
+#[cfg(feature = "python-numpy")]
+use pyo3::types::{PyList, PyTuple};
+
 /// Dimensionality of the Python-facing feature vector.
 /// Matches the mBot2 6-sensor vocabulary. Use the Rust API for other dimensions.
 const PY_DIM: usize = 6;
@@ -75,6 +113,52 @@ fn features_to_key(features: &[f32]) -> PyResult<ContextKey<PyVocab, PY_DIM>> {
     Ok(ContextKey::new(PyVocab(q)))
 }
 
+/// `ob` as a numpy array, or `None` if it is not one.
+///
+/// Lists and tuples, and everything when numpy cannot be imported, return
+/// `None` without touching the numpy C API, whose first use panics if numpy
+/// is not installed.
+#[cfg(feature = "python-numpy")]
+fn numpy_array<'a, 'py>(ob: &'a Bound<'py, PyAny>) -> Option<&'a Bound<'py, PyUntypedArray>> {
+    if ob.is_instance_of::<PyList>() || ob.is_instance_of::<PyTuple>() {
+        return None;
+    }
+    if ob.py().import_bound("numpy").is_err() {
+        return None;
+    }
+    ob.downcast::<PyUntypedArray>().ok()
+}
+
+/// A 6-element feature vector passed from Python, already quantised to a context key.
+///
+/// Accepts any sequence of floats. With the `python-numpy` feature a 1-D
+/// float32 `numpy.ndarray` is also accepted and read in place, without the
+/// copy into a Python list; arrays of any other dtype or shape raise TypeError.
+pub struct PyFeatures(ContextKey<PyVocab, PY_DIM>);
+
+impl<'py> FromPyObject<'py> for PyFeatures {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        #[cfg(feature = "python-numpy")]
+        if let Some(array) = numpy_array(ob) {
+            let array = array.downcast::<PyArray1<f32>>().map_err(|_| {
+                PyTypeError::new_err(format!(
+                    "feature array must be 1-D float32, got {}-D {}",
+                    array.ndim(),
+                    array.dtype()
+                ))
+            })?;
+            let view = array.readonly();
+            return match view.as_slice() {
+                Ok(features) => features_to_key(features),
+                Err(_) => features_to_key(&view.as_array().to_vec()),
+            }
+            .map(Self);
+        }
+        let features: Vec<f32> = ob.extract()?;
+        features_to_key(&features).map(Self)
+    }
+}
+
 // ── Personality ───────────────────────────────────────────────────────────────
 
 /// Personality modulators — tune how trust builds and erodes.
@@ -97,13 +181,7 @@ impl PyPersonality {
     #[new]
     #[pyo3(signature = (curiosity_drive=0.5, startle_sensitivity=0.5, recovery_speed=0.5))]
     pub fn new(curiosity_drive: f32, startle_sensitivity: f32, recovery_speed: f32) -> Self {
-        Self {
-            inner: RustPersonality {
-                curiosity_drive: curiosity_drive.clamp(0.0, 1.0),
-                startle_sensitivity: startle_sensitivity.clamp(0.0, 1.0),
-                recovery_speed: recovery_speed.clamp(0.0, 1.0),
-            },
-        }
+        Self { inner: RustPersonality::new_clamped(curiosity_drive, startle_sensitivity, recovery_speed) }
     }
 
     /// Returns the curiosity drive modulator in [0.0, 1.0].
@@ -269,17 +347,19 @@ impl PySocialPhase {
 /// All interaction methods take a 6-element feature vector representing
 /// the current sensory state (values in [0.0, 1.0]).
 ///
-/// Example::
+/// Example:
 ///
-///     field = CoherenceField()
-///     personality = Personality(curiosity_drive=0.7)
-///     features = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]  # bright, quiet, close, still, upright, day
+/// ```python
+/// field = CoherenceField()
+/// personality = Personality(curiosity_drive=0.7)
+/// features = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]  # bright, quiet, close, still, upright, day
 ///
-///     for tick in range(50):
-///         field.positive_interaction(features, personality, tick=tick, alone=False)
+/// for tick in range(50):
+///     field.positive_interaction(features, personality, tick=tick, alone=False)
 ///
-///     print(field.effective_coherence(0.9, features))  # → ~0.7
-#[pyclass(name = "CoherenceField")]
+/// print(field.effective_coherence(0.9, features))  # → ~0.7
+/// ```
+#[pyclass(name = "CoherenceField", module = "ccf_core")]
 pub struct PyCoherenceField {
     inner: CoherenceField<PyVocab, PY_DIM>,
 }
@@ -303,12 +383,12 @@ impl PyCoherenceField {
     ///     alone:       True if no external stimulus (passive presence only)
     pub fn positive_interaction(
         &mut self,
-        feature_vec: Vec<f32>,
+        feature_vec: PyFeatures,
         personality: &PyPersonality,
         tick: u64,
         alone: bool,
     ) -> PyResult<()> {
-        let key = features_to_key(&feature_vec)?;
+        let key = feature_vec.0;
         self.inner
             .positive_interaction(&key, &personality.inner, tick, alone);
         Ok(())
@@ -322,11 +402,11 @@ impl PyCoherenceField {
     ///     tick:        monotonic tick counter (u64)
     pub fn negative_interaction(
         &mut self,
-        feature_vec: Vec<f32>,
+        feature_vec: PyFeatures,
         personality: &PyPersonality,
         tick: u64,
     ) -> PyResult<()> {
-        let key = features_to_key(&feature_vec)?;
+        let key = feature_vec.0;
         self.inner
             .negative_interaction(&key, &personality.inner, tick);
         Ok(())
@@ -343,17 +423,221 @@ impl PyCoherenceField {
     ///
     /// Returns:
     ///     Effective coherence in [0.0, 1.0]
-    pub fn effective_coherence(&self, instant: f32, feature_vec: Vec<f32>) -> PyResult<f32> {
-        let key = features_to_key(&feature_vec)?;
+    pub fn effective_coherence(&self, instant: f32, feature_vec: PyFeatures) -> PyResult<f32> {
+        let key = feature_vec.0;
         Ok(self.inner.effective_coherence(instant, &key))
     }
 
+    /// Age every context by `elapsed_ticks` of disuse.
+    ///
+    /// Args:
+    ///     elapsed_ticks: ticks since the last decay (u64)
+    pub fn decay_all(&mut self, elapsed_ticks: u64) {
+        self.inner.decay_all(elapsed_ticks);
+    }
+
+    /// Accumulated trust for a sensory context, without the instant gate.
+    ///
+    /// Args:
+    ///     feature_vec: 6 floats in [0.0, 1.0] — sensory state to look up
+    ///
+    /// Returns:
+    ///     Coherence in [0.0, 1.0] (0.0 for an unseen context)
+    pub fn context_coherence(&self, feature_vec: PyFeatures) -> PyResult<f32> {
+        let key = feature_vec.0;
+        Ok(self.inner.context_coherence(&key))
+    }
+
+    /// Positive interactions recorded for a sensory context.
+    ///
+    /// Args:
+    ///     feature_vec: 6 floats in [0.0, 1.0] — sensory state to look up
+    pub fn context_interaction_count(&self, feature_vec: PyFeatures) -> PyResult<u32> {
+        let key = feature_vec.0;
+        Ok(self.inner.context_interaction_count(&key))
+    }
+
+    /// Number of distinct sensory contexts tracked.
+    pub fn context_count(&self) -> usize {
+        self.inner.context_count()
+    }
+
+    /// Pickle support: `(CCF_SEG snapshot bytes, quantised context keys, cold-start baseline)`.
+    ///
+    /// The snapshot stores context hashes only, so the quantised keys travel
+    /// alongside it to name the contexts on restore.
+    pub fn __getstate__<'py>(&self, py: Python<'py>) -> (Bound<'py, PyBytes>, Vec<[u16; PY_DIM]>, f32) {
+        let snapshot = CcfSegSnapshot::from_field(&self.inner, &RustPersonality::new(), 0, 0, 0);
+        let keys = self.inner.iter().map(|(key, _)| key.vocabulary.0).collect();
+        (PyBytes::new_bound(py, &snapshot.to_bytes()), keys, self.inner.personality_baseline())
+    }
+
+    /// Restore state produced by [`Self::__getstate__`] (used by `pickle.load`).
+    pub fn __setstate__(&mut self, state: (Vec<u8>, Vec<[u16; PY_DIM]>, f32)) -> PyResult<()> {
+        let (bytes, keys, baseline) = state;
+        let (snapshot, status) = CcfSegSnapshot::from_bytes_checked(&bytes)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        // __getstate__ always writes a checksum, so a missing one means tampering.
+        if status == ChecksumStatus::Missing {
+            return Err(PyValueError::new_err("pickled CoherenceField state has no checksum"));
+        }
+        let keys: Vec<_> = keys.into_iter().map(|q| ContextKey::new(PyVocab(q))).collect();
+        let mut field = CoherenceField::new();
+        snapshot
+            .restore_into(&mut field, &keys)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        field.set_personality_baseline(baseline);
+        self.inner = field;
+        Ok(())
+    }
+
     /// Python repr string.
     pub fn __repr__(&self) -> &'static str {
         "CoherenceField()"
     }
 }
 
+// ── SinkhornKnopp ─────────────────────────────────────────────────────────────
+
+/// Birkhoff polytope projector — makes a square matrix doubly stochastic.
+///
+/// Example:
+///
+/// ```python
+/// sk = SinkhornKnopp(tolerance=1e-6, max_iterations=20)
+/// rows, converged, iterations = sk.project([[1.0, 2.0], [3.0, 4.0]])
+/// # with python-numpy:
+/// ds, converged, iterations, residual = sk.project(np.ones((4, 4), dtype=np.float32))
+/// ```
+#[pyclass(name = "SinkhornKnopp")]
+#[derive(Clone)]
+pub struct PySinkhornKnopp {
+    inner: SinkhornKnopp,
+}
+
+#[pymethods]
+impl PySinkhornKnopp {
+    /// Create a projector.
+    ///
+    /// Args:
+    ///     tolerance:      stop when every row sum is within this of 1.0 (default 1e-6)
+    ///     max_iterations: cap on row+column normalisation passes (default 20)
+    #[new]
+    #[pyo3(signature = (tolerance=1e-6, max_iterations=20))]
+    pub fn new(tolerance: f32, max_iterations: u32) -> Self {
+        Self {
+            inner: SinkhornKnopp::new(tolerance, max_iterations),
+        }
+    }
+
+    /// Returns the convergence tolerance.
+    #[getter]
+    pub fn tolerance(&self) -> f32 {
+        self.inner.tolerance
+    }
+    /// Returns the iteration cap.
+    #[getter]
+    pub fn max_iterations(&self) -> u32 {
+        self.inner.max_iterations
+    }
+
+    /// Project a square, non-negative matrix given as a list of rows.
+    ///
+    /// Args:
+    ///     matrix: n lists of n floats
+    ///
+    /// Returns:
+    ///     (projected matrix as a list of rows, converged, iterations)
+    #[cfg(not(feature = "python-numpy"))]
+    pub fn project(&self, matrix: Vec<Vec<f32>>) -> PyResult<(Vec<Vec<f32>>, bool, usize)> {
+        self.project_rows(matrix)
+    }
+
+    /// Project a square, non-negative matrix onto the Birkhoff polytope.
+    ///
+    /// A list of rows returns ``(rows, converged, iterations)``. A float32
+    /// numpy matrix is read without modification and returns
+    /// ``(projected matrix, converged, iterations, residual)``; arrays of
+    /// other dtypes or shapes raise TypeError — convert with
+    /// ``np.asarray(m, dtype=np.float32)``.
+    ///
+    /// Args:
+    ///     matrix: n lists of n floats, or a 2D numpy array of shape (n, n)
+    #[cfg(feature = "python-numpy")]
+    pub fn project(&self, py: Python<'_>, matrix: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if let Some(array) = numpy_array(matrix) {
+            let array = array.downcast::<PyArray2<f32>>().map_err(|_| {
+                PyTypeError::new_err(format!(
+                    "matrix array must be 2-D float32, got {}-D {}",
+                    array.ndim(),
+                    array.dtype()
+                ))
+            })?;
+            return self.project_array(py, array.readonly());
+        }
+        Ok(self.project_rows(matrix.extract()?)?.into_py(py))
+    }
+
+    /// Python repr string.
+    pub fn __repr__(&self) -> String {
+        format!(
+            "SinkhornKnopp(tolerance={:e}, max_iterations={})",
+            self.inner.tolerance, self.inner.max_iterations,
+        )
+    }
+}
+
+impl PySinkhornKnopp {
+    /// Validate and project a list of rows; `(rows, converged, iterations)`.
+    fn project_rows(&self, matrix: Vec<Vec<f32>>) -> PyResult<(Vec<Vec<f32>>, bool, usize)> {
+        let n = matrix.len();
+        if let Some(row) = matrix.iter().find(|row| row.len() != n) {
+            return Err(PyValueError::new_err(format!(
+                "matrix must be square, got {n} rows and a row of length {}",
+                row.len()
+            )));
+        }
+        let mut flat: Vec<f32> = matrix.into_iter().flatten().collect();
+        check_entries(flat.iter().copied())?;
+        let result = self.inner.project_flat(&mut flat, n);
+        let rows = flat.chunks(n.max(1)).map(<[f32]>::to_vec).collect();
+        Ok((rows, result.converged, result.iterations as usize))
+    }
+
+    /// Validate and project a float32 matrix; `(matrix, converged, iterations, residual)`.
+    #[cfg(feature = "python-numpy")]
+    fn project_array(&self, py: Python<'_>, matrix: PyReadonlyArray2<'_, f32>) -> PyResult<PyObject> {
+        let view = matrix.as_array();
+        let (rows, cols) = view.dim();
+        if rows != cols {
+            return Err(PyValueError::new_err(format!(
+                "matrix must be square, got shape ({rows}, {cols})"
+            )));
+        }
+        check_entries(view.iter().copied())?;
+        // Logical (row-major) order regardless of the input's memory layout.
+        let mut flat: Vec<f32> = view.iter().copied().collect();
+        let result = self.inner.project_flat(&mut flat, rows);
+        let out = Array2::from_shape_vec((rows, cols), flat)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((
+            out.into_pyarray_bound(py),
+            result.converged,
+            result.iterations as usize,
+            result.residual,
+        )
+            .into_py(py))
+    }
+}
+
+/// Reject NaN and negative matrix entries.
+fn check_entries(mut entries: impl Iterator<Item = f32>) -> PyResult<()> {
+    if entries.any(|x| x.is_nan() || x < 0.0) {
+        return Err(PyValueError::new_err("matrix entries must be non-negative and not NaN"));
+    }
+    Ok(())
+}
+
 // ── Module entry point ────────────────────────────────────────────────────────
 
 /// CCF — Contextual Coherence Fields Python bindings.
@@ -367,6 +651,7 @@ pub fn ccf_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyPhaseSpace>()?;
     m.add_class::<PySocialPhase>()?;
     m.add_class::<PyCoherenceField>()?;
+    m.add_class::<PySinkhornKnopp>()?;
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add("FEATURE_DIM", PY_DIM)?;
     Ok(())
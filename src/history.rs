@@ -0,0 +1,166 @@
+//! Bounded per-context interaction history for trust-trajectory analytics.
+//!
+//! The accumulator itself keeps only scalar summaries. When enabled through
+//! [`FieldConfig::history`](crate::accumulator::FieldConfig::history), the
+//! coherence field additionally records the last K interaction outcomes of
+//! selected contexts in a [`HistoryBuffer`].
+//!
+//! Recording is pure observation: it never feeds back into accumulator values.
+//!
+//! # Invariants
+//! - **I-DIST-001** — no_std compatible; fixed-size arrays, no heap allocation
+//! - **I-DIST-005** — Zero unsafe code
+
+/// Number of interaction records retained per context by
+/// [`CoherenceField`](crate::accumulator::CoherenceField).
+pub const HISTORY_CAPACITY: usize = 32;
+
+/// Which contexts a coherence field records history for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HistoryPolicy {
+    /// No history is recorded (default) — zero cost.
+    #[default]
+    Disabled,
+    /// Only contexts explicitly pinned with `pin_history` are recorded.
+    Pinned,
+    /// Every context is recorded.
+    All,
+}
+
+/// Outcome of one interaction with a context.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InteractionRecord {
+    /// Tick at which the interaction occurred.
+    pub tick: u64,
+    /// Net change in coherence applied by the interaction.
+    pub delta: f32,
+    /// Coherence after the interaction.
+    pub value: f32,
+}
+
+/// Ring of the most recent `K` interaction records, oldest first.
+///
+/// When full, pushing a new record overwrites the oldest. Records are kept in
+/// chronological order so the contents are always available as one slice.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryBuffer<const K: usize> {
+    records: [InteractionRecord; K],
+    len: usize,
+}
+
+impl<const K: usize> HistoryBuffer<K> {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self { records: [InteractionRecord::default(); K], len: 0 }
+    }
+
+    /// Append a record, dropping the oldest when the buffer is full.
+    pub fn push(&mut self, record: InteractionRecord) {
+        if K == 0 {
+            return;
+        }
+        if self.len == K {
+            self.records.copy_within(1.., 0);
+            self.records[K - 1] = record;
+        } else {
+            self.records[self.len] = record;
+            self.len += 1;
+        }
+    }
+
+    /// Recorded interactions, oldest first.
+    pub fn as_slice(&self) -> &[InteractionRecord] {
+        &self.records[..self.len]
+    }
+
+    /// Number of records currently held (≤ `K`).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no records are held.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all records.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Merge `other`'s records into this buffer in tick order, keeping the
+    /// most recent `K`. On equal ticks this buffer's records come first.
+    pub fn merge(&mut self, other: &Self) {
+        let (a, b) = (self.clone(), other.as_slice());
+        let a = a.as_slice();
+        let (mut i, mut j) = (0, 0);
+        self.clear();
+        while i < a.len() || j < b.len() {
+            if j == b.len() || (i < a.len() && a[i].tick <= b[j].tick) {
+                self.push(a[i]);
+                i += 1;
+            } else {
+                self.push(b[j]);
+                j += 1;
+            }
+        }
+    }
+}
+
+impl<const K: usize> Default for HistoryBuffer<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rec(tick: u64) -> InteractionRecord {
+        InteractionRecord { tick, delta: 0.01, value: tick as f32 / 100.0 }
+    }
+
+    #[test]
+    fn test_history_buffer_chronological_until_full() {
+        let mut buf: HistoryBuffer<4> = HistoryBuffer::new();
+        assert!(buf.is_empty());
+        for t in 0..3 {
+            buf.push(rec(t));
+        }
+        let ticks: [u64; 3] = core::array::from_fn(|i| buf.as_slice()[i].tick);
+        assert_eq!(ticks, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_history_buffer_merge_interleaves_by_tick() {
+        let mut a: HistoryBuffer<4> = HistoryBuffer::new();
+        let mut b: HistoryBuffer<4> = HistoryBuffer::new();
+        for t in [1, 4, 6] {
+            a.push(rec(t));
+        }
+        for t in [2, 3, 5] {
+            b.push(rec(t));
+        }
+        a.merge(&b);
+        let ticks: [u64; 4] = core::array::from_fn(|i| a.as_slice()[i].tick);
+        assert_eq!(ticks, [3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_history_buffer_overwrites_oldest() {
+        let mut buf: HistoryBuffer<4> = HistoryBuffer::new();
+        for t in 0..10 {
+            buf.push(rec(t));
+        }
+        assert_eq!(buf.len(), 4);
+        let ticks: [u64; 4] = core::array::from_fn(|i| buf.as_slice()[i].tick);
+        assert_eq!(ticks, [6, 7, 8, 9]);
+        buf.clear();
+        assert!(buf.as_slice().is_empty());
+    }
+}
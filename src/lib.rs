@@ -52,11 +52,16 @@
 //! | Module | Key types | What it does |
 //! |--------|-----------|--------------|
 //! | [`vocabulary`] | [`SensorVocabulary`], [`ContextKey`] | Define your sensor space; hash + cosine similarity |
+//! | [`defaults`] | [`defaults::FAMILIARITY_THRESHOLD`], [`defaults::TABLE`] | Named default constants for every behaviour-defining number |
 //! | [`accumulator`] | [`CoherenceAccumulator`], [`CoherenceField`] | Per-context trust with earned floor and minimum gate |
+//! | [`history`] | [`history::HistoryBuffer`], [`history::HistoryPolicy`] | Opt-in per-context interaction history for trajectory analytics |
+//! | [`schedule`] | [`schedule::DecaySchedule`] | Time-of-day decay multipliers (slower forgetting overnight) |
 //! | [`phase`] | [`SocialPhase`], [`Personality`], [`PhaseSpace`] | Four-quadrant phase classifier with Schmitt trigger hysteresis |
 //! | [`boundary`] | [`MinCutBoundary`] | Stoer-Wagner comfort-zone boundary discovery |
 //! | [`sinkhorn`] | [`SinkhornKnopp`] | Doubly stochastic trust mixing |
+//! | [`agent`] | [`agent::CcfAgent`], [`agent::AgentOutput`] | Batteries-included facade: one `tick` call per observation |
 //! | [`mbot`] | [`mbot::MbotSensors`] | Reference 6-dimensional vocabulary for mBot2 ($50 hardware) |
+//! | [`source`] | [`source::SensorSource`], [`source::MockSensorSource`] | Hardware adapter: read the world, hand CCF a vocabulary |
 //! | [`seg`] | [`seg::CcfSegSnapshot`] | Serialisable field snapshot for persistence (requires `serde` feature) |
 //!
 //! ## Patent claim map
@@ -95,16 +100,24 @@ extern crate std;
 
 // Placeholder modules — populated by Phase 9 stories #48–#52
 pub mod vocabulary;   // #48: SensorVocabulary trait + ContextKey
+pub mod defaults;     // Named default constants (thresholds, rates, weights)
 pub mod accumulator;  // #49: CoherenceAccumulator + CoherenceField
+pub mod history;      // Bounded per-context interaction history
+pub mod schedule;     // Time-of-day DecaySchedule
 pub mod phase;        // #49: SocialPhase + Personality
 pub mod sinkhorn;     // #50: SinkhornKnopp projector
 pub mod boundary;     // #51: MinCutBoundary / Stoer-Wagner
 pub mod mbot;         // mBot2 reference vocabulary (MbotSensors, 6-dim)
+pub mod source;       // SensorSource hardware adapter + MockSensorSource
+pub mod agent;        // CcfAgent single-call pipeline facade
 #[cfg(feature = "serde")]
 pub mod seg;          // #53: CCF_SEG snapshot format
+pub mod pack;         // Allocation-free byte packing without serde
 
 #[cfg(feature = "python-ffi")]
 pub mod ffi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Adaptive coherence mixing — flat or hierarchical.
 ///
@@ -130,3 +143,14 @@ pub mod mixing;
 /// | [`cardinality::merge_accumulators`] | Claim B (honesty-preserving merge) |
 #[cfg(feature = "tiered-contexts")]
 pub mod cardinality;
+
+/// Fixed-capacity, allocation-free coherence field.
+///
+/// Enabled by `features = ["static-field"]`.  Backs the field with a
+/// `heapless::FnvIndexMap` of compile-time capacity for bare-metal targets.
+///
+/// | Type | Patent Claims |
+/// |------|--------------|
+/// | [`static_field::StaticCoherenceField`] | 6–7, 13 |
+#[cfg(feature = "static-field")]
+pub mod static_field;
@@ -167,7 +167,249 @@ impl SensorVocabulary<6> for MbotSensors {
         };
         [b, n, p, m, o, t]
     }
+
+    /// Snap each component to the nearest band (0.0 / 0.5 / 1.0, or 0.0 / 1.0
+    /// for orientation). `None` if any component is NaN.
+    fn from_feature_vec(v: &[f32; 6]) -> Option<Self> {
+        if v.iter().any(|x| x.is_nan()) {
+            return None;
+        }
+        // Nearest of three evenly spaced bands: 0 below 0.25, 2 from 0.75.
+        let band = |x: f32| {
+            if x < 0.25 {
+                0
+            } else if x < 0.75 {
+                1
+            } else {
+                2
+            }
+        };
+        Some(Self {
+            brightness: [BrightnessBand::Dark, BrightnessBand::Dim, BrightnessBand::Bright][band(v[0])],
+            noise: [NoiseBand::Quiet, NoiseBand::Moderate, NoiseBand::Loud][band(v[1])],
+            presence: [PresenceSignature::Absent, PresenceSignature::Far, PresenceSignature::Close][band(v[2])],
+            motion: [MotionContext::Static, MotionContext::Slow, MotionContext::Fast][band(v[3])],
+            orientation: if v[4] < 0.5 { Orientation::Upright } else { Orientation::Tilted },
+            time_period: [TimePeriod::Day, TimePeriod::Evening, TimePeriod::Night][band(v[5])],
+        })
+    }
+}
+
+/// Neutral reading: Dim, Quiet, Absent, Static, Upright, Day.
+impl Default for MbotSensors {
+    fn default() -> Self {
+        Self {
+            brightness: BrightnessBand::Dim,
+            noise: NoiseBand::Quiet,
+            presence: PresenceSignature::Absent,
+            motion: MotionContext::Static,
+            orientation: Orientation::Upright,
+            time_period: TimePeriod::Day,
+        }
+    }
+}
+
+impl MbotSensors {
+    /// Start a builder from [`MbotSensors::default`], overriding only the
+    /// dimensions that matter.
+    ///
+    /// ```rust
+    /// use ccf_core::mbot::{BrightnessBand, MbotSensors, NoiseBand};
+    ///
+    /// let sensors = MbotSensors::builder().brightness(BrightnessBand::Bright).build();
+    /// assert_eq!(sensors.noise, NoiseBand::Quiet);
+    /// ```
+    pub fn builder() -> MbotSensorsBuilder {
+        MbotSensorsBuilder { sensors: Self::default() }
+    }
+}
+
+/// Chainable constructor for [`MbotSensors`] — see [`MbotSensors::builder`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MbotSensorsBuilder {
+    sensors: MbotSensors,
+}
+
+impl MbotSensorsBuilder {
+    /// Set the ambient light band.
+    pub fn brightness(mut self, brightness: BrightnessBand) -> Self {
+        self.sensors.brightness = brightness;
+        self
+    }
+
+    /// Set the ambient sound band.
+    pub fn noise(mut self, noise: NoiseBand) -> Self {
+        self.sensors.noise = noise;
+        self
+    }
+
+    /// Set the presence signature.
+    pub fn presence(mut self, presence: PresenceSignature) -> Self {
+        self.sensors.presence = presence;
+        self
+    }
+
+    /// Set the motion context.
+    pub fn motion(mut self, motion: MotionContext) -> Self {
+        self.sensors.motion = motion;
+        self
+    }
+
+    /// Set the orientation.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.sensors.orientation = orientation;
+        self
+    }
+
+    /// Set the time-of-day period.
+    pub fn time_period(mut self, time_period: TimePeriod) -> Self {
+        self.sensors.time_period = time_period;
+        self
+    }
+
+    /// Finish building.
+    pub fn build(self) -> MbotSensors {
+        self.sensors
+    }
+}
+
+// ─── Raw readings ───────────────────────────────────────────────────────────
+
+/// Unquantised CyberPi readings, for [`MbotSensors::from_raw`].
+///
+/// Light and sound are raw 10-bit ADC values (0–1023); the dimensions
+/// without an analog source are passed through as bands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawReadings {
+    /// Light sensor ADC value, 0–1023.
+    pub light: u16,
+    /// Microphone loudness ADC value, 0–1023.
+    pub sound: u16,
+    /// Distance to the nearest object in cm; `None` when nothing is in range.
+    pub distance_cm: Option<f32>,
+    /// Motion context from the wheel encoders.
+    pub motion: MotionContext,
+    /// Orientation from the IMU.
+    pub orientation: Orientation,
+    /// Time-of-day period from the host or RTC.
+    pub time_period: TimePeriod,
+}
+
+/// Cutoffs mapping [`RawReadings`] onto bands.
+///
+/// Each band boundary belongs to the middle band: light below `light_dark`
+/// is Dark, above `light_bright` is Bright, and anything in between
+/// (inclusive) is Dim. Sound works the same way. Distances up to
+/// `close_cm` (inclusive) are Close, up to `far_cm` are Far, beyond is Absent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantizeThresholds {
+    /// Light below this is [`BrightnessBand::Dark`] (default 300).
+    pub light_dark: u16,
+    /// Light above this is [`BrightnessBand::Bright`] (default 700).
+    pub light_bright: u16,
+    /// Sound below this is [`NoiseBand::Quiet`] (default 200).
+    pub sound_quiet: u16,
+    /// Sound above this is [`NoiseBand::Loud`] (default 600).
+    pub sound_loud: u16,
+    /// Distance at or under this is [`PresenceSignature::Close`] (default 30 cm).
+    pub close_cm: f32,
+    /// Distance at or under this is [`PresenceSignature::Far`] (default 100 cm).
+    pub far_cm: f32,
+}
+
+impl Default for QuantizeThresholds {
+    fn default() -> Self {
+        Self { light_dark: 300, light_bright: 700, sound_quiet: 200, sound_loud: 600, close_cm: 30.0, far_cm: 100.0 }
+    }
+}
+
+impl MbotSensors {
+    /// Quantise raw readings into bands using `thresholds`.
+    pub fn from_raw(raw: RawReadings, thresholds: &QuantizeThresholds) -> Self {
+        let brightness = if raw.light < thresholds.light_dark {
+            BrightnessBand::Dark
+        } else if raw.light > thresholds.light_bright {
+            BrightnessBand::Bright
+        } else {
+            BrightnessBand::Dim
+        };
+        let noise = if raw.sound < thresholds.sound_quiet {
+            NoiseBand::Quiet
+        } else if raw.sound > thresholds.sound_loud {
+            NoiseBand::Loud
+        } else {
+            NoiseBand::Moderate
+        };
+        let presence = match raw.distance_cm {
+            Some(d) if d <= thresholds.close_cm => PresenceSignature::Close,
+            Some(d) if d <= thresholds.far_cm => PresenceSignature::Far,
+            _ => PresenceSignature::Absent,
+        };
+        Self {
+            brightness,
+            noise,
+            presence,
+            motion: raw.motion,
+            orientation: raw.orientation,
+            time_period: raw.time_period,
+        }
+    }
 }
 
 /// Type alias for the canonical mBot2 context key.
 pub type MbotContextKey = ContextKey<MbotSensors, 6>;
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_defaults_and_single_override() {
+        let base = MbotSensors::builder().build();
+        assert_eq!(base, MbotSensors::default());
+        assert_eq!(base.brightness, BrightnessBand::Dim);
+        assert_eq!(base.presence, PresenceSignature::Absent);
+        assert_eq!(base.time_period, TimePeriod::Day);
+
+        let loud = MbotSensors::builder().noise(NoiseBand::Loud).build();
+        assert_eq!(loud, MbotSensors { noise: NoiseBand::Loud, ..base });
+        assert_ne!(loud, base);
+    }
+
+    #[test]
+    fn test_from_raw_boundary_values() {
+        let t = QuantizeThresholds::default();
+        let raw = |light, sound, distance_cm| RawReadings {
+            light,
+            sound,
+            distance_cm,
+            motion: MotionContext::Slow,
+            orientation: Orientation::Upright,
+            time_period: TimePeriod::Evening,
+        };
+        let bands = |light, sound| {
+            let s = MbotSensors::from_raw(raw(light, sound, None), &t);
+            (s.brightness, s.noise)
+        };
+        assert_eq!(bands(0, 0), (BrightnessBand::Dark, NoiseBand::Quiet));
+        assert_eq!(bands(299, 199), (BrightnessBand::Dark, NoiseBand::Quiet));
+        assert_eq!(bands(300, 200), (BrightnessBand::Dim, NoiseBand::Moderate));
+        assert_eq!(bands(700, 600), (BrightnessBand::Dim, NoiseBand::Moderate));
+        assert_eq!(bands(701, 601), (BrightnessBand::Bright, NoiseBand::Loud));
+        assert_eq!(bands(1023, 1023), (BrightnessBand::Bright, NoiseBand::Loud));
+
+        let presence = |d| MbotSensors::from_raw(raw(500, 0, d), &t).presence;
+        assert_eq!(presence(Some(30.0)), PresenceSignature::Close);
+        assert_eq!(presence(Some(30.5)), PresenceSignature::Far);
+        assert_eq!(presence(Some(100.0)), PresenceSignature::Far);
+        assert_eq!(presence(Some(100.5)), PresenceSignature::Absent);
+        assert_eq!(presence(None), PresenceSignature::Absent);
+
+        let s = MbotSensors::from_raw(raw(500, 0, None), &t);
+        assert_eq!((s.motion, s.time_period), (MotionContext::Slow, TimePeriod::Evening));
+    }
+}
@@ -16,11 +16,35 @@
 //!
 //! Implements the hierarchical extension of Patent Claims 19–23
 //! (see continuation Claim A: US Provisional 63/988,438).
+//!
+//! # Matrix storage
+//!
+//! By default each cluster inlines two padded `MAX_CLUSTER_SIZE × MAX_CLUSTER_SIZE`
+//! matrices (128 KiB per cluster), so the whole mixer is a fixed-size value
+//! suitable for `static` placement on `no_std` targets.  With the
+//! `hierarchical-heap` feature the matrices are instead boxed slices of
+//! exactly `size × size` entries, shrinking the mixer to its active footprint
+//! at the cost of requiring `alloc`.  The inline matrices are public fields;
+//! the boxed ones are private, since their layout differs.  Code that must
+//! build either way reads entries through
+//! [`CoherenceCluster::intra_mix_raw_at`] and
+//! [`CoherenceCluster::intra_mix_projected_at`].
+
+#[cfg(feature = "hierarchical-heap")]
+extern crate alloc;
 
 use heapless::Vec as HVec;
 
 use super::{MAX_CLUSTER_SIZE, MAX_CONTEXTS_PER_CLUSTER};
 
+/// Storage for one intra-cluster mixing matrix: a padded inline array.
+#[cfg(not(feature = "hierarchical-heap"))]
+type MixMatrix = [f32; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE];
+
+/// Storage for one intra-cluster mixing matrix: `size × size` entries on the heap.
+#[cfg(feature = "hierarchical-heap")]
+type MixMatrix = alloc::boxed::Box<[f32]>;
+
 // ─── CoherenceCluster ────────────────────────────────────────────────────────
 
 /// A single context cluster within the hierarchical mixing hierarchy.
@@ -28,7 +52,8 @@ use super::{MAX_CLUSTER_SIZE, MAX_CONTEXTS_PER_CLUSTER};
 /// Groups a set of context indices (into the parent field's accumulator list)
 /// and owns a doubly stochastic intra-cluster mixing matrix of size
 /// `size × size`, stored in the top-left corner of a
-/// `MAX_CLUSTER_SIZE × MAX_CLUSTER_SIZE` padded array (row stride = `MAX_CLUSTER_SIZE`).
+/// `MAX_CLUSTER_SIZE × MAX_CLUSTER_SIZE` padded array, or compactly on the
+/// heap under `hierarchical-heap`.
 ///
 /// The projected matrix is kept in sync with the raw parameter matrix by
 /// [`crate::mixing::HierarchicalMixer::reproject_all`] and
@@ -50,16 +75,28 @@ pub struct CoherenceCluster {
     ///
     /// Row-major, row stride = `MAX_CLUSTER_SIZE`. Only the top-left `size × size`
     /// sub-block is meaningful; padding entries are ignored during projection.
+    #[cfg(not(feature = "hierarchical-heap"))]
     pub intra_mix_raw: [f32; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE],
 
+    /// Raw (un-projected) intra-cluster mixing parameters, `size × size`
+    /// row-major on the heap.
+    #[cfg(feature = "hierarchical-heap")]
+    intra_mix_raw: MixMatrix,
+
     /// Doubly stochastic projection of `intra_mix_raw`.
     ///
     /// Row-major, row stride = `MAX_CLUSTER_SIZE`. Updated by
     /// [`crate::mixing::HierarchicalMixer::reproject_all`].
     /// Padding entries outside the `size × size` sub-block are set to
     /// the identity (diagonal 1, off-diagonal 0).
+    #[cfg(not(feature = "hierarchical-heap"))]
     pub intra_mix_projected: [f32; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE],
 
+    /// Doubly stochastic projection of `intra_mix_raw`, `size × size`
+    /// row-major on the heap.
+    #[cfg(feature = "hierarchical-heap")]
+    intra_mix_projected: MixMatrix,
+
     /// Number of active members (= `member_indices.len()`).
     pub size: usize,
 
@@ -79,10 +116,116 @@ impl CoherenceCluster {
         Self {
             cluster_id,
             member_indices: HVec::new(),
-            intra_mix_raw: [0.0; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE],
-            intra_mix_projected: [0.0; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE],
+            intra_mix_raw: empty_matrix(),
+            intra_mix_projected: empty_matrix(),
             size: 0,
             projected_dirty: false,
         }
     }
+
+    /// Entry `(i, j)` of the raw `size × size` mixing parameters.
+    ///
+    /// # Panics
+    /// If `i` or `j` is not below `size`.
+    pub fn intra_mix_raw_at(&self, i: usize, j: usize) -> f32 {
+        assert!(i < self.size && j < self.size, "({}, {}) outside a {}-member cluster", i, j, self.size);
+        self.intra_mix_raw[i * self.stride() + j]
+    }
+
+    /// Entry `(i, j)` of the projected (doubly stochastic) `size × size` matrix.
+    ///
+    /// # Panics
+    /// If `i` or `j` is not below `size`.
+    pub fn intra_mix_projected_at(&self, i: usize, j: usize) -> f32 {
+        assert!(i < self.size && j < self.size, "({}, {}) outside a {}-member cluster", i, j, self.size);
+        self.intra_mix_projected[i * self.stride() + j]
+    }
+
+    /// Row stride of the mixing matrices: `MAX_CLUSTER_SIZE` for the inline
+    /// layout, `size` under `hierarchical-heap`.
+    fn stride(&self) -> usize {
+        if cfg!(feature = "hierarchical-heap") {
+            self.size
+        } else {
+            MAX_CLUSTER_SIZE
+        }
+    }
+
+    /// Store a row-major `size × size` block of raw parameters; entries past
+    /// the end of `raw_params` are left unchanged. Marks the projection dirty.
+    pub(crate) fn set_raw(&mut self, raw_params: &[f32]) {
+        let n = self.size.min(MAX_CLUSTER_SIZE);
+        let stride = self.stride();
+        for i in 0..n {
+            for j in 0..n {
+                if let Some(&v) = raw_params.get(i * self.size + j) {
+                    self.intra_mix_raw[i * stride + j] = v;
+                }
+            }
+        }
+        self.projected_dirty = true;
+    }
+
+    /// Copy the raw `size × size` block into `out` (row-major, no padding).
+    pub(crate) fn copy_raw_compact(&self, out: &mut [f32]) {
+        let (n, stride) = (self.size, self.stride());
+        for i in 0..n {
+            out[i * n..(i + 1) * n].copy_from_slice(&self.intra_mix_raw[i * stride..i * stride + n]);
+        }
+    }
+
+    /// Store a projected row-major `size × size` block, setting any padding
+    /// to the identity, and clear the dirty flag.
+    pub(crate) fn set_projected_compact(&mut self, compact: &[f32]) {
+        let (n, stride) = (self.size, self.stride());
+        for i in 0..stride {
+            for j in 0..stride {
+                self.intra_mix_projected[i * stride + j] = if i < n && j < n {
+                    compact[i * n + j]
+                } else if i == j {
+                    1.0
+                } else {
+                    0.0
+                };
+            }
+        }
+        self.projected_dirty = false;
+    }
+
+    /// Row `i` of the projected `size × size` block.
+    pub(crate) fn projected_row(&self, i: usize) -> &[f32] {
+        let start = i * self.stride();
+        &self.intra_mix_projected[start..start + self.size]
+    }
+
+    /// Reset both matrices to the `size × size` identity (padding zeroed).
+    ///
+    /// Under `hierarchical-heap` the matrices are reallocated to exactly
+    /// `size × size` entries.
+    pub(crate) fn reset_identity(&mut self) {
+        #[cfg(feature = "hierarchical-heap")]
+        {
+            let n = self.size.min(MAX_CLUSTER_SIZE);
+            self.intra_mix_raw = alloc::vec![0.0; n * n].into_boxed_slice();
+            self.intra_mix_projected = alloc::vec![0.0; n * n].into_boxed_slice();
+        }
+        for x in self.intra_mix_raw.iter_mut() { *x = 0.0; }
+        for x in self.intra_mix_projected.iter_mut() { *x = 0.0; }
+        let stride = self.stride();
+        for i in 0..self.size.min(MAX_CLUSTER_SIZE) {
+            self.intra_mix_raw[i * stride + i] = 1.0;
+            self.intra_mix_projected[i * stride + i] = 1.0;
+        }
+        self.projected_dirty = false;
+    }
+}
+
+#[cfg(not(feature = "hierarchical-heap"))]
+fn empty_matrix() -> MixMatrix {
+    [0.0; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE]
+}
+
+#[cfg(feature = "hierarchical-heap")]
+fn empty_matrix() -> MixMatrix {
+    alloc::boxed::Box::default()
 }
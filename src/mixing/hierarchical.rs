@@ -35,6 +35,9 @@
 //!
 //! **Step 5 — Final coherence:** `c''ᵢⱼ = clamp(c'ᵢⱼ + Δcᵢⱼ, 0.0, 1.0)`
 
+#[cfg(feature = "hierarchical-heap")]
+extern crate alloc;
+
 use heapless::Vec as HVec;
 
 use crate::sinkhorn::SinkhornKnopp;
@@ -107,7 +110,9 @@ impl Default for HierarchicalMixerConfig {
 ///
 /// This struct is large (~8 MiB on 64-bit with the default constants) due to
 /// inline fixed-size arrays.  On production humanoid platforms it should be
-/// placed in static memory or heap-allocated by the caller.
+/// placed in static memory or heap-allocated by the caller.  With the
+/// `hierarchical-heap` feature the per-cluster matrices are heap-allocated
+/// at their actual `size × size`, shrinking the struct to tens of KiB.
 #[derive(Clone, Debug)]
 pub struct HierarchicalMixer {
     /// Active cluster definitions (up to `MAX_CLUSTERS`).
@@ -193,6 +198,37 @@ impl HierarchicalMixer {
         &self,
         coherence_values: &mut [f32],
         interaction_counts: &[u32],
+    ) {
+        self.apply_with(apply_core, coherence_values, interaction_counts);
+    }
+
+    /// [`Self::apply`] with the per-cluster intra-mixing and summary steps
+    /// run across clusters on the rayon thread pool.
+    ///
+    /// Output is bit-for-bit identical to [`Self::apply`]: each cluster's
+    /// arithmetic is unchanged and only touches its own members.  Cluster
+    /// structures with overlapping members (never produced by
+    /// [`Self::update_clusters`]) fall back to the sequential kernel.
+    ///
+    /// Allocates one small buffer per cluster per call, so this is for
+    /// `std` hosts with many clusters — the hot path on embedded targets
+    /// remains [`Self::apply`].
+    #[cfg(feature = "parallel")]
+    pub fn apply_parallel(
+        &self,
+        coherence_values: &mut [f32],
+        interaction_counts: &[u32],
+    ) {
+        self.apply_with(apply_core_parallel, coherence_values, interaction_counts);
+    }
+
+    /// Shared body of [`Self::apply`]: runs `core` on the current structure
+    /// and, during a transition, on the old one, blending the two results.
+    fn apply_with(
+        &self,
+        core: CoreKernel,
+        coherence_values: &mut [f32],
+        interaction_counts: &[u32],
     ) {
         if self.in_transition {
             if let (Some(old_clusters), Some(old_inter)) =
@@ -209,7 +245,7 @@ impl HierarchicalMixer {
                 buf_old[..n].copy_from_slice(&coherence_values[..n]);
 
                 // Apply old structure to buf_old
-                apply_core(
+                core(
                     old_clusters,
                     old_clusters.len(),
                     old_inter,
@@ -218,7 +254,7 @@ impl HierarchicalMixer {
                 );
 
                 // Apply new structure to coherence_values in-place
-                apply_core(
+                core(
                     &self.clusters,
                     self.num_clusters,
                     &self.inter_mix_projected,
@@ -234,7 +270,7 @@ impl HierarchicalMixer {
                 }
             } else {
                 // Transition state inconsistent — fall through to new structure
-                apply_core(
+                core(
                     &self.clusters,
                     self.num_clusters,
                     &self.inter_mix_projected,
@@ -243,7 +279,7 @@ impl HierarchicalMixer {
                 );
             }
         } else {
-            apply_core(
+            core(
                 &self.clusters,
                 self.num_clusters,
                 &self.inter_mix_projected,
@@ -292,16 +328,7 @@ impl HierarchicalMixer {
 
         // Initialise intra-cluster matrices to identity (n×n block)
         for cluster in self.clusters.iter_mut() {
-            let n = cluster.size;
-            // Zero the full padded matrix first
-            for x in cluster.intra_mix_raw.iter_mut() { *x = 0.0; }
-            for x in cluster.intra_mix_projected.iter_mut() { *x = 0.0; }
-            // Set n×n identity in the top-left block
-            for i in 0..n.min(MAX_CLUSTER_SIZE) {
-                cluster.intra_mix_raw[i * MAX_CLUSTER_SIZE + i] = 1.0;
-                cluster.intra_mix_projected[i * MAX_CLUSTER_SIZE + i] = 1.0;
-            }
-            cluster.projected_dirty = false;
+            cluster.reset_identity();
         }
 
         // Initialise inter-cluster matrix to k×k identity
@@ -316,8 +343,8 @@ impl HierarchicalMixer {
     /// Update raw intra-cluster mixing parameters for a specific cluster.
     ///
     /// `raw_params` is a row-major `size × size` matrix (compact, no padding).
-    /// The values are stored into the padded `MAX_CLUSTER_SIZE × MAX_CLUSTER_SIZE`
-    /// layout and `projected_dirty` is set to `true`.
+    /// The values are stored into the cluster's matrix layout (see
+    /// [`CoherenceCluster::stride`]) and `projected_dirty` is set to `true`.
     ///
     /// Call [`Self::reproject_all`] to apply Sinkhorn-Knopp after updates.
     ///
@@ -325,16 +352,7 @@ impl HierarchicalMixer {
     pub fn update_intra_params(&mut self, cluster_id: u16, raw_params: &[f32]) {
         for cluster in self.clusters.iter_mut() {
             if cluster.cluster_id == cluster_id {
-                let n = cluster.size;
-                for i in 0..n.min(MAX_CLUSTER_SIZE) {
-                    for j in 0..n.min(MAX_CLUSTER_SIZE) {
-                        let src = i * n + j;
-                        if src < raw_params.len() {
-                            cluster.intra_mix_raw[i * MAX_CLUSTER_SIZE + j] = raw_params[src];
-                        }
-                    }
-                }
-                cluster.projected_dirty = true;
+                cluster.set_raw(raw_params);
                 break;
             }
         }
@@ -403,32 +421,21 @@ impl HierarchicalMixer {
                 continue;
             }
 
-            // Copy n×n sub-block (padded row-stride MAX_CLUSTER_SIZE) into a
-            // compact n×n buffer for the SK projector.
-            let mut compact = [0.0f32; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE];
-            for i in 0..n {
-                for j in 0..n {
-                    compact[i * n + j] = cluster.intra_mix_raw[i * MAX_CLUSTER_SIZE + j];
-                }
-            }
+            // Copy the n×n sub-block into a compact n×n buffer for the SK
+            // projector: 64 KiB on the stack, or exactly n×n on the heap
+            // under `hierarchical-heap`.
+            #[cfg(not(feature = "hierarchical-heap"))]
+            let mut buf = [0.0f32; MAX_CLUSTER_SIZE * MAX_CLUSTER_SIZE];
+            #[cfg(feature = "hierarchical-heap")]
+            let mut buf = alloc::vec![0.0f32; n * n];
+            let compact = &mut buf[..n * n];
+            cluster.copy_raw_compact(compact);
 
-            sk_intra.project_flat(&mut compact[..n * n], n);
+            sk_intra.project_flat(compact, n);
 
-            // Copy projected compact result back into the padded layout.
+            // Copy projected compact result back into the cluster's layout.
             // Padding diagonal is set to 1 (identity), off-diagonal to 0.
-            for i in 0..MAX_CLUSTER_SIZE {
-                for j in 0..MAX_CLUSTER_SIZE {
-                    cluster.intra_mix_projected[i * MAX_CLUSTER_SIZE + j] =
-                        if i < n && j < n {
-                            compact[i * n + j]
-                        } else if i == j {
-                            1.0
-                        } else {
-                            0.0
-                        };
-                }
-            }
-            cluster.projected_dirty = false;
+            cluster.set_projected_compact(compact);
         }
 
         // Project inter-cluster matrix
@@ -459,6 +466,49 @@ impl HierarchicalMixer {
 
 // ─── apply_core ─────────────────────────────────────────────────────────────
 
+/// Signature shared by [`apply_core`] and its parallel counterpart.
+type CoreKernel = fn(
+    &HVec<CoherenceCluster, MAX_CLUSTERS>,
+    usize,
+    &[f32; MAX_CLUSTERS * MAX_CLUSTERS],
+    &mut [f32],
+    &[u32],
+);
+
+/// Step 1 for one cluster: `c'_i = H_i · c_i` using the top-left n×n block.
+///
+/// Returns the mixed values in member order; entries whose global index is
+/// out of range are left at zero and never written back.
+fn intra_mix_cluster(cluster: &CoherenceCluster, coherence_values: &[f32]) -> [f32; MAX_CLUSTER_SIZE] {
+    let n = cluster.size;
+    let cv_len = coherence_values.len();
+    let mut c_out = [0.0f32; MAX_CLUSTER_SIZE];
+    for (i, out) in c_out.iter_mut().enumerate().take(n) {
+        let row = cluster.projected_row(i);
+        let mut sum = 0.0f32;
+        for (k, &global_k) in cluster.member_indices.iter().enumerate().take(n) {
+            if global_k < cv_len {
+                sum += row[k] * coherence_values[global_k];
+            }
+        }
+        *out = sum.clamp(0.0, 1.0);
+    }
+    c_out
+}
+
+/// Step 2 for one cluster: mean of its in-range members, read through `value_of(j, idx)`.
+fn cluster_mean(cluster: &CoherenceCluster, cv_len: usize, value_of: impl Fn(usize, usize) -> f32) -> f32 {
+    let n = cluster.size;
+    let mut sum = 0.0f32;
+    for j in 0..n {
+        let idx = cluster.member_indices[j];
+        if idx < cv_len {
+            sum += value_of(j, idx);
+        }
+    }
+    sum / n as f32
+}
+
 /// Inner five-step hierarchical mixing kernel.
 ///
 /// Separated from [`HierarchicalMixer::apply`] so the same logic can be
@@ -474,7 +524,6 @@ fn apply_core(
     interaction_counts: &[u32],
 ) {
     let cv_len = coherence_values.len();
-    let ic_len = interaction_counts.len();
 
     // ── Step 1: intra-cluster mixing ─────────────────────────────────────────
     for cluster in clusters.iter().take(num_clusters) {
@@ -482,24 +531,10 @@ fn apply_core(
         if n == 0 {
             continue;
         }
-
-        // c'_i = H_i · c_i  (matrix-vector multiply using top-left n×n block)
-        let mut c_out = [0.0f32; MAX_CLUSTER_SIZE];
-        for i in 0..n {
-            let mut sum = 0.0f32;
-            for k in 0..n {
-                let global_k = cluster.member_indices[k];
-                if global_k < cv_len {
-                    sum += cluster.intra_mix_projected[i * MAX_CLUSTER_SIZE + k]
-                        * coherence_values[global_k];
-                }
-            }
-            c_out[i] = sum.clamp(0.0, 1.0);
-        }
-        for i in 0..n {
-            let global_i = cluster.member_indices[i];
+        let c_out = intra_mix_cluster(cluster, coherence_values);
+        for (&global_i, &c) in cluster.member_indices.iter().zip(&c_out).take(n) {
             if global_i < cv_len {
-                coherence_values[global_i] = c_out[i];
+                coherence_values[global_i] = c;
             }
         }
     }
@@ -507,19 +542,92 @@ fn apply_core(
     // ── Step 2: cluster summary means ────────────────────────────────────────
     let mut s_bar = [0.0f32; MAX_CLUSTERS];
     for (ci, cluster) in clusters.iter().enumerate().take(num_clusters) {
-        let n = cluster.size;
-        if n == 0 {
+        if cluster.size == 0 {
             continue;
         }
-        let mut sum = 0.0f32;
-        for j in 0..n {
-            let idx = cluster.member_indices[j];
-            if idx < cv_len {
-                sum += coherence_values[idx];
+        s_bar[ci] = cluster_mean(cluster, cv_len, |_, idx| coherence_values[idx]);
+    }
+
+    inter_cluster_correction(clusters, num_clusters, inter_mix, &s_bar, coherence_values, interaction_counts);
+}
+
+/// [`apply_core`] with steps 1 and 2 run per cluster on the rayon pool.
+///
+/// Each cluster mixes into its own buffer, which is then scattered back
+/// in cluster order; with disjoint clusters every value is computed by the
+/// same arithmetic in the same order as the sequential kernel.
+#[cfg(feature = "parallel")]
+fn apply_core_parallel(
+    clusters: &HVec<CoherenceCluster, MAX_CLUSTERS>,
+    num_clusters: usize,
+    inter_mix: &[f32; MAX_CLUSTERS * MAX_CLUSTERS],
+    coherence_values: &mut [f32],
+    interaction_counts: &[u32],
+) {
+    use rayon::prelude::*;
+
+    let active = &clusters[..num_clusters.min(clusters.len())];
+    if !clusters_disjoint(active) {
+        apply_core(clusters, num_clusters, inter_mix, coherence_values, interaction_counts);
+        return;
+    }
+    let cv_len = coherence_values.len();
+
+    // ── Steps 1 & 2, one cluster per task ────────────────────────────────────
+    let input: &[f32] = coherence_values;
+    let mixed: std::vec::Vec<Option<([f32; MAX_CLUSTER_SIZE], f32)>> = active
+        .par_iter()
+        .map(|cluster| {
+            if cluster.size == 0 {
+                return None;
+            }
+            let c_out = intra_mix_cluster(cluster, input);
+            Some((c_out, cluster_mean(cluster, cv_len, |j, _| c_out[j])))
+        })
+        .collect();
+
+    let mut s_bar = [0.0f32; MAX_CLUSTERS];
+    for (ci, (cluster, result)) in active.iter().zip(mixed).enumerate() {
+        let Some((c_out, mean)) = result else { continue };
+        for (&global_i, &value) in cluster.member_indices.iter().zip(&c_out).take(cluster.size) {
+            if global_i < cv_len {
+                coherence_values[global_i] = value;
+            }
+        }
+        s_bar[ci] = mean;
+    }
+
+    inter_cluster_correction(clusters, num_clusters, inter_mix, &s_bar, coherence_values, interaction_counts);
+}
+
+/// `true` if no context index belongs to more than one of `clusters`.
+#[cfg(feature = "parallel")]
+fn clusters_disjoint(clusters: &[CoherenceCluster]) -> bool {
+    let mut seen = [false; MAX_TOTAL_CONTEXTS];
+    for cluster in clusters {
+        for &idx in cluster.member_indices.iter().take(cluster.size) {
+            if idx < MAX_TOTAL_CONTEXTS {
+                if seen[idx] {
+                    return false;
+                }
+                seen[idx] = true;
             }
         }
-        s_bar[ci] = sum / n as f32;
     }
+    true
+}
+
+/// Steps 3–5 given the per-cluster means `s_bar` from step 2.
+fn inter_cluster_correction(
+    clusters: &HVec<CoherenceCluster, MAX_CLUSTERS>,
+    num_clusters: usize,
+    inter_mix: &[f32; MAX_CLUSTERS * MAX_CLUSTERS],
+    s_bar: &[f32; MAX_CLUSTERS],
+    coherence_values: &mut [f32],
+    interaction_counts: &[u32],
+) {
+    let cv_len = coherence_values.len();
+    let ic_len = interaction_counts.len();
 
     // ── Step 3: inter-cluster mixing ─────────────────────────────────────────
     let mut s_bar_prime = [0.0f32; MAX_CLUSTERS];
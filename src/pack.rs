@@ -0,0 +1,378 @@
+//! Allocation-free byte packing of a coherence field, without serde.
+//!
+//! [`seg`](crate::seg) is the full snapshot format but needs `serde` and
+//! `alloc`. For an MCU persisting to EEPROM or flash, [`pack`] writes the
+//! essential per-context state into a caller-supplied buffer and [`unpack`]
+//! reads it back as a borrowed [`PackedSnapshot`] view — no heap, no serde.
+//!
+//! # Byte format (little-endian)
+//!
+//! ```text
+//! Header (4 bytes):
+//!   [0..2]   version:       u16 = 1
+//!   [2..4]   context_count: u16
+//! Context table: context_count × 20-byte record, starting at offset 4:
+//!   [+0..+4]   context_hash:          u32 (ContextKey::context_hash_u32)
+//!   [+4..+8]   coherence_value:       f32 (IEEE 754 bits)
+//!   [+8..+12]  interaction_count:     u32
+//!   [+12..+20] last_interaction_tick: u64
+//! ```
+//!
+//! Total length is `4 + 20 × context_count` bytes (see [`packed_len`]).
+//! Records appear in field iteration order. Personality, peak value, the
+//! first-familiar tick and per-context dynamics are not stored; restored
+//! contexts take the field's configuration, as with
+//! [`CcfSegSnapshot::restore_into`](crate::seg::CcfSegSnapshot::restore_into).
+
+use crate::accumulator::{CoherenceAccumulator, CoherenceField};
+use crate::vocabulary::{ContextKey, SensorVocabulary};
+
+/// Current packed format version.
+pub const PACK_VERSION: u16 = 1;
+
+/// Size of the packed header in bytes.
+pub const PACK_HEADER_LEN: usize = 4;
+
+/// Size of one packed context record in bytes.
+pub const PACK_RECORD_LEN: usize = 20;
+
+/// Bytes needed to pack `context_count` contexts.
+pub const fn packed_len(context_count: usize) -> usize {
+    PACK_HEADER_LEN + PACK_RECORD_LEN * context_count
+}
+
+// ─── PackError ────────────────────────────────────────────────────────────────
+
+/// Reasons packing or unpacking can fail.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PackError {
+    /// The buffer is shorter than the packed data requires.
+    BufferTooSmall {
+        /// Bytes required.
+        needed: usize,
+        /// Bytes available.
+        available: usize,
+    },
+    /// The packed data was written by an unsupported format version.
+    VersionMismatch {
+        /// Version this build understands.
+        expected: u16,
+        /// Version found in the header.
+        found: u16,
+    },
+    /// More contexts than the `u16` count field can describe.
+    TooManyContexts {
+        /// Number of contexts offered.
+        count: usize,
+    },
+    /// A record carries a coherence value that is NaN or outside [0.0, 1.0].
+    CoherenceOutOfRange {
+        /// Context hash of the offending record.
+        hash: u32,
+        /// The stored value.
+        value: f32,
+    },
+}
+
+impl core::fmt::Display for PackError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PackError::BufferTooSmall { needed, available } => {
+                write!(f, "packed data needs {} bytes, buffer has {}", needed, available)
+            }
+            PackError::VersionMismatch { expected, found } => {
+                write!(f, "packed version {} not supported (expected {})", found, expected)
+            }
+            PackError::TooManyContexts { count } => {
+                write!(f, "{} contexts exceed the packed format limit of {}", count, u16::MAX)
+            }
+            PackError::CoherenceOutOfRange { hash, value } => {
+                write!(f, "context {:#010x} has coherence {} outside [0, 1]", hash, value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackError {}
+
+// ─── PackedContext ────────────────────────────────────────────────────────────
+
+/// One context record decoded from a packed buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackedContext {
+    /// FNV-1a hash of the context key (from [`ContextKey::context_hash_u32`]).
+    pub context_hash: u32,
+    /// Accumulated coherence value [0.0, 1.0].
+    pub coherence_value: f32,
+    /// Total positive interactions recorded for this context.
+    pub interaction_count: u32,
+    /// Tick of the most recent interaction.
+    pub last_interaction_tick: u64,
+}
+
+impl PackedContext {
+    fn write(&self, out: &mut [u8]) {
+        out[0..4].copy_from_slice(&self.context_hash.to_le_bytes());
+        out[4..8].copy_from_slice(&self.coherence_value.to_le_bytes());
+        out[8..12].copy_from_slice(&self.interaction_count.to_le_bytes());
+        out[12..20].copy_from_slice(&self.last_interaction_tick.to_le_bytes());
+    }
+
+    fn read(bytes: &[u8]) -> Self {
+        let u32_at = |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let mut tick = [0u8; 8];
+        tick.copy_from_slice(&bytes[12..20]);
+        Self {
+            context_hash: u32_at(0),
+            coherence_value: f32::from_bits(u32_at(4)),
+            interaction_count: u32_at(8),
+            last_interaction_tick: u64::from_le_bytes(tick),
+        }
+    }
+}
+
+// ─── pack / unpack ────────────────────────────────────────────────────────────
+
+/// Pack every context of `field` into `buf`. Returns the number of bytes written.
+///
+/// Fails with [`PackError::BufferTooSmall`] (leaving `buf` partly written)
+/// when `buf` is shorter than [`packed_len`] of the field's context count.
+pub fn pack<V, const N: usize>(field: &CoherenceField<V, N>, buf: &mut [u8]) -> Result<usize, PackError>
+where
+    V: SensorVocabulary<N>,
+{
+    pack_entries(field.iter(), buf)
+}
+
+/// Pack `(key, accumulator)` entries into `buf`, e.g. from a field's `iter()`.
+///
+/// Lets fields other than [`CoherenceField`] share the format. Returns the
+/// number of bytes written.
+pub fn pack_entries<'a, V, const N: usize, I>(entries: I, buf: &mut [u8]) -> Result<usize, PackError>
+where
+    V: SensorVocabulary<N> + 'a,
+    I: IntoIterator<Item = (&'a ContextKey<V, N>, &'a CoherenceAccumulator)>,
+{
+    let mut count = 0usize;
+    for (key, acc) in entries {
+        let offset = packed_len(count);
+        let needed = offset + PACK_RECORD_LEN;
+        if needed > buf.len() {
+            return Err(PackError::BufferTooSmall { needed, available: buf.len() });
+        }
+        PackedContext {
+            context_hash: key.context_hash_u32(),
+            coherence_value: acc.value,
+            interaction_count: acc.interaction_count,
+            last_interaction_tick: acc.last_interaction_tick,
+        }
+        .write(&mut buf[offset..needed]);
+        count += 1;
+    }
+    if count > u16::MAX as usize {
+        return Err(PackError::TooManyContexts { count });
+    }
+    if buf.len() < PACK_HEADER_LEN {
+        return Err(PackError::BufferTooSmall { needed: PACK_HEADER_LEN, available: buf.len() });
+    }
+    buf[0..2].copy_from_slice(&PACK_VERSION.to_le_bytes());
+    buf[2..4].copy_from_slice(&(count as u16).to_le_bytes());
+    Ok(packed_len(count))
+}
+
+/// Decode a buffer written by [`pack`].
+///
+/// Checks the version, the length implied by the header and every coherence
+/// value. Trailing bytes after the context table are ignored.
+pub fn unpack(buf: &[u8]) -> Result<PackedSnapshot<'_>, PackError> {
+    if buf.len() < PACK_HEADER_LEN {
+        return Err(PackError::BufferTooSmall { needed: PACK_HEADER_LEN, available: buf.len() });
+    }
+    let version = u16::from_le_bytes([buf[0], buf[1]]);
+    if version != PACK_VERSION {
+        return Err(PackError::VersionMismatch { expected: PACK_VERSION, found: version });
+    }
+    let count = u16::from_le_bytes([buf[2], buf[3]]) as usize;
+    let needed = packed_len(count);
+    if buf.len() < needed {
+        return Err(PackError::BufferTooSmall { needed, available: buf.len() });
+    }
+    let snapshot = PackedSnapshot { records: &buf[PACK_HEADER_LEN..needed] };
+    for record in snapshot.iter() {
+        let value = record.coherence_value;
+        if !(0.0..=1.0).contains(&value) {
+            return Err(PackError::CoherenceOutOfRange { hash: record.context_hash, value });
+        }
+    }
+    Ok(snapshot)
+}
+
+// ─── PackedSnapshot ───────────────────────────────────────────────────────────
+
+/// A validated, borrowed view over a packed buffer returned by [`unpack`].
+#[derive(Clone, Copy, Debug)]
+pub struct PackedSnapshot<'a> {
+    /// The context table, `len() × PACK_RECORD_LEN` bytes.
+    records: &'a [u8],
+}
+
+impl<'a> PackedSnapshot<'a> {
+    /// Number of packed contexts.
+    pub fn len(&self) -> usize {
+        self.records.len() / PACK_RECORD_LEN
+    }
+
+    /// `true` if no contexts were packed.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The `index`-th record, in packing order.
+    pub fn get(&self, index: usize) -> Option<PackedContext> {
+        let start = index.checked_mul(PACK_RECORD_LEN)?;
+        self.records.get(start..start + PACK_RECORD_LEN).map(PackedContext::read)
+    }
+
+    /// Iterate over all records in packing order.
+    pub fn iter(&self) -> impl Iterator<Item = PackedContext> + 'a {
+        self.records.chunks_exact(PACK_RECORD_LEN).map(PackedContext::read)
+    }
+
+    /// The record for `context_hash`, if packed.
+    pub fn find(&self, context_hash: u32) -> Option<PackedContext> {
+        self.iter().find(|r| r.context_hash == context_hash)
+    }
+
+    /// Restore packed contexts into a live field.
+    ///
+    /// As with [`CcfSegSnapshot::restore_into`](crate::seg::CcfSegSnapshot::restore_into),
+    /// only hashes are stored, so the caller supplies the keys it can name;
+    /// each one found in the buffer gets its accumulator restored. Returns the
+    /// number of contexts restored.
+    pub fn restore_into<V, const N: usize>(
+        &self,
+        field: &mut CoherenceField<V, N>,
+        candidate_keys: &[ContextKey<V, N>],
+    ) -> usize
+    where
+        V: SensorVocabulary<N>,
+    {
+        let mut restored = 0;
+        for key in candidate_keys {
+            if let Some(record) = self.find(key.context_hash_u32()) {
+                field.insert_accumulator(
+                    key.clone(),
+                    CoherenceAccumulator {
+                        value: record.coherence_value,
+                        interaction_count: record.interaction_count,
+                        last_interaction_tick: record.last_interaction_tick,
+                        peak_value: record.coherence_value,
+                        first_familiar_tick: None,
+                        // Not stored in the packed format: use the field's configuration.
+                        floor_params: field.config().floor_params,
+                        dynamics: field.config().dynamics,
+                        recent_ema: 0.0,
+                    },
+                );
+                restored += 1;
+            }
+        }
+        restored
+    }
+}
+
+// ─── Tests ────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mbot::{BrightnessBand, MbotSensors, NoiseBand, PresenceSignature};
+    use crate::phase::Personality;
+
+    fn make_key(brightness: BrightnessBand, noise: NoiseBand, presence: PresenceSignature) -> ContextKey<MbotSensors, 6> {
+        ContextKey::new(MbotSensors::builder().brightness(brightness).noise(noise).presence(presence).build())
+    }
+
+    fn trained_field() -> (CoherenceField<MbotSensors, 6>, [ContextKey<MbotSensors, 6>; 2]) {
+        let keys = [
+            make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Absent),
+            make_key(BrightnessBand::Dark, NoiseBand::Loud, PresenceSignature::Close),
+        ];
+        let mut field = CoherenceField::new();
+        let p = Personality::new();
+        for t in 0..25 {
+            field.positive_interaction(&keys[0], &p, t, false);
+        }
+        field.positive_interaction(&keys[1], &p, 30, false);
+        field.negative_interaction(&keys[1], &p, 31);
+        (field, keys)
+    }
+
+    #[test]
+    fn test_pack_unpack_round_trip_fixed_buffer() {
+        let (field, keys) = trained_field();
+        let mut buf = [0u8; 64];
+        let written = pack(&field, &mut buf).unwrap();
+        assert_eq!(written, packed_len(2));
+
+        let snapshot = unpack(&buf[..written]).unwrap();
+        assert_eq!(snapshot.len(), 2);
+        for key in &keys {
+            let record = snapshot.find(key.context_hash_u32()).unwrap();
+            assert_eq!(record.coherence_value, field.context_coherence(key));
+            assert_eq!(record.interaction_count, field.context_interaction_count(key));
+        }
+
+        let mut restored: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(snapshot.restore_into(&mut restored, &keys), 2);
+        for key in &keys {
+            assert_eq!(restored.context_coherence(key), field.context_coherence(key));
+        }
+    }
+
+    #[test]
+    fn test_pack_layout_is_little_endian() {
+        let (field, keys) = trained_field();
+        let mut buf = [0u8; 64];
+        pack(&field, &mut buf).unwrap();
+        assert_eq!(&buf[0..4], &[1, 0, 2, 0]);
+        let first = u32::from_le_bytes([buf[4], buf[5], buf[6], buf[7]]);
+        assert!(keys.iter().any(|k| k.context_hash_u32() == first));
+    }
+
+    #[test]
+    fn test_pack_rejects_small_buffer() {
+        let (field, _) = trained_field();
+        let mut buf = [0u8; 30];
+        assert_eq!(
+            pack(&field, &mut buf),
+            Err(PackError::BufferTooSmall { needed: packed_len(2), available: 30 })
+        );
+    }
+
+    #[test]
+    fn test_unpack_rejects_bad_input() {
+        let (field, _) = trained_field();
+        let mut buf = [0u8; 44];
+        pack(&field, &mut buf).unwrap();
+
+        assert!(matches!(unpack(&buf[..40]), Err(PackError::BufferTooSmall { needed: 44, available: 40 })));
+
+        let mut wrong_version = buf;
+        wrong_version[0] = 9;
+        assert_eq!(unpack(&wrong_version).unwrap_err(), PackError::VersionMismatch { expected: 1, found: 9 });
+
+        let mut bad_value = buf;
+        bad_value[8..12].copy_from_slice(&f32::NAN.to_le_bytes());
+        assert!(matches!(unpack(&bad_value), Err(PackError::CoherenceOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_empty_field_packs_header_only() {
+        let field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut buf = [0u8; PACK_HEADER_LEN];
+        assert_eq!(pack(&field, &mut buf), Ok(PACK_HEADER_LEN));
+        assert!(unpack(&buf).unwrap().is_empty());
+    }
+}
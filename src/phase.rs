@@ -16,6 +16,10 @@
 //! - [`Personality`]: dynamic modulators — curiosity, startle sensitivity, recovery speed (Claim 3).
 //! - [`SocialPhase`]: four-quadrant phase classifier with Schmitt trigger hysteresis (Claims 14–18).
 //! - [`PhaseSpace`]: configurable thresholds for quadrant transitions (Claim 14).
+//! - [`HysteresisState`]: independent per-axis latches for explicit-state classification.
+//! - [`PhaseTracker`]: current phase plus dwell, carrying hysteresis state across ticks.
+//! - [`PhaseOccupancy`]: per-phase tick counts for session analytics.
+//! - [`PhaseTransitionMatrix`]: empirical phase-to-phase transition counts and probabilities.
 //!
 //! # Invariants
 //!
@@ -24,6 +28,12 @@
 //! - **I-DIST-001**: no_std compatible.
 //! - **I-DIST-005**: Zero unsafe code.
 
+use crate::boundary::exp_approx;
+use crate::defaults::{
+    COHERENCE_HIGH_ENTER, COHERENCE_HIGH_EXIT, CURIOSITY_GROWTH_WEIGHT, TENSION_HIGH_ENTER,
+    TENSION_HIGH_EXIT,
+};
+
 // ─── Personality ────────────────────────────────────────────────────────────
 
 /// Dynamic personality modulators.
@@ -34,6 +44,7 @@
 /// Patent Claim 3 (modulators).
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Personality {
     /// Drive to explore new contexts. Scales the cold-start baseline and
     /// the rate of positive coherence accumulation.
@@ -62,18 +73,39 @@ impl Personality {
         }
     }
 
+    /// Construct a personality with each parameter clamped to [0.0, 1.0].
+    pub fn new_clamped(curiosity_drive: f32, startle_sensitivity: f32, recovery_speed: f32) -> Self {
+        Self {
+            curiosity_drive: curiosity_drive.clamp(0.0, 1.0),
+            startle_sensitivity: startle_sensitivity.clamp(0.0, 1.0),
+            recovery_speed: recovery_speed.clamp(0.0, 1.0),
+        }
+    }
+
     /// Scale a base coherence gain delta by this personality's `recovery_speed`.
     ///
-    /// Returns `base * (0.5 + recovery_speed)`, clamped to [0.0, 2.0 * base].
+    /// Returns `base * (0.5 + recovery_speed)`, with `recovery_speed` clamped
+    /// to [0.0, 1.0] so an out-of-range field cannot scale past 1.5 × base.
     pub fn modulate_coherence_gain(&self, base: f32) -> f32 {
-        base * (0.5 + self.recovery_speed)
+        base * (0.5 + self.recovery_speed.clamp(0.0, 1.0))
     }
 
     /// Scale a base startle drop by this personality's `startle_sensitivity`.
     ///
-    /// Returns `base * (0.5 + startle_sensitivity)`, clamped to [0.0, 2.0 * base].
+    /// Returns `base * (0.5 + startle_sensitivity)`, with `startle_sensitivity`
+    /// clamped to [0.0, 1.0] so an out-of-range field cannot scale past 1.5 × base.
     pub fn modulate_startle_drop(&self, base: f32) -> f32 {
-        base * (0.5 + self.startle_sensitivity)
+        base * (0.5 + self.startle_sensitivity.clamp(0.0, 1.0))
+    }
+
+    /// Multiplier `curiosity_drive` applies to positive coherence growth.
+    ///
+    /// Returns `1 + 0.2 × (curiosity_drive − 0.5)` ([`CURIOSITY_GROWTH_WEIGHT`]):
+    /// 0.9 for a cautious robot, 1.1 for a curious one, exactly 1.0 at the
+    /// default. It multiplies the `recovery_speed` term rather than replacing
+    /// it, so the two modulators stay independent (CCF-003).
+    pub fn curiosity_growth_factor(&self) -> f32 {
+        1.0 + CURIOSITY_GROWTH_WEIGHT * (self.curiosity_drive.clamp(0.0, 1.0) - 0.5)
     }
 }
 
@@ -106,6 +138,16 @@ pub struct PhaseSpace {
     pub tension_high_enter: f32,
     /// Tension threshold to *stay in* the high-tension quadrants (exit when below).
     pub tension_high_exit: f32,
+    /// Forbid diagonal jumps between opposite quadrants (default `false`).
+    ///
+    /// When both axes cross their thresholds on the same tick — e.g.
+    /// ShyObserver → ProtectiveGuardian — only one axis moves: the one that
+    /// crossed by the larger margin, so the phase lands in the adjacent
+    /// quadrant the reading is already deeper into. The other axis follows on
+    /// a later tick if its reading still qualifies. Ties move the tension axis
+    /// first, so a startle is never the step that waits.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restrict_diagonal: bool,
 }
 
 impl PhaseSpace {
@@ -118,10 +160,11 @@ impl PhaseSpace {
 impl Default for PhaseSpace {
     fn default() -> Self {
         Self {
-            coherence_high_enter: 0.65,
-            coherence_high_exit: 0.55,
-            tension_high_enter: 0.45,
-            tension_high_exit: 0.35,
+            coherence_high_enter: COHERENCE_HIGH_ENTER,
+            coherence_high_exit: COHERENCE_HIGH_EXIT,
+            tension_high_enter: TENSION_HIGH_ENTER,
+            tension_high_exit: TENSION_HIGH_EXIT,
+            restrict_diagonal: false,
         }
     }
 }
@@ -144,6 +187,7 @@ impl Default for PhaseSpace {
 /// Patent Claims 14–18.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SocialPhase {
     /// Low coherence, low tension: minimal expression, cautious observation.
     ShyObserver,
@@ -168,25 +212,121 @@ impl SocialPhase {
         prev: SocialPhase,
         ps: &PhaseSpace,
     ) -> SocialPhase {
-        let high_coherence = match prev {
-            SocialPhase::QuietlyBeloved | SocialPhase::ProtectiveGuardian => {
-                effective_coherence >= ps.coherence_high_exit
-            }
-            _ => effective_coherence >= ps.coherence_high_enter,
+        let state = HysteresisState::from_phase(prev);
+        Self::classify_with_state(effective_coherence, tension, state, ps).0
+    }
+
+    /// [`Self::classify`] with a minimum dwell time in the previous phase.
+    ///
+    /// Hysteresis stops jitter around a threshold, but coherence that
+    /// legitimately crosses back and forth every few ticks still flaps. This
+    /// holds `prev` until `ticks_in_prev ≥ min_dwell` (e.g. from
+    /// [`PhaseTracker::ticks_in_phase`]); `min_dwell = 0` is plain `classify`.
+    ///
+    /// **Safety override:** a transition into `StartledRetreat` is never
+    /// held back — a startle must register on the tick it happens, however
+    /// recently the phase last changed.
+    pub fn classify_with_dwell(
+        effective_coherence: f32,
+        tension: f32,
+        prev: SocialPhase,
+        ticks_in_prev: u32,
+        min_dwell: u32,
+        ps: &PhaseSpace,
+    ) -> SocialPhase {
+        let next = Self::classify(effective_coherence, tension, prev, ps);
+        if next == SocialPhase::StartledRetreat || ticks_in_prev >= min_dwell {
+            next
+        } else {
+            prev
+        }
+    }
+
+    /// Classify with an explicit per-axis latch instead of inferring it from the previous phase.
+    ///
+    /// Each axis is an independent Schmitt trigger: the coherence latch uses
+    /// only the coherence thresholds, the tension latch only the tension
+    /// thresholds. Returns the phase and the updated latches to thread into
+    /// the next call. With `HysteresisState::from_phase(prev)` this is exactly
+    /// [`Self::classify`]; callers may also reset one axis without touching the other.
+    pub fn classify_with_state(
+        effective_coherence: f32,
+        tension: f32,
+        state: HysteresisState,
+        ps: &PhaseSpace,
+    ) -> (SocialPhase, HysteresisState) {
+        let high_coherence = if state.high_coherence {
+            effective_coherence >= ps.coherence_high_exit
+        } else {
+            effective_coherence >= ps.coherence_high_enter
         };
 
-        let high_tension = match prev {
-            SocialPhase::StartledRetreat | SocialPhase::ProtectiveGuardian => {
-                tension >= ps.tension_high_exit
-            }
-            _ => tension >= ps.tension_high_enter,
+        let high_tension = if state.high_tension {
+            tension >= ps.tension_high_exit
+        } else {
+            tension >= ps.tension_high_enter
         };
 
-        match (high_coherence, high_tension) {
-            (false, false) => SocialPhase::ShyObserver,
-            (false, true) => SocialPhase::StartledRetreat,
-            (true, false) => SocialPhase::QuietlyBeloved,
-            (true, true) => SocialPhase::ProtectiveGuardian,
+        let mut next = HysteresisState { high_coherence, high_tension };
+        let diagonal = high_coherence != state.high_coherence && high_tension != state.high_tension;
+        if ps.restrict_diagonal && diagonal {
+            // How far past the crossed threshold each reading lies.
+            let coherence_margin = if high_coherence {
+                effective_coherence - ps.coherence_high_enter
+            } else {
+                ps.coherence_high_exit - effective_coherence
+            };
+            let tension_margin = if high_tension {
+                tension - ps.tension_high_enter
+            } else {
+                ps.tension_high_exit - tension
+            };
+            if coherence_margin > tension_margin {
+                next.high_tension = state.high_tension;
+            } else {
+                next.high_coherence = state.high_coherence;
+            }
+        }
+        (next.phase(), next)
+    }
+
+    /// Soft membership weight of each phase, for blending outputs across a boundary.
+    ///
+    /// Each axis gets a logistic centred midway between its enter and exit
+    /// thresholds, with the deadband spanning about ±2 logistic widths; the
+    /// phase weights are products of the two axis memberships, so they sum
+    /// to 1.0. Order: `[ShyObserver, StartledRetreat, QuietlyBeloved,
+    /// ProtectiveGuardian]`.
+    ///
+    /// Away from the deadbands the argmax equals [`Self::classify`]; inside a
+    /// deadband the weights are memoryless and ignore hysteresis, so two
+    /// phases share weight where `classify` would depend on `prev`.
+    pub fn memberships(effective_coherence: f32, tension: f32, ps: &PhaseSpace) -> [f32; 4] {
+        let c = axis_membership(effective_coherence, ps.coherence_high_enter, ps.coherence_high_exit);
+        let t = axis_membership(tension, ps.tension_high_enter, ps.tension_high_exit);
+        [(1.0 - c) * (1.0 - t), (1.0 - c) * t, c * (1.0 - t), c * t]
+    }
+
+    /// Stable one-byte code for this phase, in declaration order (0..=3).
+    ///
+    /// Independent of `serde`, for compact logging over serial links.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            SocialPhase::ShyObserver => 0,
+            SocialPhase::StartledRetreat => 1,
+            SocialPhase::QuietlyBeloved => 2,
+            SocialPhase::ProtectiveGuardian => 3,
+        }
+    }
+
+    /// Decode a byte produced by [`Self::as_u8`]; `None` for values above 3.
+    pub fn from_u8(b: u8) -> Option<SocialPhase> {
+        match b {
+            0 => Some(SocialPhase::ShyObserver),
+            1 => Some(SocialPhase::StartledRetreat),
+            2 => Some(SocialPhase::QuietlyBeloved),
+            3 => Some(SocialPhase::ProtectiveGuardian),
+            _ => None,
         }
     }
 
@@ -194,19 +334,339 @@ impl SocialPhase {
     ///
     /// Delegates to [`permeability`] with representative mid-range values
     /// (coherence = 0.5, tension = 0.3) for backward-compatible ordering.
-    /// New code should call [`permeability`] directly for full control.
+    /// Use [`Self::expression_scale_at`] when the live readings are known.
     pub fn expression_scale(&self) -> f32 {
-        permeability(0.5, 0.3, *self)
+        self.expression_scale_at(0.5, 0.3)
+    }
+
+    /// Scale factor for expressive output in this phase at the given
+    /// effective coherence and tension — see [`permeability`].
+    pub fn expression_scale_at(&self, coherence: f32, tension: f32) -> f32 {
+        permeability(coherence, tension, *self)
     }
 
     /// LED color tint for this phase (overlaid on reflex mode color).
+    ///
+    /// Uses the default palette; see [`Self::led_tint_with`] for custom colours.
     pub fn led_tint(&self) -> [u8; 3] {
+        self.led_tint_with(&PhaseColors::default())
+    }
+
+    /// LED color tint for this phase from a caller-supplied palette.
+    pub fn led_tint_with(&self, colors: &PhaseColors) -> [u8; 3] {
         match self {
-            SocialPhase::ShyObserver => [40, 40, 80],          // Muted blue-grey
-            SocialPhase::StartledRetreat => [80, 20, 20],      // Dark red
-            SocialPhase::QuietlyBeloved => [60, 120, 200],     // Warm blue
-            SocialPhase::ProtectiveGuardian => [200, 100, 0],  // Amber
+            SocialPhase::ShyObserver => colors.shy,
+            SocialPhase::StartledRetreat => colors.startled,
+            SocialPhase::QuietlyBeloved => colors.beloved,
+            SocialPhase::ProtectiveGuardian => colors.guardian,
+        }
+    }
+}
+
+/// RGB LED palette, one colour per [`SocialPhase`].
+///
+/// The default is the built-in palette returned by [`SocialPhase::led_tint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseColors {
+    /// Tint for [`SocialPhase::ShyObserver`].
+    pub shy: [u8; 3],
+    /// Tint for [`SocialPhase::StartledRetreat`].
+    pub startled: [u8; 3],
+    /// Tint for [`SocialPhase::QuietlyBeloved`].
+    pub beloved: [u8; 3],
+    /// Tint for [`SocialPhase::ProtectiveGuardian`].
+    pub guardian: [u8; 3],
+}
+
+impl Default for PhaseColors {
+    fn default() -> Self {
+        Self {
+            shy: [40, 40, 80],         // Muted blue-grey
+            startled: [80, 20, 20],    // Dark red
+            beloved: [60, 120, 200],   // Warm blue
+            guardian: [200, 100, 0],   // Amber
+        }
+    }
+}
+
+/// Logistic membership of `x` in the high side of one Schmitt trigger axis.
+fn axis_membership(x: f32, enter: f32, exit: f32) -> f32 {
+    let centre = 0.5 * (enter + exit);
+    let width = (0.25 * (enter - exit).abs()).max(1e-3);
+    1.0 / (1.0 + exp_approx(-(x - centre) / width))
+}
+
+/// Per-axis Schmitt trigger latches for [`SocialPhase::classify_with_state`].
+///
+/// `true` means the axis is currently latched high and is held there until it
+/// falls below the exit threshold.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HysteresisState {
+    /// Coherence axis latched high (QuietlyBeloved / ProtectiveGuardian).
+    pub high_coherence: bool,
+    /// Tension axis latched high (StartledRetreat / ProtectiveGuardian).
+    pub high_tension: bool,
+}
+
+impl HysteresisState {
+    /// Latches implied by a phase — what [`SocialPhase::classify`] infers from `prev`.
+    pub fn from_phase(phase: SocialPhase) -> Self {
+        Self {
+            high_coherence: matches!(phase, SocialPhase::QuietlyBeloved | SocialPhase::ProtectiveGuardian),
+            high_tension: matches!(phase, SocialPhase::StartledRetreat | SocialPhase::ProtectiveGuardian),
+        }
+    }
+
+    /// The quadrant these latches select.
+    pub fn phase(&self) -> SocialPhase {
+        match (self.high_coherence, self.high_tension) {
+            (false, false) => SocialPhase::ShyObserver,
+            (false, true) => SocialPhase::StartledRetreat,
+            (true, false) => SocialPhase::QuietlyBeloved,
+            (true, true) => SocialPhase::ProtectiveGuardian,
+        }
+    }
+}
+
+// ─── Phase Tracker ───────────────────────────────────────────────────────────
+
+/// Current [`SocialPhase`] plus how long it has been held.
+///
+/// Owns the "previous phase" that [`SocialPhase::classify`] needs for hysteresis,
+/// so persisting a tracker and restoring it resumes classification exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseTracker {
+    /// Phase after the most recent update.
+    phase: SocialPhase,
+    /// Consecutive updates spent in `phase`.
+    ticks_in_phase: u32,
+}
+
+impl PhaseTracker {
+    /// Start in `ShyObserver` with no dwell.
+    pub fn new() -> Self {
+        Self::with_state(SocialPhase::ShyObserver, 0)
+    }
+
+    /// Resume from a known phase and dwell count.
+    pub fn with_state(phase: SocialPhase, ticks_in_phase: u32) -> Self {
+        Self { phase, ticks_in_phase }
+    }
+
+    /// Current phase.
+    pub fn phase(&self) -> SocialPhase {
+        self.phase
+    }
+
+    /// Consecutive updates spent in the current phase.
+    pub fn ticks_in_phase(&self) -> u32 {
+        self.ticks_in_phase
+    }
+
+    /// Classify one tick (with hysteresis against the current phase) and return the new phase.
+    pub fn update(&mut self, effective_coherence: f32, tension: f32, ps: &PhaseSpace) -> SocialPhase {
+        self.update_transition(effective_coherence, tension, ps);
+        self.phase
+    }
+
+    /// [`Self::update`], reporting only changes: `Some` on the tick the phase
+    /// differs from the previous one, `None` otherwise.
+    pub fn update_transition(
+        &mut self,
+        effective_coherence: f32,
+        tension: f32,
+        ps: &PhaseSpace,
+    ) -> Option<PhaseTransition> {
+        let next = SocialPhase::classify(effective_coherence, tension, self.phase, ps);
+        if next == self.phase {
+            self.ticks_in_phase = self.ticks_in_phase.saturating_add(1);
+            return None;
+        }
+        let transition = PhaseTransition { from: self.phase, to: next, dwell_ticks: self.ticks_in_phase };
+        #[cfg(feature = "defmt")]
+        defmt::debug!("ccf phase transition: {}", transition);
+        self.phase = next;
+        self.ticks_in_phase = 1;
+        Some(transition)
+    }
+}
+
+/// A phase change reported by [`PhaseTracker::update_transition`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhaseTransition {
+    /// Phase held before this tick.
+    pub from: SocialPhase,
+    /// Phase entered on this tick.
+    pub to: SocialPhase,
+    /// Updates spent in `from` before leaving it.
+    pub dwell_ticks: u32,
+}
+
+impl Default for PhaseTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─── Phase Occupancy ─────────────────────────────────────────────────────────
+
+/// Per-phase tick counts over a session — "how much of the day was QuietlyBeloved?".
+///
+/// Feed it the current phase once per tick; query fractions at any time.
+/// Four `u64` counters, no heap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseOccupancy {
+    /// Ticks spent in each phase, in [`SocialPhase`] declaration order.
+    ticks: [u64; 4],
+}
+
+impl PhaseOccupancy {
+    /// Phases in declaration order — also the tie-break order for [`Self::dominant_phase`].
+    const PHASES: [SocialPhase; 4] = [
+        SocialPhase::ShyObserver,
+        SocialPhase::StartledRetreat,
+        SocialPhase::QuietlyBeloved,
+        SocialPhase::ProtectiveGuardian,
+    ];
+
+    /// Construct an empty occupancy record.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one tick spent in `phase`.
+    pub fn record(&mut self, phase: SocialPhase) {
+        self.record_ticks(phase, 1);
+    }
+
+    /// Record `ticks` consecutive ticks spent in `phase`.
+    pub fn record_ticks(&mut self, phase: SocialPhase, ticks: u64) {
+        let slot = &mut self.ticks[Self::index(phase)];
+        *slot = slot.saturating_add(ticks);
+    }
+
+    /// Ticks spent in `phase`.
+    pub fn ticks(&self, phase: SocialPhase) -> u64 {
+        self.ticks[Self::index(phase)]
+    }
+
+    /// Total ticks recorded across all phases.
+    pub fn total_ticks(&self) -> u64 {
+        self.ticks.iter().fold(0u64, |acc, &t| acc.saturating_add(t))
+    }
+
+    /// Fraction of the session spent in `phase` in [0.0, 1.0] (0.0 for an empty session).
+    ///
+    /// Fractions over all four phases sum to 1.0 within rounding.
+    pub fn fraction(&self, phase: SocialPhase) -> f32 {
+        let total = self.total_ticks();
+        if total == 0 {
+            return 0.0;
+        }
+        (self.ticks(phase) as f64 / total as f64) as f32
+    }
+
+    /// Phase with the most recorded ticks.
+    ///
+    /// Ties are broken by enum declaration order (ShyObserver first), so an
+    /// empty session reports `ShyObserver`.
+    pub fn dominant_phase(&self) -> SocialPhase {
+        let mut best = 0;
+        for i in 1..Self::PHASES.len() {
+            if self.ticks[i] > self.ticks[best] {
+                best = i;
+            }
         }
+        Self::PHASES[best]
+    }
+
+    /// Clear all counters for a new session.
+    pub fn reset(&mut self) {
+        self.ticks = [0; 4];
+    }
+
+    fn index(phase: SocialPhase) -> usize {
+        phase.as_u8() as usize
+    }
+}
+
+// ─── Phase Transition Matrix ─────────────────────────────────────────────────
+
+/// Empirical Markov model of phase changes — "given ShyObserver, how often next QuietlyBeloved?".
+///
+/// Feed it each tick's `(previous, current)` phase pair, e.g. the tracker's
+/// phase before and after [`PhaseTracker::update`]. Staying in a phase is
+/// counted on the diagonal, distinct from transitions. A 4×4 `u32` matrix, no heap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseTransitionMatrix {
+    /// `counts[from][to]`, both in [`SocialPhase`] declaration order.
+    counts: [[u32; 4]; 4],
+}
+
+impl PhaseTransitionMatrix {
+    /// Construct an empty matrix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one step from `from` to `to`; `from == to` counts as a stay.
+    pub fn record(&mut self, from: SocialPhase, to: SocialPhase) {
+        let slot = &mut self.counts[PhaseOccupancy::index(from)][PhaseOccupancy::index(to)];
+        *slot = slot.saturating_add(1);
+    }
+
+    /// Raw count of steps from `from` to `to`.
+    pub fn count(&self, from: SocialPhase, to: SocialPhase) -> u32 {
+        self.counts[PhaseOccupancy::index(from)][PhaseOccupancy::index(to)]
+    }
+
+    /// Steps that stayed in `phase`.
+    pub fn stays(&self, phase: SocialPhase) -> u32 {
+        self.count(phase, phase)
+    }
+
+    /// Steps that left `from` for a different phase.
+    pub fn transitions_from(&self, from: SocialPhase) -> u32 {
+        let i = PhaseOccupancy::index(from);
+        self.row_total(i).saturating_sub(self.counts[i][i])
+    }
+
+    /// All steps recorded from `from`, stays included.
+    pub fn total_from(&self, from: SocialPhase) -> u32 {
+        self.row_total(PhaseOccupancy::index(from))
+    }
+
+    /// Probability of stepping from `from` to `to`, normalised over the `from` row.
+    ///
+    /// Each row sums to 1.0 once any step from it has been recorded; rows with
+    /// no data report 0.0 everywhere.
+    pub fn probability(&self, from: SocialPhase, to: SocialPhase) -> f32 {
+        let total = self.total_from(from);
+        if total == 0 {
+            return 0.0;
+        }
+        (self.count(from, to) as f64 / total as f64) as f32
+    }
+
+    /// The raw 4×4 count matrix, rows and columns in [`SocialPhase`] declaration order.
+    pub fn counts(&self) -> &[[u32; 4]; 4] {
+        &self.counts
+    }
+
+    /// Clear all counters.
+    pub fn reset(&mut self) {
+        self.counts = [[0; 4]; 4];
+    }
+
+    fn row_total(&self, i: usize) -> u32 {
+        self.counts[i].iter().fold(0u32, |acc, &c| acc.saturating_add(c))
     }
 }
 
@@ -225,16 +685,39 @@ impl SocialPhase {
 /// | ShyObserver | [0.0, 0.3] | `effective_coherence × 0.3` |
 /// | StartledRetreat | 0.1 fixed | reflexive, not expressive |
 /// | QuietlyBeloved | [0.5, 1.0] | `0.5 + effective_coherence × 0.5` |
-/// | ProtectiveGuardian | [0.4, 0.6] | `0.4 + effective_coherence × 0.2` |
-pub fn permeability(effective_coherence: f32, _tension: f32, quadrant: SocialPhase) -> f32 {
+/// | ProtectiveGuardian | [0.4, 0.6] | `0.4 + (effective_coherence + 0.3 − tension) × 0.2` |
+///
+/// Only ProtectiveGuardian reads `tension`: a guardian under more pressure
+/// expresses less, clamped to its band. At the reference tension of 0.3 the
+/// formula reduces to `0.4 + effective_coherence × 0.2`.
+pub fn permeability(effective_coherence: f32, tension: f32, quadrant: SocialPhase) -> f32 {
     match quadrant {
         SocialPhase::ShyObserver => effective_coherence * 0.3,
         SocialPhase::StartledRetreat => 0.1,
         SocialPhase::QuietlyBeloved => 0.5 + effective_coherence * 0.5,
-        SocialPhase::ProtectiveGuardian => 0.4 + effective_coherence * 0.2,
+        SocialPhase::ProtectiveGuardian => {
+            (0.4 + (effective_coherence + 0.3 - tension) * 0.2).clamp(0.4, 0.6)
+        }
     }
 }
 
+/// Motor speed for a permeability: linear, `p × max_speed`.
+///
+/// `p` is clamped to [0.0, 1.0], so the result lies in `[0.0, max_speed]`.
+pub fn permeability_to_motor_speed(p: f32, max_speed: f32) -> f32 {
+    p.clamp(0.0, 1.0) * max_speed
+}
+
+/// Per-tick probability of vocalizing for a permeability: cubic, `p³`.
+///
+/// The cubic keeps quiet phases nearly silent — ShyObserver tops out at
+/// 0.3³ ≈ 0.03 — while QuietlyBeloved reaches 0.125–1.0. `p` is clamped to
+/// [0.0, 1.0].
+pub fn permeability_to_sound_probability(p: f32) -> f32 {
+    let p = p.clamp(0.0, 1.0);
+    p * p * p
+}
+
 /// Narration depth levels gated by output permeability.
 ///
 /// Determines how much reflection the robot performs based on the current
@@ -255,15 +738,33 @@ pub enum NarrationDepth {
 }
 
 impl NarrationDepth {
+    /// Permeability at which `Minimal`, `Brief`, `Full` and `Deep` begin.
+    pub const DEFAULT_THRESHOLDS: [f32; 4] = [0.2, 0.4, 0.6, 0.8];
+
     /// Map a permeability scalar to a narration depth level.
     pub fn from_permeability(p: f32) -> Self {
-        if p < 0.2 {
+        Self::from_permeability_with(p, &Self::DEFAULT_THRESHOLDS)
+    }
+
+    /// Map a permeability scalar to a narration depth level using custom
+    /// thresholds: the permeability at which `Minimal`, `Brief`, `Full` and
+    /// `Deep` begin, in ascending order.
+    ///
+    /// # Panics
+    /// In debug builds, panics if `thresholds` is not ascending.
+    pub fn from_permeability_with(p: f32, thresholds: &[f32; 4]) -> Self {
+        debug_assert!(
+            thresholds.windows(2).all(|w| w[0] <= w[1]),
+            "narration thresholds must be ascending: {:?}",
+            thresholds
+        );
+        if p < thresholds[0] {
             NarrationDepth::None
-        } else if p < 0.4 {
+        } else if p < thresholds[1] {
             NarrationDepth::Minimal
-        } else if p < 0.6 {
+        } else if p < thresholds[2] {
             NarrationDepth::Brief
-        } else if p < 0.8 {
+        } else if p < thresholds[3] {
             NarrationDepth::Full
         } else {
             NarrationDepth::Deep
@@ -303,6 +804,18 @@ mod tests {
         assert!((result - 0.05 * 0.6).abs() < f32::EPSILON, "got {}", result);
     }
 
+    #[test]
+    fn test_personality_out_of_range_is_bounded() {
+        let clamped = Personality::new_clamped(-1.0, 5.0, 2.0);
+        assert_eq!(clamped, Personality { curiosity_drive: 0.0, startle_sensitivity: 1.0, recovery_speed: 1.0 });
+
+        // Fields set directly still modulate within [0.5, 1.5] × base.
+        let wild = Personality { curiosity_drive: 0.5, startle_sensitivity: 5.0, recovery_speed: -3.0 };
+        assert!((wild.modulate_startle_drop(0.05) - 0.05 * 1.5).abs() < f32::EPSILON);
+        assert!((wild.modulate_coherence_gain(0.02) - 0.02 * 0.5).abs() < f32::EPSILON);
+        assert_eq!(wild.modulate_startle_drop(0.05), clamped.modulate_startle_drop(0.05));
+    }
+
     // ── PhaseSpace tests ──────────────────────────────────────────────────
 
     #[test]
@@ -378,6 +891,31 @@ mod tests {
         assert_eq!(phase, SocialPhase::ShyObserver);
     }
 
+    #[test]
+    fn test_restrict_diagonal_steps_through_adjacent_phase() {
+        let ps = PhaseSpace { restrict_diagonal: true, ..PhaseSpace::default() };
+
+        // Both axes cross at once: unrestricted classify jumps to the corner.
+        assert_eq!(
+            SocialPhase::classify(0.9, 0.5, SocialPhase::ShyObserver, &PhaseSpace::default()),
+            SocialPhase::ProtectiveGuardian
+        );
+
+        // Restricted: coherence is further past its threshold (0.25 vs 0.05),
+        // so it moves first; tension follows on the next tick.
+        let step = SocialPhase::classify(0.9, 0.5, SocialPhase::ShyObserver, &ps);
+        assert_eq!(step, SocialPhase::QuietlyBeloved);
+        let corner = SocialPhase::classify(0.9, 0.5, step, &ps);
+        assert_eq!(corner, SocialPhase::ProtectiveGuardian);
+
+        // The deeper tension crossing moves first instead.
+        let step = SocialPhase::classify(0.7, 0.9, SocialPhase::ShyObserver, &ps);
+        assert_eq!(step, SocialPhase::StartledRetreat);
+
+        // Single-axis transitions are unaffected.
+        assert_eq!(SocialPhase::classify(0.9, 0.1, SocialPhase::ShyObserver, &ps), SocialPhase::QuietlyBeloved);
+    }
+
     #[test]
     fn test_custom_thresholds_stricter() {
         let strict = PhaseSpace {
@@ -505,6 +1043,251 @@ mod tests {
         assert_ne!(qb, pg);
     }
 
+    #[test]
+    fn test_social_phase_u8_round_trip() {
+        for (i, &phase) in PhaseOccupancy::PHASES.iter().enumerate() {
+            assert_eq!(phase.as_u8(), i as u8);
+            assert_eq!(SocialPhase::from_u8(phase.as_u8()), Some(phase));
+        }
+        assert_eq!(SocialPhase::from_u8(4), None);
+        assert_eq!(SocialPhase::from_u8(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_led_tint_with_custom_palette() {
+        let colors = PhaseColors {
+            shy: [1, 2, 3],
+            startled: [255, 0, 0],
+            beloved: [0, 255, 0],
+            guardian: [0, 0, 255],
+        };
+        let tints = [
+            SocialPhase::ShyObserver.led_tint_with(&colors),
+            SocialPhase::StartledRetreat.led_tint_with(&colors),
+            SocialPhase::QuietlyBeloved.led_tint_with(&colors),
+            SocialPhase::ProtectiveGuardian.led_tint_with(&colors),
+        ];
+        assert_eq!(tints, [colors.shy, colors.startled, colors.beloved, colors.guardian]);
+        for i in 0..4 {
+            for j in i + 1..4 {
+                assert_ne!(tints[i], tints[j]);
+            }
+        }
+        assert_eq!(
+            SocialPhase::QuietlyBeloved.led_tint_with(&PhaseColors::default()),
+            SocialPhase::QuietlyBeloved.led_tint()
+        );
+    }
+
+    #[test]
+    fn test_classify_with_state_reproduces_classify() {
+        let ps = PhaseSpace::default();
+        let phases = [
+            SocialPhase::ShyObserver,
+            SocialPhase::StartledRetreat,
+            SocialPhase::QuietlyBeloved,
+            SocialPhase::ProtectiveGuardian,
+        ];
+        for prev in phases {
+            assert_eq!(HysteresisState::from_phase(prev).phase(), prev);
+            for c in 0..=20 {
+                for t in 0..=20 {
+                    let (coh, ten) = (c as f32 * 0.05, t as f32 * 0.05);
+                    let (phase, state) =
+                        SocialPhase::classify_with_state(coh, ten, HysteresisState::from_phase(prev), &ps);
+                    assert_eq!(phase, SocialPhase::classify(coh, ten, prev, &ps));
+                    assert_eq!(state.phase(), phase);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_classify_with_state_axes_are_independent() {
+        let ps = PhaseSpace::default();
+        let latched = HysteresisState { high_coherence: true, high_tension: true };
+        // Both values in their deadbands: both latches hold.
+        let (phase, _) = SocialPhase::classify_with_state(0.60, 0.40, latched, &ps);
+        assert_eq!(phase, SocialPhase::ProtectiveGuardian);
+
+        // Reset only the tension latch: coherence hysteresis persists.
+        let reset = HysteresisState { high_tension: false, ..latched };
+        let (phase, state) = SocialPhase::classify_with_state(0.60, 0.40, reset, &ps);
+        assert_eq!(phase, SocialPhase::QuietlyBeloved);
+        assert!(state.high_coherence && !state.high_tension);
+    }
+
+    // ── PhaseTracker tests ────────────────────────────────────────────────
+
+    #[test]
+    fn test_classify_with_dwell_holds_until_min_dwell() {
+        let ps = PhaseSpace::default();
+        let beloved = SocialPhase::QuietlyBeloved;
+        // Coherence drops below the exit threshold after only 2 ticks in QB.
+        assert_eq!(SocialPhase::classify_with_dwell(0.4, 0.1, beloved, 2, 5, &ps), beloved);
+        assert_eq!(SocialPhase::classify_with_dwell(0.4, 0.1, beloved, 5, 5, &ps), SocialPhase::ShyObserver);
+        // Without a minimum dwell it is plain classify.
+        assert_eq!(
+            SocialPhase::classify_with_dwell(0.4, 0.1, beloved, 0, 0, &ps),
+            SocialPhase::classify(0.4, 0.1, beloved, &ps)
+        );
+        // Entering ProtectiveGuardian is an ordinary transition and waits too.
+        assert_eq!(SocialPhase::classify_with_dwell(0.8, 0.7, beloved, 1, 5, &ps), beloved);
+    }
+
+    #[test]
+    fn test_classify_with_dwell_startle_overrides_hold() {
+        let ps = PhaseSpace::default();
+        let phase = SocialPhase::classify_with_dwell(0.1, 0.7, SocialPhase::ShyObserver, 1, 100, &ps);
+        assert_eq!(phase, SocialPhase::StartledRetreat);
+        // Leaving StartledRetreat, by contrast, still respects the dwell.
+        let phase = SocialPhase::classify_with_dwell(0.1, 0.1, SocialPhase::StartledRetreat, 1, 100, &ps);
+        assert_eq!(phase, SocialPhase::StartledRetreat);
+    }
+
+    #[test]
+    fn test_phase_tracker_counts_dwell() {
+        let ps = PhaseSpace::default();
+        let mut t = PhaseTracker::new();
+        assert_eq!(t.update(0.1, 0.1, &ps), SocialPhase::ShyObserver);
+        assert_eq!(t.update(0.1, 0.1, &ps), SocialPhase::ShyObserver);
+        assert_eq!(t.ticks_in_phase(), 2);
+
+        assert_eq!(t.update(0.8, 0.1, &ps), SocialPhase::QuietlyBeloved);
+        assert_eq!(t.ticks_in_phase(), 1);
+    }
+
+    #[test]
+    fn test_phase_tracker_reports_transitions_only_on_change() {
+        let ps = PhaseSpace::default();
+        let mut t = PhaseTracker::new();
+        for _ in 0..3 {
+            assert_eq!(t.update_transition(0.1, 0.1, &ps), None);
+        }
+        let change = t.update_transition(0.8, 0.1, &ps);
+        assert_eq!(
+            change,
+            Some(PhaseTransition {
+                from: SocialPhase::ShyObserver,
+                to: SocialPhase::QuietlyBeloved,
+                dwell_ticks: 3,
+            })
+        );
+        assert_eq!(t.update_transition(0.8, 0.1, &ps), None);
+        assert_eq!(t.ticks_in_phase(), 2);
+    }
+
+    #[test]
+    fn test_memberships_sum_to_one_and_saturate_inside_quadrants() {
+        let ps = PhaseSpace::default();
+        for ci in 0..=20 {
+            for ti in 0..=20 {
+                let w = SocialPhase::memberships(ci as f32 / 20.0, ti as f32 / 20.0, &ps);
+                let sum: f32 = w.iter().sum();
+                assert!((sum - 1.0).abs() < 1e-5, "sum {} at ({}, {})", sum, ci, ti);
+                assert!(w.iter().all(|&x| (0.0..=1.0).contains(&x)));
+            }
+        }
+        let deep = [(0.1, 0.05, 0), (0.1, 0.95, 1), (0.95, 0.05, 2), (0.95, 0.95, 3)];
+        for &(coh, ten, idx) in &deep {
+            let w = SocialPhase::memberships(coh, ten, &ps);
+            assert!(w[idx] > 0.999, "({}, {}) -> {:?}", coh, ten, w);
+            let hard = SocialPhase::classify(coh, ten, SocialPhase::ShyObserver, &ps);
+            assert_eq!(PhaseOccupancy::PHASES[idx], hard);
+        }
+        // On the coherence boundary the two low-tension phases share weight.
+        let w = SocialPhase::memberships(0.6, 0.05, &ps);
+        assert!((w[0] - 0.5).abs() < 0.01 && (w[2] - 0.5).abs() < 0.01, "{:?}", w);
+    }
+
+    #[test]
+    fn test_phase_tracker_resumes_hysteresis() {
+        // 0.60 sits in the deadband: stays QB if already QB, stays SO otherwise.
+        let ps = PhaseSpace::default();
+        let mut resumed = PhaseTracker::with_state(SocialPhase::QuietlyBeloved, 10);
+        assert_eq!(resumed.update(0.60, 0.1, &ps), SocialPhase::QuietlyBeloved);
+        assert_eq!(resumed.ticks_in_phase(), 11);
+
+        let mut fresh = PhaseTracker::new();
+        assert_eq!(fresh.update(0.60, 0.1, &ps), SocialPhase::ShyObserver);
+    }
+
+    // ── PhaseOccupancy tests ──────────────────────────────────────────────
+
+    #[test]
+    fn test_phase_occupancy_fractions_sum_to_one() {
+        let mut occ = PhaseOccupancy::new();
+        occ.record_ticks(SocialPhase::QuietlyBeloved, 78);
+        occ.record_ticks(SocialPhase::ShyObserver, 15);
+        for _ in 0..7 {
+            occ.record(SocialPhase::StartledRetreat);
+        }
+        assert_eq!(occ.total_ticks(), 100);
+        assert!((occ.fraction(SocialPhase::QuietlyBeloved) - 0.78).abs() < 1e-6);
+        assert_eq!(occ.fraction(SocialPhase::ProtectiveGuardian), 0.0);
+        let sum: f32 = PhaseOccupancy::PHASES.iter().map(|&p| occ.fraction(p)).sum();
+        assert!((sum - 1.0).abs() < 1e-5, "sum={}", sum);
+        assert_eq!(occ.dominant_phase(), SocialPhase::QuietlyBeloved);
+    }
+
+    #[test]
+    fn test_phase_occupancy_tie_break_by_enum_order() {
+        let mut occ = PhaseOccupancy::new();
+        assert_eq!(occ.dominant_phase(), SocialPhase::ShyObserver);
+        assert_eq!(occ.fraction(SocialPhase::ShyObserver), 0.0);
+
+        occ.record_ticks(SocialPhase::ProtectiveGuardian, 5);
+        occ.record_ticks(SocialPhase::StartledRetreat, 5);
+        assert_eq!(occ.dominant_phase(), SocialPhase::StartledRetreat);
+
+        occ.reset();
+        assert_eq!(occ.total_ticks(), 0);
+    }
+
+    // ── PhaseTransitionMatrix tests ───────────────────────────────────────
+
+    #[test]
+    fn test_transition_matrix_rows_normalise() {
+        let mut m = PhaseTransitionMatrix::new();
+        let shy = SocialPhase::ShyObserver;
+        let beloved = SocialPhase::QuietlyBeloved;
+        for _ in 0..6 {
+            m.record(shy, shy);
+        }
+        m.record(shy, beloved);
+        m.record(shy, SocialPhase::StartledRetreat);
+        m.record(beloved, beloved);
+
+        assert_eq!(m.stays(shy), 6);
+        assert_eq!(m.transitions_from(shy), 2);
+        assert_eq!(m.total_from(shy), 8);
+        assert_eq!(m.counts()[0][2], 1);
+        assert!((m.probability(shy, beloved) - 0.125).abs() < 1e-6);
+
+        for from in PhaseOccupancy::PHASES {
+            let sum: f32 = PhaseOccupancy::PHASES.iter().map(|&to| m.probability(from, to)).sum();
+            let expected = if m.total_from(from) > 0 { 1.0 } else { 0.0 };
+            assert!((sum - expected).abs() < 1e-5, "{:?} sum={}", from, sum);
+        }
+        assert_eq!(m.probability(SocialPhase::ProtectiveGuardian, shy), 0.0);
+    }
+
+    #[test]
+    fn test_transition_matrix_fed_by_tracker() {
+        let ps = PhaseSpace::default();
+        let mut tracker = PhaseTracker::new();
+        let mut m = PhaseTransitionMatrix::new();
+        for &coh in &[0.2, 0.2, 0.8, 0.8, 0.8] {
+            let prev = tracker.phase();
+            m.record(prev, tracker.update(coh, 0.1, &ps));
+        }
+        assert_eq!(m.stays(SocialPhase::ShyObserver), 2);
+        assert_eq!(m.count(SocialPhase::ShyObserver, SocialPhase::QuietlyBeloved), 1);
+        assert_eq!(m.stays(SocialPhase::QuietlyBeloved), 2);
+        m.reset();
+        assert_eq!(m, PhaseTransitionMatrix::new());
+    }
+
     // ── Permeability tests ────────────────────────────────────────────────
 
     #[test]
@@ -559,6 +1342,26 @@ mod tests {
         assert!((p_mid - 0.5).abs() < f32::EPSILON, "got {}", p_mid);
     }
 
+    #[test]
+    fn test_guardian_permeability_falls_with_tension() {
+        let mut prev = f32::INFINITY;
+        for step in 0..=10 {
+            let tension = 0.3 + step as f32 * 0.07;
+            let p = SocialPhase::ProtectiveGuardian.expression_scale_at(0.8, tension);
+            assert!((0.4..=0.6).contains(&p), "out of band: {}", p);
+            assert!(p <= prev, "rose at tension {}: {} > {}", tension, p, prev);
+            prev = p;
+        }
+        let calm = SocialPhase::ProtectiveGuardian.expression_scale_at(0.8, 0.45);
+        let tense = SocialPhase::ProtectiveGuardian.expression_scale_at(0.8, 0.9);
+        assert!(tense < calm, "tense {} should be < calm {}", tense, calm);
+        // Other quadrants ignore tension.
+        assert_eq!(
+            SocialPhase::QuietlyBeloved.expression_scale_at(0.8, 0.1),
+            SocialPhase::QuietlyBeloved.expression_scale_at(0.8, 0.9)
+        );
+    }
+
     #[test]
     fn test_permeability_ordering() {
         let coh = 0.7;
@@ -588,6 +1391,27 @@ mod tests {
         assert!((sr - permeability(0.5, 0.3, SocialPhase::StartledRetreat)).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_output_channel_mappings_endpoints_and_monotonic() {
+        assert_eq!(permeability_to_motor_speed(0.0, 120.0), 0.0);
+        assert_eq!(permeability_to_motor_speed(1.0, 120.0), 120.0);
+        assert_eq!(permeability_to_motor_speed(1.5, 120.0), 120.0);
+        assert_eq!(permeability_to_sound_probability(0.0), 0.0);
+        assert_eq!(permeability_to_sound_probability(1.0), 1.0);
+        assert_eq!(permeability_to_sound_probability(-0.2), 0.0);
+        // Quiet phases rarely vocalize.
+        assert!(permeability_to_sound_probability(0.3) < 0.03);
+
+        let (mut speed, mut sound) = (0.0, 0.0);
+        for i in 1..=20 {
+            let p = i as f32 / 20.0;
+            let (s, v) = (permeability_to_motor_speed(p, 1.0), permeability_to_sound_probability(p));
+            assert!(s > speed && v > sound, "not increasing at p = {}", p);
+            speed = s;
+            sound = v;
+        }
+    }
+
     // ── NarrationDepth tests ──────────────────────────────────────────────
 
     #[test]
@@ -604,6 +1428,22 @@ mod tests {
         assert_eq!(NarrationDepth::from_permeability(1.0), NarrationDepth::Deep);
     }
 
+    #[test]
+    fn test_narration_depth_custom_thresholds_escalate_earlier() {
+        let verbose = [0.05, 0.1, 0.15, 0.2];
+        assert_eq!(NarrationDepth::from_permeability_with(0.0, &verbose), NarrationDepth::None);
+        assert_eq!(NarrationDepth::from_permeability_with(0.12, &verbose), NarrationDepth::Brief);
+        assert_eq!(NarrationDepth::from_permeability_with(0.3, &verbose), NarrationDepth::Deep);
+        assert_eq!(NarrationDepth::from_permeability(0.3), NarrationDepth::Minimal);
+        for i in 0..=20 {
+            let p = i as f32 / 20.0;
+            assert_eq!(
+                NarrationDepth::from_permeability_with(p, &NarrationDepth::DEFAULT_THRESHOLDS),
+                NarrationDepth::from_permeability(p)
+            );
+        }
+    }
+
     #[test]
     fn test_narration_depth_matches_quadrants() {
         // ShyObserver at max coherence: p=0.3 -> Minimal
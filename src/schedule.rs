@@ -0,0 +1,323 @@
+//! Time-of-day decay scheduling.
+//!
+//! A [`DecaySchedule`] divides a repeating period (e.g. one day of ticks) into
+//! segments, each with a decay multiplier. [`CoherenceField::decay_scheduled`]
+//! scales the elapsed ticks by the schedule before decaying, so a robot can
+//! forget more slowly overnight when nothing is expected to happen.
+//!
+//! The schedule modulates *time*, not rates: any per-context decay rate applies
+//! on top of it, so the two compose multiplicatively.
+//!
+//! [`CoherenceField::decay_scheduled`]: crate::accumulator::CoherenceField::decay_scheduled
+//!
+//! # Invariants
+//! - **I-DIST-001** — no_std compatible; fixed-size arrays, no heap allocation
+//! - **I-DIST-005** — Zero unsafe code
+
+/// Maximum number of segments in a [`DecaySchedule`].
+pub const MAX_SCHEDULE_SEGMENTS: usize = 8;
+
+// ─── ScheduleError ──────────────────────────────────────────────────────────
+
+/// Reasons a [`DecaySchedule`] cannot be built or deserialized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScheduleError {
+    /// The schedule already holds [`MAX_SCHEDULE_SEGMENTS`] segments.
+    Full,
+    /// A deserialized schedule has a zero period.
+    ZeroPeriod,
+    /// A deserialized schedule claims more than [`MAX_SCHEDULE_SEGMENTS`] segments.
+    TooManySegments {
+        /// The stored segment count.
+        count: usize,
+    },
+    /// A deserialized segment starts at or past the period, or not strictly
+    /// after the previous segment.
+    SegmentOutOfOrder {
+        /// The offending start offset.
+        start: u64,
+    },
+    /// A deserialized multiplier is NaN, infinite or negative.
+    InvalidMultiplier {
+        /// The stored value.
+        value: f32,
+    },
+}
+
+impl core::fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScheduleError::Full => {
+                write!(f, "DecaySchedule holds at most {} segments", MAX_SCHEDULE_SEGMENTS)
+            }
+            ScheduleError::ZeroPeriod => write!(f, "schedule period must be at least 1 tick"),
+            ScheduleError::TooManySegments { count } => {
+                write!(f, "{} segments exceed the limit of {}", count, MAX_SCHEDULE_SEGMENTS)
+            }
+            ScheduleError::SegmentOutOfOrder { start } => {
+                write!(f, "segment start {} is out of order or outside the period", start)
+            }
+            ScheduleError::InvalidMultiplier { value } => {
+                write!(f, "segment multiplier {} is not a finite value >= 0", value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScheduleError {}
+
+// ─── DecaySchedule ──────────────────────────────────────────────────────────
+
+/// Periodic map from tick-of-period to decay multiplier.
+///
+/// Each segment starts at an offset within the period and lasts until the next
+/// segment's start; the last segment wraps around to the first. A schedule
+/// with no segments is uniform (multiplier 1.0 everywhere).
+///
+/// ```rust
+/// use ccf_core::schedule::DecaySchedule;
+///
+/// // 24 000-tick day; half-rate decay from tick 20 000 to 6 000 (overnight).
+/// let day = DecaySchedule::new(24_000)
+///     .with_segment(6_000, 1.0)?
+///     .with_segment(20_000, 0.5)?;
+/// assert_eq!(day.multiplier_at(12_000), 1.0);
+/// assert_eq!(day.multiplier_at(2_000), 0.5);
+/// # Ok::<(), ccf_core::schedule::ScheduleError>(())
+/// ```
+///
+/// Deserialization checks the same invariants the builder maintains (non-zero
+/// period, at most [`MAX_SCHEDULE_SEGMENTS`] sorted segments inside the
+/// period, finite non-negative multipliers) and fails with a
+/// [`ScheduleError`] otherwise.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DecayScheduleRepr", into = "DecayScheduleRepr"))]
+pub struct DecaySchedule {
+    period: u64,
+    /// `(start offset, multiplier)`, sorted by start.
+    segments: [(u64, f32); MAX_SCHEDULE_SEGMENTS],
+    len: usize,
+}
+
+impl DecaySchedule {
+    /// Uniform schedule: multiplier 1.0 at every tick.
+    pub fn uniform() -> Self {
+        Self::new(1)
+    }
+
+    /// Empty (uniform) schedule over a repeating `period` of ticks.
+    ///
+    /// A zero `period` is treated as 1.
+    pub fn new(period: u64) -> Self {
+        Self { period: period.max(1), segments: [(0, 1.0); MAX_SCHEDULE_SEGMENTS], len: 0 }
+    }
+
+    /// Add a segment starting at `start` (taken modulo the period) with the
+    /// given `multiplier` (clamped to ≥ 0.0). Replaces any segment with the
+    /// same start.
+    ///
+    /// Fails with [`ScheduleError::Full`] if a new segment would exceed
+    /// [`MAX_SCHEDULE_SEGMENTS`].
+    pub fn with_segment(mut self, start: u64, multiplier: f32) -> Result<Self, ScheduleError> {
+        let start = start % self.period;
+        let multiplier = multiplier.max(0.0);
+        let pos = self.segments[..self.len].iter().position(|&(s, _)| s >= start);
+        match pos {
+            Some(i) if self.segments[i].0 == start => self.segments[i].1 = multiplier,
+            _ => {
+                if self.len >= MAX_SCHEDULE_SEGMENTS {
+                    return Err(ScheduleError::Full);
+                }
+                let i = pos.unwrap_or(self.len);
+                self.segments.copy_within(i..self.len, i + 1);
+                self.segments[i] = (start, multiplier);
+                self.len += 1;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Length of the repeating period in ticks.
+    pub fn period(&self) -> u64 {
+        self.period
+    }
+
+    /// Decay multiplier in effect at absolute `tick`.
+    pub fn multiplier_at(&self, tick: u64) -> f32 {
+        self.segment_at(tick % self.period).0
+    }
+
+    /// Schedule-weighted length of the tick interval `[from, to)`:
+    /// `Σ multiplier_at(t)` for `from ≤ t < to`.
+    ///
+    /// Equals `(to − from)` for a uniform schedule.
+    pub fn effective_ticks(&self, from: u64, to: u64) -> f32 {
+        if to <= from {
+            return 0.0;
+        }
+        if self.len == 0 {
+            return (to - from) as f32;
+        }
+        let full_periods = (to - from) / self.period;
+        let mut total = full_periods as f32 * self.period_weight();
+        let mut t = from + full_periods * self.period;
+        while t < to {
+            let offset = t % self.period;
+            let (multiplier, end) = self.segment_at(offset);
+            let run = (end - offset).min(to - t);
+            total += multiplier * run as f32;
+            t += run;
+        }
+        total
+    }
+
+    /// `(multiplier, end offset)` of the segment containing `offset`.
+    ///
+    /// The end is clipped at the period boundary so callers never step past it.
+    fn segment_at(&self, offset: u64) -> (f32, u64) {
+        if self.len == 0 {
+            return (1.0, self.period);
+        }
+        let segs = &self.segments[..self.len];
+        match segs.iter().rposition(|&(s, _)| s <= offset) {
+            Some(i) => {
+                let end = segs.get(i + 1).map_or(self.period, |&(s, _)| s);
+                (segs[i].1, end)
+            }
+            // Before the first start: still inside the wrapped last segment.
+            None => (segs[self.len - 1].1, segs[0].0),
+        }
+    }
+
+    /// `Σ multiplier_at(t)` over one full period.
+    fn period_weight(&self) -> f32 {
+        let segs = &self.segments[..self.len];
+        let mut total = 0.0_f32;
+        for (i, &(start, multiplier)) in segs.iter().enumerate() {
+            let end = segs.get(i + 1).map_or(self.period + segs[0].0, |&(s, _)| s);
+            total += multiplier * (end - start) as f32;
+        }
+        total
+    }
+}
+
+impl Default for DecaySchedule {
+    fn default() -> Self {
+        Self::uniform()
+    }
+}
+
+/// Serialized shape of a [`DecaySchedule`], validated on the way back in.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct DecayScheduleRepr {
+    period: u64,
+    segments: [(u64, f32); MAX_SCHEDULE_SEGMENTS],
+    len: usize,
+}
+
+#[cfg(feature = "serde")]
+impl From<DecaySchedule> for DecayScheduleRepr {
+    fn from(s: DecaySchedule) -> Self {
+        Self { period: s.period, segments: s.segments, len: s.len }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DecayScheduleRepr> for DecaySchedule {
+    type Error = ScheduleError;
+
+    fn try_from(r: DecayScheduleRepr) -> Result<Self, ScheduleError> {
+        if r.period == 0 {
+            return Err(ScheduleError::ZeroPeriod);
+        }
+        if r.len > MAX_SCHEDULE_SEGMENTS {
+            return Err(ScheduleError::TooManySegments { count: r.len });
+        }
+        let mut schedule = Self::new(r.period);
+        for (i, &(start, multiplier)) in r.segments[..r.len].iter().enumerate() {
+            if start >= r.period || (i > 0 && start <= r.segments[i - 1].0) {
+                return Err(ScheduleError::SegmentOutOfOrder { start });
+            }
+            if !multiplier.is_finite() || multiplier < 0.0 {
+                return Err(ScheduleError::InvalidMultiplier { value: multiplier });
+            }
+            schedule.segments[i] = (start, multiplier);
+        }
+        schedule.len = r.len;
+        Ok(schedule)
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day() -> DecaySchedule {
+        DecaySchedule::new(100).with_segment(80, 0.5).unwrap().with_segment(20, 1.0).unwrap()
+    }
+
+    #[test]
+    fn test_uniform_effective_ticks_is_elapsed() {
+        let u = DecaySchedule::uniform();
+        assert_eq!(u.effective_ticks(7, 1_007), 1_000.0);
+        assert_eq!(u.effective_ticks(5, 5), 0.0);
+        let ones = DecaySchedule::new(10).with_segment(3, 1.0).unwrap();
+        assert_eq!(ones.effective_ticks(4, 1_004), 1_000.0);
+    }
+
+    #[test]
+    fn test_segments_wrap_around_period() {
+        let d = day();
+        assert_eq!(d.multiplier_at(50), 1.0);
+        assert_eq!(d.multiplier_at(90), 0.5);
+        assert_eq!(d.multiplier_at(110), 0.5); // wrapped night
+        assert_eq!(d.multiplier_at(120), 1.0);
+    }
+
+    #[test]
+    fn test_effective_ticks_matches_tick_by_tick_sum() {
+        let d = day();
+        for &(from, to) in &[(0u64, 100u64), (75, 130), (15, 415), (99, 101)] {
+            let brute: f32 = (from..to).map(|t| d.multiplier_at(t)).sum();
+            assert!((d.effective_ticks(from, to) - brute).abs() < 1e-3, "[{}, {})", from, to);
+        }
+        // One full day: 60 ticks at 1.0 + 40 at 0.5.
+        assert_eq!(d.effective_ticks(0, 100), 80.0);
+    }
+
+    #[test]
+    fn test_with_segment_fails_when_full() {
+        let mut s = DecaySchedule::new(100);
+        for start in 0..MAX_SCHEDULE_SEGMENTS as u64 {
+            s = s.with_segment(start * 10, 0.5).unwrap();
+        }
+        // Replacing an existing start still succeeds; a new one does not.
+        let s = s.with_segment(10, 0.25).unwrap();
+        assert_eq!(s.multiplier_at(10), 0.25);
+        assert_eq!(s.with_segment(95, 1.0), Err(ScheduleError::Full));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_enforces_invariants() {
+        let d = day();
+        let json = serde_json::to_value(&d).unwrap();
+        assert_eq!(serde_json::from_value::<DecaySchedule>(json.clone()).unwrap(), d);
+
+        let reject = |edit: &dyn Fn(&mut serde_json::Value)| {
+            let mut bad = json.clone();
+            edit(&mut bad);
+            assert!(serde_json::from_value::<DecaySchedule>(bad).is_err());
+        };
+        reject(&|v| v["period"] = 0.into());
+        reject(&|v| v["len"] = 9.into());
+        reject(&|v| v["segments"][0][0] = 90.into()); // unsorted
+        reject(&|v| v["segments"][1][0] = 100.into()); // outside the period
+        reject(&|v| v["segments"][0][1] = (-1.0).into());
+    }
+}
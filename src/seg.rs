@@ -5,7 +5,7 @@
 //! ```text
 //! Header (32 bytes):
 //!   [0..4]   magic:              0x43_43_46_53 ("CCFS")
-//!   [4..6]   version:            u16 = 1
+//!   [4..6]   version:            u16 = 2
 //!   [6..8]   context_count:      u16
 //!   [8..16]  created_at:         i64 (Unix timestamp, 0 if unknown)
 //!   [16..24] last_active_at:     i64 (Unix timestamp of last interaction)
@@ -18,26 +18,237 @@
 //! It operates at the hash level — the vocabulary is not stored, only the FNV-1a hash
 //! of each context key. The runtime reconstructs the vocabulary from live sensor readings.
 //!
+//! With the `tiered-contexts` feature, [`CcfSegSnapshot::from_tiered`] captures
+//! a [`TieredContextMap`](crate::cardinality::TieredContextMap) the same way,
+//! tagging each record with the tier it came from.
+//!
+//! # Integrity
+//!
+//! [`CcfSegSnapshot::from_field`] stores a CRC-32 of the snapshot contents;
+//! [`CcfSegSnapshot::validate`] (and therefore `restore_into`) rejects a
+//! snapshot whose checksum no longer matches, and [`CcfSegSnapshot::verify`]
+//! is `true` only for a stored, matching checksum. Snapshots written before
+//! checksums existed load unverified: `validate` accepts them, so check
+//! [`CcfSegSnapshot::checksum_status`] for [`ChecksumStatus::Missing`] (which
+//! [`CcfSegSnapshot::from_bytes_checked`] returns alongside the snapshot) to
+//! warn about or refuse them.
+//!
+//! # Versioning and migration
+//!
+//! Version 2 adds the optional boundary graph and filter threshold and
+//! declares the fields added during version 1 — the checksum, the 64-bit
+//! context hash and the first-familiar tick. All of them stay optional: a
+//! version 2 snapshot without a checksum still loads, unverified.
+//! [`CcfSegSnapshot::validate`] accepts only [`CCF_SEG_VERSION`]; older JSON
+//! loads through [`CcfSegSnapshot::migrate`] (feature `seg-migrate`), which
+//! reads the embedded version and upgrades one version at a time, starting
+//! from the frozen [`CcfSegSnapshotV1`] shape.
+//!
+//! # Binary encoding
+//!
+//! With the `seg-binary` feature, [`CcfSegSnapshot::to_bytes`] and
+//! [`CcfSegSnapshot::from_bytes`] use postcard for a compact, no_std-friendly
+//! byte stream alongside the JSON interop path.
+//!
 //! # no_std
 //!
 //! This module requires the `serde` feature. It uses `alloc::vec::Vec` via the
 //! `serde` feature path and is compatible with no_std + alloc environments.
 //!
+//! # Whole-system snapshot
+//!
+//! [`CcfSystemSnapshot`] bundles everything a robot has learned — keyed field
+//! accumulators, the comfort-zone boundary graph, the current phase with its
+//! dwell, the personality, and the tick — into one versioned artifact. Unlike
+//! [`CcfSegSnapshot`] it stores full context keys, so it restores to live types.
+//!
 //! [`CoherenceField`]: crate::accumulator::CoherenceField
 
 extern crate alloc;
 
 use alloc::vec::Vec;
 
-use crate::accumulator::{CoherenceAccumulator, CoherenceField};
-use crate::phase::Personality;
-use crate::vocabulary::SensorVocabulary;
+use crate::accumulator::{CoherenceAccumulator, CoherenceDynamics, CoherenceField, FieldConfig, FloorParams};
+use crate::boundary::{BoundaryConfig, MinCutBoundary, MinCutResult, MAX_CONTEXTS};
+use crate::phase::{Personality, PhaseTracker};
+use crate::vocabulary::{ContextKey, ContextMetric, CosineMetric, SensorVocabulary};
+#[cfg(feature = "tiered-contexts")]
+use crate::cardinality::{merge_accumulators, Tier1Key, TieredContextMap};
+#[cfg(feature = "tiered-contexts")]
+use crate::defaults::BASELINE_PER_CURIOSITY;
+
+/// Fold a 64-bit Tier 1 key into the 32-bit `context_hash` slot.
+#[cfg(feature = "tiered-contexts")]
+fn fold_tier1_key(key: Tier1Key) -> u32 {
+    (key ^ (key >> 32)) as u32
+}
 
 /// Magic bytes identifying a CCF_SEG binary blob: "CCFS".
 pub const CCF_SEG_MAGIC: u32 = 0x43_43_46_53;
 
 /// Current CCF_SEG format version.
-pub const CCF_SEG_VERSION: u16 = 1;
+///
+/// - **1** — original format; later writers added optional fields.
+/// - **2** — declares the optional checksum, 64-bit context hash and
+///   first-familiar tick, and adds an optional boundary graph and filter
+///   threshold. Version 1 loads via [`CcfSegSnapshot::migrate`].
+pub const CCF_SEG_VERSION: u16 = 2;
+
+/// Current [`CcfSystemSnapshot`] format version.
+pub const CCF_SYSTEM_VERSION: u16 = 1;
+
+/// Reasons a snapshot cannot be restored.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SnapshotError {
+    /// The snapshot was written by an incompatible format version.
+    VersionMismatch {
+        /// Version this build understands.
+        expected: u16,
+        /// Version found in the snapshot.
+        found: u16,
+    },
+    /// More contexts or boundary nodes than the runtime can hold.
+    TooManyContexts {
+        /// Number of entries in the snapshot.
+        count: usize,
+        /// Capacity of the runtime structure.
+        max: usize,
+    },
+    /// A context record carries a coherence value that is NaN or outside [0.0, 1.0].
+    CoherenceOutOfRange {
+        /// Context hash of the offending record.
+        hash: u32,
+        /// The stored value.
+        value: f32,
+    },
+    /// A personality modulator is NaN or outside [0.0, 1.0].
+    PersonalityOutOfRange {
+        /// Name of the offending modulator.
+        field: &'static str,
+        /// The stored value.
+        value: f32,
+    },
+    /// A boundary node hash appears more than once.
+    DuplicateBoundaryNode {
+        /// The duplicated context hash.
+        hash: u32,
+    },
+    /// A boundary edge references a node index that is not in the snapshot.
+    BoundaryEdgeOutOfRange {
+        /// First endpoint index.
+        a: u16,
+        /// Second endpoint index.
+        b: u16,
+    },
+    /// A boundary edge weight is NaN or outside [0.0, 1.0].
+    BoundaryWeightOutOfRange {
+        /// First endpoint index.
+        a: u16,
+        /// Second endpoint index.
+        b: u16,
+        /// The stored weight.
+        value: f32,
+    },
+    /// The stored boundary partition does not cover every node exactly once.
+    BoundaryPartitionMismatch,
+    /// The stored checksum does not match the snapshot contents (corruption).
+    ChecksumMismatch {
+        /// Checksum stored in the snapshot.
+        stored: u32,
+        /// Checksum recomputed from the contents.
+        computed: u32,
+    },
+    /// The byte stream is not a valid snapshot encoding (truncated or garbled).
+    Malformed,
+}
+
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::VersionMismatch { expected, found } => {
+                write!(f, "snapshot version {} not supported (expected {})", found, expected)
+            }
+            SnapshotError::TooManyContexts { count, max } => {
+                write!(f, "snapshot holds {} contexts, capacity is {}", count, max)
+            }
+            SnapshotError::CoherenceOutOfRange { hash, value } => {
+                write!(f, "context {:#010x} has coherence {} outside [0, 1]", hash, value)
+            }
+            SnapshotError::PersonalityOutOfRange { field, value } => {
+                write!(f, "personality {} = {} outside [0, 1]", field, value)
+            }
+            SnapshotError::DuplicateBoundaryNode { hash } => {
+                write!(f, "boundary node {:#010x} appears more than once", hash)
+            }
+            SnapshotError::BoundaryEdgeOutOfRange { a, b } => {
+                write!(f, "boundary edge ({}, {}) references a missing node", a, b)
+            }
+            SnapshotError::BoundaryWeightOutOfRange { a, b, value } => {
+                write!(f, "boundary edge ({}, {}) has weight {} outside [0, 1]", a, b, value)
+            }
+            SnapshotError::BoundaryPartitionMismatch => {
+                write!(f, "boundary partition does not match the stored nodes")
+            }
+            SnapshotError::ChecksumMismatch { stored, computed } => {
+                write!(f, "snapshot checksum {:#010x} does not match contents ({:#010x})", stored, computed)
+            }
+            SnapshotError::Malformed => write!(f, "snapshot bytes are not a valid encoding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+/// Reasons an older snapshot cannot be migrated to [`CCF_SEG_VERSION`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MigrateError {
+    /// The input has no integer `version` field.
+    MissingVersion,
+    /// The version is newer than this build, or 0.
+    UnsupportedVersion {
+        /// Version found in the snapshot.
+        found: u16,
+        /// Newest version this build understands.
+        latest: u16,
+    },
+    /// The input does not match the shape its version declares.
+    Malformed {
+        /// Version the input declared.
+        version: u16,
+    },
+    /// The stored checksum does not match the contents of the old version.
+    ChecksumMismatch {
+        /// Version whose checksum failed.
+        version: u16,
+        /// Checksum stored in the snapshot.
+        stored: u32,
+        /// Checksum recomputed from the contents.
+        computed: u32,
+    },
+}
+
+impl core::fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MigrateError::MissingVersion => write!(f, "snapshot has no version field"),
+            MigrateError::UnsupportedVersion { found, latest } => {
+                write!(f, "snapshot version {} cannot be migrated (latest is {})", found, latest)
+            }
+            MigrateError::Malformed { version } => {
+                write!(f, "snapshot does not match the version {} format", version)
+            }
+            MigrateError::ChecksumMismatch { version, stored, computed } => write!(
+                f,
+                "version {} snapshot checksum {:#010x} does not match contents ({:#010x})",
+                version, stored, computed
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MigrateError {}
 
 /// A serializable snapshot of a [`CoherenceField`] and [`Personality`] state.
 ///
@@ -70,6 +281,33 @@ pub struct CcfSegSnapshot {
     pub personality: PersonalityRecord,
     /// All tracked context accumulators, in iteration order.
     pub contexts: Vec<ContextRecord>,
+    /// Coherence threshold of a partial snapshot from
+    /// [`Self::from_field_filtered`]: only contexts at or above it are
+    /// included. `None` when every context was captured.
+    #[serde(default)]
+    pub min_coherence: Option<f32>,
+    /// The comfort-zone boundary graph, when captured with
+    /// [`Self::from_field_and_boundary`]. `None` in field-only snapshots.
+    #[serde(default)]
+    pub boundary: Option<BoundarySnapshot>,
+    /// CRC-32 over the metadata and context records, set by [`Self::from_field`].
+    ///
+    /// `None` for snapshots written before checksums existed; those load
+    /// without integrity verification (see [`ChecksumStatus::Missing`]).
+    #[serde(default)]
+    pub checksum: Option<u32>,
+}
+
+/// Outcome of [`CcfSegSnapshot::checksum_status`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// The stored checksum matches the contents.
+    Valid,
+    /// No checksum stored (pre-checksum snapshot) — integrity unverified;
+    /// callers should warn.
+    Missing,
+    /// The stored checksum does not match — the snapshot is corrupt.
+    Mismatch,
 }
 
 /// Serializable representation of [`Personality`] modulators.
@@ -114,21 +352,79 @@ pub struct ContextRecord {
     ///
     /// [`ContextKey::context_hash_u32`]: crate::vocabulary::ContextKey::context_hash_u32
     pub context_hash: u32,
+    /// 64-bit FNV-1a hash of the context key (from [`ContextKey::context_hash_u64`]).
+    /// When present, restore also requires it to match, so two contexts whose
+    /// 32-bit hashes collide cannot share trust. Absent in older snapshots.
+    ///
+    /// [`ContextKey::context_hash_u64`]: crate::vocabulary::ContextKey::context_hash_u64
+    #[serde(default)]
+    pub context_hash_u64: Option<u64>,
     /// Accumulated coherence value [0.0, 1.0].
     pub coherence_value: f32,
     /// Total positive interactions recorded for this context.
     pub interaction_count: u32,
     /// Tick of the most recent interaction.
     pub last_interaction_tick: u64,
+    /// Tick at which the context first became familiar. Absent in older snapshots.
+    #[serde(default)]
+    pub first_familiar_tick: Option<u64>,
+    /// Which tier of a tiered context map the record came from. Absent for
+    /// snapshots of a flat [`CoherenceField`].
+    #[serde(default)]
+    pub tier: Option<RecordTier>,
+}
+
+/// Tier tag on a [`ContextRecord`] captured from a tiered context map.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordTier {
+    /// A Tier 1 coarse class accumulator; `context_hash_u64` is its coarse key.
+    Coarse,
+    /// A Tier 2 fine entry, hashed like a flat-field context.
+    Fine,
+    /// A coarse class and a fine entry whose hashes collided, stored once as
+    /// their honesty-preserving merge. Restores into both tiers.
+    Both,
+}
+
+#[cfg(feature = "tiered-contexts")]
+impl RecordTier {
+    fn is_coarse(self) -> bool {
+        matches!(self, Self::Coarse | Self::Both)
+    }
+
+    fn is_fine(self) -> bool {
+        matches!(self, Self::Fine | Self::Both)
+    }
 }
 
 impl From<(u32, &CoherenceAccumulator)> for ContextRecord {
     fn from((hash, acc): (u32, &CoherenceAccumulator)) -> Self {
         Self {
             context_hash: hash,
+            context_hash_u64: None,
             coherence_value: acc.value,
             interaction_count: acc.interaction_count,
             last_interaction_tick: acc.last_interaction_tick,
+            first_familiar_tick: acc.first_familiar_tick,
+            tier: None,
+        }
+    }
+}
+
+impl ContextRecord {
+    /// Rebuild an accumulator, taking the parameters the SEG format does not
+    /// store from the restoring side.
+    fn to_accumulator(&self, floor_params: FloorParams, dynamics: CoherenceDynamics) -> CoherenceAccumulator {
+        CoherenceAccumulator {
+            value: self.coherence_value,
+            interaction_count: self.interaction_count,
+            last_interaction_tick: self.last_interaction_tick,
+            peak_value: self.coherence_value,
+            first_familiar_tick: self.first_familiar_tick,
+            floor_params,
+            dynamics,
+            // Nor is the recent trend: restored contexts read as stable.
+            recent_ema: 0.0,
         }
     }
 }
@@ -153,17 +449,374 @@ impl CcfSegSnapshot {
     {
         let contexts: Vec<ContextRecord> = field
             .iter()
-            .map(|(key, acc)| ContextRecord::from((key.context_hash_u32(), acc)))
+            .map(|(key, acc)| ContextRecord {
+                context_hash_u64: Some(key.context_hash_u64()),
+                ..ContextRecord::from((key.context_hash_u32(), acc))
+            })
             .collect();
 
-        Self {
+        let mut snapshot = Self {
             version: CCF_SEG_VERSION,
             created_at,
             last_active_at,
             total_interactions,
             personality: PersonalityRecord::from(personality),
             contexts,
+            min_coherence: None,
+            boundary: None,
+            checksum: None,
+        };
+        snapshot.update_checksum();
+        snapshot
+    }
+
+    /// [`Self::from_field`] keeping only contexts with coherence
+    /// `≥ min_coherence`, e.g. to upload just the trusted ones.
+    ///
+    /// The threshold is recorded in [`Self::min_coherence`] so consumers know
+    /// the snapshot is partial. `total_interactions` still describes the full
+    /// history.
+    pub fn from_field_filtered<V, const N: usize>(
+        field: &CoherenceField<V, N>,
+        personality: &Personality,
+        created_at: i64,
+        last_active_at: i64,
+        total_interactions: u64,
+        min_coherence: f32,
+    ) -> Self
+    where
+        V: SensorVocabulary<N>,
+    {
+        let mut snapshot = Self::from_field(field, personality, created_at, last_active_at, total_interactions);
+        snapshot.contexts.retain(|r| r.coherence_value >= min_coherence);
+        snapshot.min_coherence = Some(min_coherence);
+        snapshot.update_checksum();
+        snapshot
+    }
+
+    /// [`Self::from_field`] plus the boundary graph, so a restored robot gets
+    /// its comfort zone back without re-deriving it over many ticks.
+    pub fn from_field_and_boundary<V, const N: usize, const C: usize, M>(
+        field: &CoherenceField<V, N>,
+        personality: &Personality,
+        created_at: i64,
+        last_active_at: i64,
+        total_interactions: u64,
+        boundary: &MinCutBoundary<V, N, C, M>,
+    ) -> Self
+    where
+        V: SensorVocabulary<N>,
+        M: ContextMetric,
+    {
+        let mut snapshot = Self::from_field(field, personality, created_at, last_active_at, total_interactions);
+        snapshot.boundary = Some(BoundarySnapshot::capture(boundary));
+        snapshot.update_checksum();
+        snapshot
+    }
+
+    /// Build a snapshot from a [`TieredContextMap`], flattening both tiers into
+    /// [`Self::contexts`] with each record's [`ContextRecord::tier`] set.
+    ///
+    /// Coarse records carry the Tier 1 key as `context_hash_u64` (folded to 32
+    /// bits for `context_hash`); fine records are hashed like
+    /// [`Self::from_field`]'s. When a coarse class and one of its fine entries
+    /// share a hash — e.g. the default all-dimensions feature mask — they are
+    /// stored once, as their honesty-preserving merge
+    /// ([`merge_accumulators`]), tagged [`RecordTier::Both`].
+    ///
+    /// Requires the `tiered-contexts` feature.
+    #[cfg(feature = "tiered-contexts")]
+    pub fn from_tiered<V, const N: usize, const T1: usize, const T2: usize>(
+        map: &TieredContextMap<V, N, T1, T2>,
+        personality: &Personality,
+        created_at: i64,
+        last_active_at: i64,
+        total_interactions: u64,
+    ) -> Self
+    where
+        V: SensorVocabulary<N>,
+    {
+        let mut contexts = Vec::new();
+        for (t1k, cls) in map.iter_tier1() {
+            let mut coarse = Some(&cls.accumulator);
+            for (key, fine) in map.tier2_entries_of(t1k) {
+                let hash_u64 = key.context_hash_u64();
+                let (acc, tier) = match coarse {
+                    Some(c) if hash_u64 == t1k => {
+                        coarse = None;
+                        (merge_accumulators(c, fine), RecordTier::Both)
+                    }
+                    _ => (fine.clone(), RecordTier::Fine),
+                };
+                contexts.push(ContextRecord {
+                    context_hash_u64: Some(hash_u64),
+                    tier: Some(tier),
+                    ..ContextRecord::from((key.context_hash_u32(), &acc))
+                });
+            }
+            if let Some(c) = coarse {
+                contexts.push(ContextRecord {
+                    context_hash_u64: Some(t1k),
+                    tier: Some(RecordTier::Coarse),
+                    ..ContextRecord::from((fold_tier1_key(t1k), c))
+                });
+            }
         }
+
+        let mut snapshot = Self {
+            version: CCF_SEG_VERSION,
+            created_at,
+            last_active_at,
+            total_interactions,
+            personality: PersonalityRecord::from(personality),
+            contexts,
+            min_coherence: None,
+            boundary: None,
+            checksum: None,
+        };
+        snapshot.update_checksum();
+        snapshot
+    }
+
+    /// CRC-32 (IEEE) of the version, metadata, personality, every context
+    /// record, and the filter threshold and boundary graph when present, in a
+    /// fixed big-endian byte order. Excludes the checksum itself.
+    pub fn compute_checksum(&self) -> u32 {
+        let mut crc = Crc32::new();
+        crc.update(&self.version.to_be_bytes());
+        crc.update(&self.created_at.to_be_bytes());
+        crc.update(&self.last_active_at.to_be_bytes());
+        crc.update(&self.total_interactions.to_be_bytes());
+        let p = &self.personality;
+        for v in [p.curiosity_drive, p.startle_sensitivity, p.recovery_speed] {
+            crc.update(&v.to_bits().to_be_bytes());
+        }
+        for r in &self.contexts {
+            crc.update(&r.context_hash.to_be_bytes());
+            if let Some(h) = r.context_hash_u64 {
+                crc.update(&h.to_be_bytes());
+            }
+            crc.update(&r.coherence_value.to_bits().to_be_bytes());
+            crc.update(&r.interaction_count.to_be_bytes());
+            crc.update(&r.last_interaction_tick.to_be_bytes());
+            // Only hashed when present, so checksums of older snapshots still verify.
+            if let Some(t) = r.first_familiar_tick {
+                crc.update(&t.to_be_bytes());
+            }
+            if let Some(tier) = r.tier {
+                crc.update(&[tier as u8]);
+            }
+        }
+        if let Some(m) = self.min_coherence {
+            crc.update(&m.to_bits().to_be_bytes());
+        }
+        if let Some(b) = &self.boundary {
+            let c = &b.config;
+            for v in [c.edge_threshold, c.trust_scale, c.recency_lambda] {
+                crc.update(&v.to_bits().to_be_bytes());
+            }
+            crc.update(&c.min_trust_observations.to_be_bytes());
+            for n in &b.nodes {
+                crc.update(&n.context_hash.to_be_bytes());
+                crc.update(&n.coherence.to_bits().to_be_bytes());
+                crc.update(&n.observations.to_be_bytes());
+                if let Some(t) = n.last_tick {
+                    crc.update(&t.to_be_bytes());
+                }
+            }
+            for e in &b.edges {
+                crc.update(&e.a.to_be_bytes());
+                crc.update(&e.b.to_be_bytes());
+                crc.update(&e.weight.to_bits().to_be_bytes());
+                if let Some(w) = e.base_weight {
+                    crc.update(&w.to_bits().to_be_bytes());
+                }
+            }
+            if let Some(t) = b.current_tick {
+                crc.update(&t.to_be_bytes());
+            }
+            if let Some(p) = &b.partition {
+                crc.update(&p.min_cut_value.to_bits().to_be_bytes());
+                for h in p.s.iter().chain(&p.complement) {
+                    crc.update(&h.to_be_bytes());
+                }
+                crc.update(&(p.s.len() as u32).to_be_bytes());
+            }
+        }
+        crc.finish()
+    }
+
+    /// Recompute and store the checksum, e.g. after deliberately editing records.
+    pub fn update_checksum(&mut self) {
+        self.checksum = Some(self.compute_checksum());
+    }
+
+    /// Whether the stored checksum matches, is missing, or is wrong.
+    pub fn checksum_status(&self) -> ChecksumStatus {
+        match self.checksum {
+            None => ChecksumStatus::Missing,
+            Some(stored) if stored == self.compute_checksum() => ChecksumStatus::Valid,
+            Some(_) => ChecksumStatus::Mismatch,
+        }
+    }
+
+    /// `false` only if a stored checksum does not match the contents.
+    ///
+    /// Snapshots without a checksum pass; use [`Self::checksum_status`] to
+    /// distinguish them.
+    pub fn verify_checksum(&self) -> bool {
+        self.checksum_status() != ChecksumStatus::Mismatch
+    }
+
+    /// `true` only if a checksum is stored and matches the contents.
+    ///
+    /// Stricter than [`Self::verify_checksum`]: a snapshot without a checksum
+    /// cannot be verified and returns `false`.
+    pub fn verify(&self) -> bool {
+        self.checksum_status() == ChecksumStatus::Valid
+    }
+
+    /// Check that this snapshot is safe to restore.
+    ///
+    /// Rejects an unsupported version, more than `MAX_CONTEXTS` records,
+    /// personality modulators outside [0.0, 1.0], any coherence value that
+    /// is NaN or outside [0.0, 1.0], and a stored checksum that does not match
+    /// (an absent checksum is not an error). The error names the first failing
+    /// check. Snapshots produced by [`Self::from_field`] always pass.
+    pub fn validate(&self) -> Result<(), SnapshotError> {
+        if self.version != CCF_SEG_VERSION {
+            return Err(SnapshotError::VersionMismatch {
+                expected: CCF_SEG_VERSION,
+                found: self.version,
+            });
+        }
+        if self.contexts.len() > MAX_CONTEXTS {
+            return Err(SnapshotError::TooManyContexts { count: self.contexts.len(), max: MAX_CONTEXTS });
+        }
+        let p = &self.personality;
+        for (field, value) in [
+            ("curiosity_drive", p.curiosity_drive),
+            ("startle_sensitivity", p.startle_sensitivity),
+            ("recovery_speed", p.recovery_speed),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(SnapshotError::PersonalityOutOfRange { field, value });
+            }
+        }
+        for record in &self.contexts {
+            if !(0.0..=1.0).contains(&record.coherence_value) {
+                return Err(SnapshotError::CoherenceOutOfRange {
+                    hash: record.context_hash,
+                    value: record.coherence_value,
+                });
+            }
+        }
+        if let Some(stored) = self.checksum {
+            let computed = self.compute_checksum();
+            if stored != computed {
+                return Err(SnapshotError::ChecksumMismatch { stored, computed });
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore this snapshot into a live field after [`Self::validate`] passes.
+    ///
+    /// The snapshot stores hashes only, so the caller supplies the context keys
+    /// it can currently name (e.g. every key its vocabulary can produce). Each
+    /// candidate whose hash appears in the snapshot gets its accumulator
+    /// restored; the field adopts the snapshot personality for future cold
+    /// starts. Returns the number of contexts restored.
+    pub fn restore_into<V, const N: usize>(
+        &self,
+        field: &mut CoherenceField<V, N>,
+        candidate_keys: &[ContextKey<V, N>],
+    ) -> Result<usize, SnapshotError>
+    where
+        V: SensorVocabulary<N>,
+    {
+        self.validate()?;
+        field.update_personality(&Personality::from(&self.personality));
+        let mut restored = 0;
+        for key in candidate_keys {
+            if let Some(record) = self.find_context_for(key) {
+                // Not stored in the SEG format: use the field's configuration.
+                let acc = record.to_accumulator(field.config().floor_params, field.config().dynamics);
+                field.insert_accumulator(key.clone(), acc);
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Restore a snapshot taken with [`Self::from_tiered`] into `map`, after
+    /// [`Self::validate`] passes.
+    ///
+    /// As with [`Self::restore_into`], the caller supplies the context keys it
+    /// can name. For each candidate, the coarse record of its Tier 1 class
+    /// (under `map`'s feature mask) and its own fine record are restored into
+    /// their tiers; a [`RecordTier::Both`] record fills both. Records without a
+    /// tier tag are ignored. Returns the number of accumulators restored.
+    ///
+    /// Requires the `tiered-contexts` feature.
+    #[cfg(feature = "tiered-contexts")]
+    pub fn restore_tiered<V, const N: usize, const T1: usize, const T2: usize>(
+        &self,
+        map: &mut TieredContextMap<V, N, T1, T2>,
+        candidate_keys: &[ContextKey<V, N>],
+    ) -> Result<usize, SnapshotError>
+    where
+        V: SensorVocabulary<N>,
+    {
+        self.validate()?;
+        let curiosity = self.personality.curiosity_drive.clamp(0.0, 1.0);
+        map.set_personality_baseline(BASELINE_PER_CURIOSITY * curiosity);
+        // Not stored in the SEG format, nor configured on the map: use the defaults.
+        let template = CoherenceAccumulator::new();
+        let restore = |r: &ContextRecord| r.to_accumulator(template.floor_params, template.dynamics);
+
+        let mut restored_classes: Vec<Tier1Key> = Vec::new();
+        let mut restored = 0;
+        for key in candidate_keys {
+            let t1k = map.tier1_key_of(key);
+            if !restored_classes.contains(&t1k) {
+                let coarse = self
+                    .contexts
+                    .iter()
+                    .find(|r| r.tier.is_some_and(RecordTier::is_coarse) && r.context_hash_u64 == Some(t1k));
+                if let Some(record) = coarse {
+                    map.insert_tier1_accumulator(t1k, restore(record));
+                    restored_classes.push(t1k);
+                    restored += 1;
+                }
+            }
+            let (hash, hash_u64) = (key.context_hash_u32(), key.context_hash_u64());
+            let fine = self.contexts.iter().find(|r| {
+                r.tier.is_some_and(RecordTier::is_fine)
+                    && r.context_hash == hash
+                    && r.context_hash_u64 == Some(hash_u64)
+            });
+            if let Some(record) = fine {
+                map.insert_tier2_accumulator(key, restore(record));
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Rebuild the stored boundary after [`Self::validate`] passes.
+    ///
+    /// Returns `Ok(None)` for a field-only snapshot. The restored graph is
+    /// identical, so its min-cut partition is too.
+    pub fn restore_boundary<V, const N: usize, const C: usize, M>(
+        &self,
+    ) -> Result<Option<MinCutBoundary<V, N, C, M>>, SnapshotError>
+    where
+        V: SensorVocabulary<N>,
+        M: ContextMetric,
+    {
+        self.validate()?;
+        self.boundary.as_ref().map(BoundarySnapshot::restore).transpose()
     }
 
     /// Number of context entries in this snapshot.
@@ -177,4 +830,488 @@ impl CcfSegSnapshot {
     pub fn find_context(&self, hash: u32) -> Option<&ContextRecord> {
         self.contexts.iter().find(|r| r.context_hash == hash)
     }
+
+    /// Look up the record for `key`, matching the 64-bit hash as well when the
+    /// record carries one.
+    pub fn find_context_for<V, const N: usize>(&self, key: &ContextKey<V, N>) -> Option<&ContextRecord>
+    where
+        V: SensorVocabulary<N>,
+    {
+        let (hash, hash_u64) = (key.context_hash_u32(), key.context_hash_u64());
+        self.contexts
+            .iter()
+            .find(|r| r.context_hash == hash && r.context_hash_u64.map_or(true, |h| h == hash_u64))
+    }
+
+    /// Encode as a compact [postcard](https://docs.rs/postcard) byte stream.
+    ///
+    /// Requires the `seg-binary` feature. The JSON (serde) path is unaffected;
+    /// use this where flash space and parse time matter.
+    #[cfg(feature = "seg-binary")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        postcard::to_allocvec(self).expect("CcfSegSnapshot fields are always encodable")
+    }
+
+    /// Decode a byte stream produced by [`Self::to_bytes`].
+    ///
+    /// Only the encoding is checked; call [`Self::validate`] before trusting
+    /// the contents.
+    #[cfg(feature = "seg-binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes(bytes)
+    }
+
+    /// [`Self::from_bytes`], rejecting flash corruption: fails with
+    /// [`SnapshotError::Malformed`] if the bytes do not decode and
+    /// [`SnapshotError::ChecksumMismatch`] if they decode to contents that no
+    /// longer match the stored checksum.
+    ///
+    /// A snapshot without a checksum still loads, but comes back with
+    /// [`ChecksumStatus::Missing`] so the caller can warn or refuse it; a
+    /// verified one comes back with [`ChecksumStatus::Valid`].
+    #[cfg(feature = "seg-binary")]
+    pub fn from_bytes_checked(bytes: &[u8]) -> Result<(Self, ChecksumStatus), SnapshotError> {
+        let snapshot = Self::from_bytes(bytes).map_err(|_| SnapshotError::Malformed)?;
+        match (snapshot.checksum, snapshot.checksum_status()) {
+            (Some(stored), ChecksumStatus::Mismatch) => {
+                Err(SnapshotError::ChecksumMismatch { stored, computed: snapshot.compute_checksum() })
+            }
+            (_, status) => Ok((snapshot, status)),
+        }
+    }
+}
+
+// ─── Migration ──────────────────────────────────────────────────────────────
+
+/// Frozen shape of a version 1 [`CcfSegSnapshot`], for migration.
+///
+/// Late version 1 writers already emitted the optional checksum, 64-bit hash
+/// and first-familiar tick; they are read when present and carried over.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CcfSegSnapshotV1 {
+    /// Always 1.
+    pub version: u16,
+    /// Unix timestamp (seconds) when the field was first created.
+    pub created_at: i64,
+    /// Unix timestamp (seconds) of the most recent interaction.
+    pub last_active_at: i64,
+    /// Total interactions recorded across all contexts.
+    pub total_interactions: u64,
+    /// Personality modulators at snapshot time.
+    pub personality: PersonalityRecord,
+    /// Context accumulators.
+    pub contexts: Vec<ContextRecord>,
+    /// CRC-32 computed over the version 1 contents, if written.
+    #[serde(default)]
+    pub checksum: Option<u32>,
+}
+
+impl CcfSegSnapshotV1 {
+    /// Upgrade to version 2.
+    ///
+    /// A stored checksum is verified against the version 1 contents first
+    /// (mismatch fails with [`MigrateError::ChecksumMismatch`]) and then
+    /// recomputed for version 2. A snapshot without one stays unverified.
+    pub fn upgrade(self) -> Result<CcfSegSnapshot, MigrateError> {
+        let mut snapshot = CcfSegSnapshot {
+            version: 1,
+            created_at: self.created_at,
+            last_active_at: self.last_active_at,
+            total_interactions: self.total_interactions,
+            personality: self.personality,
+            contexts: self.contexts,
+            min_coherence: None,
+            boundary: None,
+            checksum: self.checksum,
+        };
+        if let Some(stored) = snapshot.checksum {
+            let computed = snapshot.compute_checksum();
+            if stored != computed {
+                return Err(MigrateError::ChecksumMismatch { version: 1, stored, computed });
+            }
+        }
+        snapshot.version = 2;
+        if snapshot.checksum.is_some() {
+            snapshot.update_checksum();
+        }
+        Ok(snapshot)
+    }
+}
+
+#[cfg(feature = "seg-migrate")]
+impl CcfSegSnapshot {
+    /// Load a snapshot of any supported version from parsed JSON, upgrading
+    /// it step by step to [`CCF_SEG_VERSION`].
+    ///
+    /// Fails with [`MigrateError::MissingVersion`] without an integer
+    /// `version`, [`MigrateError::UnsupportedVersion`] for a version newer
+    /// than this build (or 0), and [`MigrateError::Malformed`] if the JSON
+    /// does not match the shape its version declares.
+    pub fn migrate(raw: serde_json::Value) -> Result<Self, MigrateError> {
+        let version = raw
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|v| u16::try_from(v).ok())
+            .ok_or(MigrateError::MissingVersion)?;
+        match version {
+            1 => serde_json::from_value::<CcfSegSnapshotV1>(raw)
+                .map_err(|_| MigrateError::Malformed { version })?
+                .upgrade(),
+            CCF_SEG_VERSION => {
+                serde_json::from_value(raw).map_err(|_| MigrateError::Malformed { version })
+            }
+            found => Err(MigrateError::UnsupportedVersion { found, latest: CCF_SEG_VERSION }),
+        }
+    }
+}
+
+// ─── CRC-32 ─────────────────────────────────────────────────────────────────
+
+/// CRC-32 (IEEE 802.3, reflected polynomial 0xEDB88320) lookup table.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+/// Streaming CRC-32 over byte slices.
+struct Crc32(u32);
+
+impl Crc32 {
+    fn new() -> Self {
+        Self(0xFFFF_FFFF)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = CRC32_TABLE[((self.0 ^ b as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+// ─── Boundary snapshot ──────────────────────────────────────────────────────
+
+/// One node of a persisted [`MinCutBoundary`] graph.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BoundaryNodeRecord {
+    /// FNV-1a hash of the context key.
+    pub context_hash: u32,
+    /// Coherence last reported via `update_trust`.
+    pub coherence: f32,
+    /// Observations last reported via `update_trust`.
+    pub observations: u32,
+    /// Tick of the last trust update, for recency weighting. `None` in
+    /// snapshots written before it was stored; the node is then as fresh as
+    /// [`BoundarySnapshot::current_tick`].
+    #[serde(default)]
+    pub last_tick: Option<u64>,
+}
+
+/// One undirected edge of a persisted [`MinCutBoundary`] graph.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BoundaryEdgeRecord {
+    /// Index of the first endpoint in [`BoundarySnapshot::nodes`].
+    pub a: u16,
+    /// Index of the second endpoint in [`BoundarySnapshot::nodes`].
+    pub b: u16,
+    /// Current edge weight (Graph A or Graph B).
+    pub weight: f32,
+    /// Graph A (cosine) baseline weight. `None` in snapshots written before
+    /// the baseline was stored; `weight` is then used as the baseline.
+    #[serde(default)]
+    pub base_weight: Option<f32>,
+}
+
+/// A persisted minimum cut of a [`MinCutBoundary`] graph.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BoundaryPartitionRecord {
+    /// Weight of the minimum cut.
+    pub min_cut_value: f32,
+    /// Context hashes on the S side.
+    pub s: Vec<u32>,
+    /// Context hashes on the complement side.
+    pub complement: Vec<u32>,
+}
+
+/// Serializable copy of a [`MinCutBoundary`] graph: nodes in insertion order
+/// plus every non-zero edge.
+///
+/// Only context hashes are stored. After [`Self::restore`], each node's key
+/// comes back when the context is next passed to
+/// [`MinCutBoundary::report_context_with_key`]; until then
+/// [`MinCutBoundary::side_keys`] skips it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BoundarySnapshot {
+    /// Registered nodes, in index order.
+    pub nodes: Vec<BoundaryNodeRecord>,
+    /// Edges with `a < b` whose current or baseline weight is non-zero.
+    pub edges: Vec<BoundaryEdgeRecord>,
+    /// Boundary parameters. Defaults in snapshots written before they were stored.
+    #[serde(default)]
+    pub config: BoundaryConfig,
+    /// Recency clock (latest tick seen by `update_trust_at`). `None` in
+    /// snapshots written before it was stored, which restore at tick 0.
+    #[serde(default)]
+    pub current_tick: Option<u64>,
+    /// The minimum cut cached by `MinCutBoundary::refresh`, if it was up to
+    /// date at capture time. Restored as the cache so queries do not recompute it.
+    #[serde(default)]
+    pub partition: Option<BoundaryPartitionRecord>,
+}
+
+impl BoundarySnapshot {
+    /// Capture the graph of a live boundary.
+    pub fn capture<V, const N: usize, const C: usize, M>(boundary: &MinCutBoundary<V, N, C, M>) -> Self
+    where
+        V: SensorVocabulary<N>,
+        M: ContextMetric,
+    {
+        let n = boundary.node_count();
+        let nodes = (0..n)
+            .filter_map(|i| boundary.node_entry(i))
+            .map(|(context_hash, coherence, observations, last_tick)| BoundaryNodeRecord {
+                context_hash,
+                coherence,
+                observations,
+                last_tick: Some(last_tick),
+            })
+            .collect();
+        let mut edges = Vec::new();
+        for a in 0..n {
+            for b in (a + 1)..n {
+                let weight = boundary.edge_weight(a, b);
+                let base = boundary.base_edge_weight(a, b);
+                if weight != 0.0 || base != 0.0 {
+                    edges.push(BoundaryEdgeRecord {
+                        a: a as u16,
+                        b: b as u16,
+                        weight,
+                        base_weight: Some(base),
+                    });
+                }
+            }
+        }
+        let partition = boundary.cached_partition().map(|r| BoundaryPartitionRecord {
+            min_cut_value: r.min_cut_value,
+            s: r.partition_s[..r.partition_s_count].to_vec(),
+            complement: r.partition_complement[..r.partition_complement_count].to_vec(),
+        });
+        Self {
+            nodes,
+            edges,
+            config: *boundary.config(),
+            current_tick: Some(boundary.current_tick()),
+            partition,
+        }
+    }
+
+    /// Rebuild a boundary with the identical graph, recency clock and
+    /// partition.
+    ///
+    /// Fails with [`SnapshotError::TooManyContexts`] if the snapshot holds more
+    /// nodes than the target capacity `C`, with
+    /// [`SnapshotError::BoundaryWeightOutOfRange`] if an edge weight is NaN or
+    /// outside [0.0, 1.0] (I-BNDRY-002), and with
+    /// [`SnapshotError::BoundaryPartitionMismatch`] if the stored partition
+    /// does not cover every node exactly once.
+    pub fn restore<V, const N: usize, const C: usize, M>(
+        &self,
+    ) -> Result<MinCutBoundary<V, N, C, M>, SnapshotError>
+    where
+        V: SensorVocabulary<N>,
+        M: ContextMetric,
+    {
+        if self.nodes.len() > C {
+            return Err(SnapshotError::TooManyContexts { count: self.nodes.len(), max: C });
+        }
+        let current_tick = self.current_tick.unwrap_or(0);
+        let mut boundary = MinCutBoundary::with_config(self.config);
+        boundary.set_current_tick(current_tick);
+        for node in &self.nodes {
+            let last_tick = node.last_tick.unwrap_or(current_tick);
+            if !boundary.push_node(node.context_hash, node.coherence, node.observations, last_tick) {
+                return Err(SnapshotError::DuplicateBoundaryNode { hash: node.context_hash });
+            }
+        }
+        for edge in &self.edges {
+            let (a, b) = (edge.a as usize, edge.b as usize);
+            if a >= self.nodes.len() || b >= self.nodes.len() || a == b {
+                return Err(SnapshotError::BoundaryEdgeOutOfRange { a: edge.a, b: edge.b });
+            }
+            let base = edge.base_weight.unwrap_or(edge.weight);
+            for value in [edge.weight, base] {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(SnapshotError::BoundaryWeightOutOfRange { a: edge.a, b: edge.b, value });
+                }
+            }
+            boundary.set_edge_weights(a, b, edge.weight, base);
+        }
+        if let Some(p) = &self.partition {
+            boundary.seed_partition(self.partition_result(p)?);
+        }
+        Ok(boundary)
+    }
+
+    /// Convert a stored partition back to a [`MinCutResult`], checking that
+    /// its two sides cover every node exactly once.
+    fn partition_result<const C: usize>(
+        &self,
+        p: &BoundaryPartitionRecord,
+    ) -> Result<MinCutResult<C>, SnapshotError> {
+        let n = self.nodes.len();
+        let mut seen = alloc::vec![false; n];
+        for hash in p.s.iter().chain(&p.complement) {
+            match self.nodes.iter().position(|node| node.context_hash == *hash) {
+                Some(i) if !seen[i] => seen[i] = true,
+                _ => return Err(SnapshotError::BoundaryPartitionMismatch),
+            }
+        }
+        if seen.iter().any(|s| !s) || !p.min_cut_value.is_finite() {
+            return Err(SnapshotError::BoundaryPartitionMismatch);
+        }
+        let mut result = MinCutResult {
+            min_cut_value: p.min_cut_value,
+            partition_s_count: p.s.len(),
+            partition_s: [0; C],
+            partition_complement_count: p.complement.len(),
+            partition_complement: [0; C],
+        };
+        result.partition_s[..p.s.len()].copy_from_slice(&p.s);
+        result.partition_complement[..p.complement.len()].copy_from_slice(&p.complement);
+        Ok(result)
+    }
+}
+
+// ─── Whole-system snapshot ──────────────────────────────────────────────────
+
+/// A context accumulator stored with its full context key.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct KeyedContextRecord<V: SensorVocabulary<N>, const N: usize> {
+    /// The context key.
+    pub key: ContextKey<V, N>,
+    /// The accumulator state for that context.
+    pub accumulator: CoherenceAccumulator,
+}
+
+/// The robot's entire relational state as one serde artifact.
+///
+/// Captures the coherence field (full keys, personality baseline, fallback and
+/// config), the comfort-zone boundary graph, the phase tracker, the personality
+/// and the tick. Versioned as a whole: [`Self::restore`] rejects any snapshot
+/// whose `version` differs from [`CCF_SYSTEM_VERSION`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, tick);
+/// let json = serde_json::to_string(&snap).unwrap();
+/// let back: CcfSystemSnapshot<MbotSensors, 6> = serde_json::from_str(&json).unwrap();
+/// let (field, boundary, tracker) = back.restore().unwrap();
+/// ```
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CcfSystemSnapshot<V: SensorVocabulary<N>, const N: usize> {
+    /// Format version — always [`CCF_SYSTEM_VERSION`] for newly captured snapshots.
+    pub version: u16,
+    /// Tick at capture time.
+    pub tick: u64,
+    /// Personality modulators at capture time.
+    pub personality: PersonalityRecord,
+    /// Field personality baseline (0.15 × curiosity_drive).
+    pub personality_baseline: f32,
+    /// Field fallback coherence for unseen contexts.
+    pub fallback_coherence: Option<f32>,
+    /// Field configuration.
+    pub field_config: FieldConfig,
+    /// Every tracked context with its accumulator.
+    pub contexts: Vec<KeyedContextRecord<V, N>>,
+    /// Comfort-zone boundary graph.
+    pub boundary: BoundarySnapshot,
+    /// Current phase and dwell.
+    pub phase: PhaseTracker,
+    /// Tick up to which field decay had been applied.
+    #[serde(default)]
+    pub last_decay_tick: u64,
+}
+
+impl<V: SensorVocabulary<N>, const N: usize> CcfSystemSnapshot<V, N> {
+    /// Capture field, boundary, phase tracker and personality at `tick`.
+    pub fn capture<M: ContextMetric>(
+        field: &CoherenceField<V, N>,
+        boundary: &MinCutBoundary<V, N, MAX_CONTEXTS, M>,
+        phase: &PhaseTracker,
+        personality: &Personality,
+        tick: u64,
+    ) -> Self {
+        let contexts = field
+            .iter()
+            .map(|(key, acc)| KeyedContextRecord { key: key.clone(), accumulator: acc.clone() })
+            .collect();
+        Self {
+            version: CCF_SYSTEM_VERSION,
+            tick,
+            personality: PersonalityRecord::from(personality),
+            personality_baseline: field.personality_baseline(),
+            fallback_coherence: field.fallback(),
+            field_config: field.config().clone(),
+            contexts,
+            boundary: BoundarySnapshot::capture(boundary),
+            phase: phase.clone(),
+            last_decay_tick: field.last_decay_tick(),
+        }
+    }
+
+    /// Rebuild the field, boundary and phase tracker.
+    ///
+    /// The personality is available as [`Self::personality`].
+    pub fn restore(
+        &self,
+    ) -> Result<(CoherenceField<V, N>, MinCutBoundary<V, N>, PhaseTracker), SnapshotError> {
+        self.restore_with_metric::<CosineMetric>()
+    }
+
+    /// [`Self::restore`] for a boundary captured with the [`ContextMetric`] `M`.
+    #[allow(clippy::type_complexity)]
+    pub fn restore_with_metric<M: ContextMetric>(
+        &self,
+    ) -> Result<
+        (CoherenceField<V, N>, MinCutBoundary<V, N, MAX_CONTEXTS, M>, PhaseTracker),
+        SnapshotError,
+    > {
+        if self.version != CCF_SYSTEM_VERSION {
+            return Err(SnapshotError::VersionMismatch {
+                expected: CCF_SYSTEM_VERSION,
+                found: self.version,
+            });
+        }
+        if self.contexts.len() > MAX_CONTEXTS {
+            return Err(SnapshotError::TooManyContexts { count: self.contexts.len(), max: MAX_CONTEXTS });
+        }
+        let mut boundary: MinCutBoundary<V, N, MAX_CONTEXTS, M> = self.boundary.restore()?;
+        for record in &self.contexts {
+            boundary.attach_key(&record.key);
+        }
+
+        let mut field = CoherenceField::with_config(self.field_config.clone());
+        field.set_personality_baseline(self.personality_baseline);
+        field.set_fallback(self.fallback_coherence);
+        field.set_last_decay_tick(self.last_decay_tick);
+        for record in &self.contexts {
+            field.insert_accumulator(record.key.clone(), record.accumulator.clone());
+        }
+        Ok((field, boundary, self.phase.clone()))
+    }
 }
@@ -9,6 +9,14 @@
 //! This matrix constrains cross-context trust transfer: no single context can
 //! dominate, and the total trust weight is conserved across all contexts.
 //!
+//! [`SinkhornKnopp::project_rect`] generalises the same iteration to
+//! rectangular matrices and arbitrary positive row/column targets.
+//!
+//! Setting [`SinkhornKnopp::stabilized`] runs every projection in log space
+//! instead ([`SinkhornKnopp::project_log`]), which tolerates entries many
+//! orders of magnitude apart at roughly twice the cost.
+//! [`SinkhornKnoppF64`] runs the multiplicative iteration in double precision.
+//!
 //! # Reference
 //! DeepSeek mHC (arXiv:2512.24880) applies the same mathematics to gradient
 //! stability. CCF applies it to trust stability.
@@ -31,6 +39,29 @@ pub struct ConvergenceResult {
     pub residual: f32,
 }
 
+/// Diagonal scalings found by [`SinkhornKnopp::project_scaled`].
+///
+/// The projection of `A` is `diag(row_scale) · A · diag(col_scale)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SinkhornScaling<const N: usize> {
+    /// Left scaling `u`: factor applied to each row.
+    pub row_scale: [f32; N],
+    /// Right scaling `v`: factor applied to each column.
+    pub col_scale: [f32; N],
+}
+
+impl<const N: usize> SinkhornScaling<N> {
+    /// Scale `m` in place to `diag(row_scale) · m · diag(col_scale)`, e.g. to
+    /// transport a second matrix with the scaling learned on the first.
+    pub fn apply(&self, m: &mut [[f32; N]; N]) {
+        for (row, &u) in m.iter_mut().zip(&self.row_scale) {
+            for (x, &v) in row.iter_mut().zip(&self.col_scale) {
+                *x *= u * v;
+            }
+        }
+    }
+}
+
 /// Birkhoff polytope projector — doubly stochastic matrix via Sinkhorn-Knopp iteration.
 ///
 /// Patent Claims 19–23.
@@ -41,18 +72,24 @@ pub struct SinkhornKnopp {
     pub tolerance: f32,
     /// Maximum number of row+column normalisation iterations (default: 20).
     pub max_iterations: u32,
+    /// Iterate in log space (default: false, the faster multiplicative path).
+    ///
+    /// Enable for trust matrices with near-zero similarities — see
+    /// [`Self::project_log`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stabilized: bool,
 }
 
 impl Default for SinkhornKnopp {
     fn default() -> Self {
-        Self { tolerance: 1e-6, max_iterations: 20 }
+        Self { tolerance: 1e-6, max_iterations: 20, stabilized: false }
     }
 }
 
 impl SinkhornKnopp {
     /// Create a new projector with the given tolerance and iteration cap.
     pub fn new(tolerance: f32, max_iterations: u32) -> Self {
-        Self { tolerance, max_iterations }
+        Self { tolerance, max_iterations, stabilized: false }
     }
 
     /// Project an N×N matrix in-place to the Birkhoff polytope.
@@ -61,12 +98,53 @@ impl SinkhornKnopp {
     /// one positive entry (otherwise that marginal cannot be normalised).
     ///
     /// After convergence, every row sum and column sum is 1.0 ± `tolerance`.
+    /// Equivalent to [`Self::project_rect`] with every target 1.0.
     ///
     /// Patent Claim 19: doubly stochastic constraint.
     /// Patent Claim 20: iterative normalisation procedure.
     /// Patent Claim 21: bounded mixing (no row dominates after projection).
     /// Patent Claim 22: non-negativity preservation.
     /// Patent Claim 23: Birkhoff polytope membership.
+    pub fn project<const N: usize>(&self, m: &mut [[f32; N]; N]) -> ConvergenceResult {
+        self.project_with(m, |_, _| true)
+    }
+
+    /// [`Self::project`] with a per-iteration hook, for tuning `tolerance`
+    /// and `max_iterations`.
+    ///
+    /// `on_iter(iteration, residual)` runs after every row+column pass, with
+    /// `iteration` counted from 1 and the same residual the convergence check
+    /// uses. Returning `false` stops early with `converged: false` (unless
+    /// that pass already met the tolerance). Allocation-free.
+    pub fn project_with<const N: usize>(
+        &self,
+        m: &mut [[f32; N]; N],
+        on_iter: impl FnMut(usize, f32) -> bool,
+    ) -> ConvergenceResult {
+        self.scale_to_marginals(m, (N, N), |_| 1.0, |_| 1.0, (&mut [], &mut []), on_iter)
+    }
+
+    /// [`Self::project`], also returning the diagonal scalings it applied.
+    ///
+    /// The projected matrix equals `diag(row_scale) · A · diag(col_scale)` for
+    /// the input `A`, up to rounding; see [`SinkhornScaling::apply`] to scale
+    /// a second matrix the same way.
+    pub fn project_scaled<const N: usize>(
+        &self,
+        m: &mut [[f32; N]; N],
+    ) -> (ConvergenceResult, SinkhornScaling<N>) {
+        let mut scaling = SinkhornScaling { row_scale: [1.0; N], col_scale: [1.0; N] };
+        let result = self.scale_to_marginals(
+            m,
+            (N, N),
+            |_| 1.0,
+            |_| 1.0,
+            (&mut scaling.row_scale, &mut scaling.col_scale),
+            |_, _| true,
+        );
+        (result, scaling)
+    }
+
     /// Project an n×n matrix stored as a flat row-major slice in-place.
     ///
     /// This is the runtime-n variant of [`Self::project`] used by the
@@ -80,100 +158,455 @@ impl SinkhornKnopp {
     /// same convergence criterion.
     pub fn project_flat(&self, m: &mut [f32], n: usize) -> ConvergenceResult {
         debug_assert_eq!(m.len(), n * n, "project_flat: slice length must be n*n");
+        self.scale_to_marginals(&mut Flat { m, cols: n }, (n, n), |_| 1.0, |_| 1.0, (&mut [], &mut []), |_, _| true)
+    }
 
-        for iter in 0..self.max_iterations {
-            // Row normalisation
-            for i in 0..n {
-                let s: f32 = m[i * n..(i + 1) * n].iter().sum();
-                if s > 1e-12 {
-                    let inv = 1.0 / s;
-                    for x in &mut m[i * n..(i + 1) * n] {
-                        *x *= inv;
-                    }
-                }
+    /// Scale a `rows × cols` row-major matrix in-place toward the given row
+    /// and column marginals.
+    ///
+    /// Alternately rescales each row to sum to `row_targets[i]` and each
+    /// column to sum to `col_targets[j]`, e.g. to mix `m` clusters against
+    /// `n` contexts with unequal weights. Targets must be positive, and both
+    /// must have the same total — otherwise no matrix satisfies them and the
+    /// result reports `converged: false`. `residual` is the maximum
+    /// `|row_sum(i) − row_targets[i]|`; column sums are exact after each
+    /// iteration. The square projections are the special case of all targets
+    /// equal to 1.0.
+    ///
+    /// Slice lengths must be `rows * cols`, `rows` and `cols` (debug-checked).
+    pub fn project_rect(
+        &self,
+        m: &mut [f32],
+        rows: usize,
+        cols: usize,
+        row_targets: &[f32],
+        col_targets: &[f32],
+    ) -> ConvergenceResult {
+        debug_assert_eq!(m.len(), rows * cols, "project_rect: slice length must be rows*cols");
+        debug_assert_eq!(row_targets.len(), rows, "project_rect: one target per row");
+        debug_assert_eq!(col_targets.len(), cols, "project_rect: one target per column");
+        let (row_target, col_target) = (|i: usize| row_targets[i], |j: usize| col_targets[j]);
+        let flat = &mut Flat { m, cols };
+        self.scale_to_marginals(flat, (rows, cols), row_target, col_target, (&mut [], &mut []), |_, _| true)
+    }
+
+    /// [`Self::project_rect`] iterated in log space, whatever [`Self::stabilized`] says.
+    ///
+    /// Entries are replaced by their logarithms and each row and column is
+    /// shifted by `ln(target) − logsumexp(...)`, so the iteration never
+    /// divides by a tiny sum and cannot overflow. The multiplicative path
+    /// skips any row or column summing below 1e-12 and can therefore stall on
+    /// near-zero similarities; this path normalises them like any other.
+    /// Zero entries stay zero; rows or columns that are entirely zero are left
+    /// alone.
+    pub fn project_log(
+        &self,
+        m: &mut [f32],
+        rows: usize,
+        cols: usize,
+        row_targets: &[f32],
+        col_targets: &[f32],
+    ) -> ConvergenceResult {
+        debug_assert_eq!(m.len(), rows * cols, "project_log: slice length must be rows*cols");
+        debug_assert_eq!(row_targets.len(), rows, "project_log: one target per row");
+        debug_assert_eq!(col_targets.len(), cols, "project_log: one target per column");
+        let (row_target, col_target) = (|i: usize| row_targets[i], |j: usize| col_targets[j]);
+        let flat = &mut Flat { m, cols };
+        scale_in_log_space(self, flat, (rows, cols), row_target, col_target, (&mut [], &mut []), |_, _| true)
+    }
+
+    /// Entropic-regularised optimal transport plan between distributions `a`
+    /// and `b` under `cost`.
+    ///
+    /// Builds the Gibbs kernel `K = exp(−cost / epsilon)` and Sinkhorn-scales
+    /// it so row `i` sums to `a[i]` and column `j` to `b[j]`. `a` and `b`
+    /// must be positive with equal totals. Smaller `epsilon` concentrates the
+    /// plan on the cheapest cells and approaches the hard (unregularised)
+    /// assignment, at the cost of slower convergence — raise
+    /// `max_iterations` accordingly. Larger `epsilon` spreads mass towards
+    /// the independent coupling `a·bᵀ / Σa`. Always iterates in log space, so
+    /// a tiny `epsilon` cannot underflow the kernel. A non-positive `epsilon`
+    /// is treated as `f32::EPSILON`.
+    pub fn transport<const N: usize>(
+        &self,
+        cost: &[[f32; N]; N],
+        a: &[f32; N],
+        b: &[f32; N],
+        epsilon: f32,
+    ) -> [[f32; N]; N] {
+        let epsilon = epsilon.max(f32::EPSILON);
+        let mut plan = [[0.0_f32; N]; N];
+        for (row, costs) in plan.iter_mut().zip(cost) {
+            for (x, &c) in row.iter_mut().zip(costs) {
+                *x = -c / epsilon;
             }
+        }
+        scale_log_matrix(self, &mut plan, (N, N), |i| a[i], |j| b[j], (&mut [], &mut []), |_, _| true);
+        plan
+    }
 
-            // Column normalisation
-            for j in 0..n {
-                let s: f32 = (0..n).map(|i| m[i * n + j]).sum();
-                if s > 1e-12 {
-                    let inv = 1.0 / s;
-                    for i in 0..n {
-                        m[i * n + j] *= inv;
-                    }
+    /// Sinkhorn-Knopp iteration shared by every projection.
+    ///
+    /// `scales` accumulates the applied row and column factors onto its
+    /// entries (which start at 1.0); pass empty slices to skip tracking.
+    fn scale_to_marginals<M: Cells>(
+        &self,
+        m: &mut M,
+        shape: (usize, usize),
+        row_target: impl Fn(usize) -> f32,
+        col_target: impl Fn(usize) -> f32,
+        scales: (&mut [f32], &mut [f32]),
+        on_iter: impl FnMut(usize, f32) -> bool,
+    ) -> ConvergenceResult {
+        if self.stabilized {
+            return scale_in_log_space(self, m, shape, row_target, col_target, scales, on_iter);
+        }
+        let limits = (self.tolerance, self.max_iterations);
+        scale_multiplicative(limits, m, shape, row_target, col_target, scales, on_iter)
+    }
+}
+
+// ─── f64 projector ──────────────────────────────────────────────────────────
+
+/// Double-precision Sinkhorn-Knopp projector.
+///
+/// Same multiplicative iteration as [`SinkhornKnopp::project`], run in `f64`
+/// for downstream analysis that needs residuals far below what `f32` can
+/// reach (≈1e-7 on a row sum of 1.0), e.g. matrices near a Birkhoff vertex.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SinkhornKnoppF64 {
+    /// Convergence tolerance (default: 1e-12). Stop when max|row_sum - 1.0| < tolerance.
+    pub tolerance: f64,
+    /// Maximum number of row+column normalisation iterations (default: 100).
+    pub max_iterations: u32,
+}
+
+impl Default for SinkhornKnoppF64 {
+    fn default() -> Self {
+        Self { tolerance: 1e-12, max_iterations: 100 }
+    }
+}
+
+impl SinkhornKnoppF64 {
+    /// Create a new projector with the given tolerance and iteration cap.
+    pub fn new(tolerance: f64, max_iterations: u32) -> Self {
+        Self { tolerance, max_iterations }
+    }
+
+    /// Project an N×N `f64` matrix in-place to the Birkhoff polytope.
+    ///
+    /// The returned `residual` is the `f64` residual rounded to `f32`.
+    pub fn project_f64<const N: usize>(&self, m: &mut [[f64; N]; N]) -> ConvergenceResult {
+        let limits = (self.tolerance, self.max_iterations);
+        scale_multiplicative(limits, m, (N, N), |_| 1.0, |_| 1.0, (&mut [], &mut []), |_, _| true)
+    }
+}
+
+// ─── Iteration kernels ──────────────────────────────────────────────────────
+
+/// Scalar the multiplicative iteration runs in: `f32` or `f64`.
+trait Float:
+    Copy
+    + PartialOrd
+    + core::ops::Sub<Output = Self>
+    + core::ops::Div<Output = Self>
+    + core::ops::MulAssign
+    + core::iter::Sum
+{
+    /// Additive identity.
+    const ZERO: Self;
+    /// Row or column sums at or below this are left unscaled.
+    const MIN_SUM: Self;
+    /// Absolute value.
+    fn abs(self) -> Self;
+    /// Rounded to `f32` for [`ConvergenceResult::residual`].
+    fn to_f32(self) -> f32;
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    const MIN_SUM: Self = 1e-12;
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    const MIN_SUM: Self = 1e-200;
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+}
+
+/// Multiplicative Sinkhorn-Knopp; `limits` is `(tolerance, max_iterations)`.
+fn scale_multiplicative<T: Float, M: Cells<T>>(
+    limits: (T, u32),
+    m: &mut M,
+    shape: (usize, usize),
+    row_target: impl Fn(usize) -> T,
+    col_target: impl Fn(usize) -> T,
+    scales: (&mut [T], &mut [T]),
+    mut on_iter: impl FnMut(usize, f32) -> bool,
+) -> ConvergenceResult {
+    let (rows, cols) = shape;
+    let (tolerance, max_iterations) = limits;
+    let (row_scale, col_scale) = scales;
+    for iter in 0..max_iterations {
+        // Row normalisation
+        for i in 0..rows {
+            let s: T = (0..cols).map(|j| *m.cell(i, j)).sum();
+            if s > T::MIN_SUM {
+                let scale = row_target(i) / s;
+                for j in 0..cols {
+                    *m.cell(i, j) *= scale;
+                }
+                if let Some(u) = row_scale.get_mut(i) {
+                    *u *= scale;
                 }
             }
+        }
 
-            // Check convergence: max |row_sum - 1.0|
-            let residual = (0..n)
-                .map(|i| {
-                    let s: f32 = m[i * n..(i + 1) * n].iter().sum();
-                    (s - 1.0_f32).abs()
-                })
-                .fold(0.0_f32, f32::max);
-
-            if residual < self.tolerance {
-                return ConvergenceResult {
-                    converged: true,
-                    iterations: iter + 1,
-                    residual,
-                };
+        // Column normalisation
+        for j in 0..cols {
+            let s: T = (0..rows).map(|i| *m.cell(i, j)).sum();
+            if s > T::MIN_SUM {
+                let scale = col_target(j) / s;
+                for i in 0..rows {
+                    *m.cell(i, j) *= scale;
+                }
+                if let Some(v) = col_scale.get_mut(j) {
+                    *v *= scale;
+                }
             }
         }
 
-        let residual = (0..n)
-            .map(|i| {
-                let s: f32 = m[i * n..(i + 1) * n].iter().sum();
-                (s - 1.0_f32).abs()
-            })
-            .fold(0.0_f32, f32::max);
+        // Check convergence: max |row_sum - target|
+        let residual = row_residual(m, rows, cols, &row_target);
+        let keep_going = on_iter(iter as usize + 1, residual.to_f32());
+        if residual < tolerance {
+            return ConvergenceResult { converged: true, iterations: iter + 1, residual: residual.to_f32() };
+        }
+        if !keep_going {
+            return ConvergenceResult { converged: false, iterations: iter + 1, residual: residual.to_f32() };
+        }
+    }
+
+    // Return final residual even if not converged
+    let residual = row_residual(m, rows, cols, &row_target).to_f32();
+    ConvergenceResult { converged: false, iterations: max_iterations, residual }
+}
 
-        ConvergenceResult {
-            converged: false,
-            iterations: self.max_iterations,
-            residual,
+/// Log-domain Sinkhorn-Knopp on a linear-domain matrix.
+fn scale_in_log_space<M: Cells>(
+    sk: &SinkhornKnopp,
+    m: &mut M,
+    shape: (usize, usize),
+    row_target: impl Fn(usize) -> f32,
+    col_target: impl Fn(usize) -> f32,
+    scales: (&mut [f32], &mut [f32]),
+    on_iter: impl FnMut(usize, f32) -> bool,
+) -> ConvergenceResult {
+    let (rows, cols) = shape;
+    for i in 0..rows {
+        for j in 0..cols {
+            let x = m.cell(i, j);
+            *x = ln_f32(*x);
         }
     }
+    scale_log_matrix(sk, m, shape, row_target, col_target, scales, on_iter)
+}
 
-    /// Project an N×N matrix (const-generic form) in-place to the Birkhoff polytope.
-    pub fn project<const N: usize>(&self, m: &mut [[f32; N]; N]) -> ConvergenceResult {
-        for iter in 0..self.max_iterations {
-            // Row normalisation
-            for row in m.iter_mut() {
-                let s: f32 = row.iter().sum();
-                if s > 1e-12 {
-                    let inv = 1.0 / s;
-                    for x in row.iter_mut() { *x *= inv; }
+/// Log-domain Sinkhorn-Knopp on a matrix already holding logarithms;
+/// converts `m` back to the linear domain on return. `scales` are
+/// linear-domain, as for [`SinkhornKnopp::scale_to_marginals`].
+fn scale_log_matrix<M: Cells>(
+    sk: &SinkhornKnopp,
+    m: &mut M,
+    shape: (usize, usize),
+    row_target: impl Fn(usize) -> f32,
+    col_target: impl Fn(usize) -> f32,
+    scales: (&mut [f32], &mut [f32]),
+    mut on_iter: impl FnMut(usize, f32) -> bool,
+) -> ConvergenceResult {
+    let (rows, cols) = shape;
+    let (row_scale, col_scale) = scales;
+    for x in row_scale.iter_mut().chain(col_scale.iter_mut()) {
+        *x = ln_f32(*x);
+    }
+
+    let mut result = ConvergenceResult { converged: false, iterations: sk.max_iterations, residual: 0.0 };
+    for iter in 0..sk.max_iterations {
+        // Row normalisation
+        for i in 0..rows {
+            let lse = log_sum_exp(cols, |j| *m.cell(i, j));
+            if lse.is_finite() {
+                let shift = ln_f32(row_target(i)) - lse;
+                for j in 0..cols {
+                    *m.cell(i, j) += shift;
+                }
+                if let Some(u) = row_scale.get_mut(i) {
+                    *u += shift;
                 }
             }
+        }
 
-            // Column normalisation
-            for j in 0..N {
-                let s: f32 = (0..N).map(|i| m[i][j]).sum();
-                if s > 1e-12 {
-                    let inv = 1.0 / s;
-                    for i in 0..N { m[i][j] *= inv; }
+        // Column normalisation
+        for j in 0..cols {
+            let lse = log_sum_exp(rows, |i| *m.cell(i, j));
+            if lse.is_finite() {
+                let shift = ln_f32(col_target(j)) - lse;
+                for i in 0..rows {
+                    *m.cell(i, j) += shift;
+                }
+                if let Some(v) = col_scale.get_mut(j) {
+                    *v += shift;
                 }
             }
+        }
 
-            // Check convergence: max |row_sum - 1.0|
-            let residual = m.iter().map(|row| {
-                let s: f32 = row.iter().sum();
-                (s - 1.0_f32).abs()
-            }).fold(0.0_f32, f32::max);
+        // Check convergence: max |row_sum - target|
+        let residual = (0..rows)
+            .map(|i| {
+                let s: f32 = (0..cols).map(|j| exp_f32(*m.cell(i, j))).sum();
+                (s - row_target(i)).abs()
+            })
+            .fold(0.0_f32, f32::max);
+        result.residual = residual;
+        let keep_going = on_iter(iter as usize + 1, residual);
+        if residual < sk.tolerance {
+            result = ConvergenceResult { converged: true, iterations: iter + 1, residual };
+            break;
+        }
+        if !keep_going {
+            result.iterations = iter + 1;
+            break;
+        }
+    }
 
-            if residual < self.tolerance {
-                return ConvergenceResult { converged: true, iterations: iter + 1, residual };
-            }
+    for i in 0..rows {
+        for j in 0..cols {
+            let x = m.cell(i, j);
+            *x = exp_f32(*x);
         }
+    }
+    for x in row_scale.iter_mut().chain(col_scale.iter_mut()) {
+        *x = exp_f32(*x);
+    }
+    result
+}
 
-        // Return final residual even if not converged
-        let residual = m.iter().map(|row| {
-            let s: f32 = row.iter().sum();
-            (s - 1.0_f32).abs()
-        }).fold(0.0_f32, f32::max);
+/// `ln Σ exp(x(k))` over `k < len`, shifted by the maximum so no term
+/// overflows; −∞ when every term is −∞.
+fn log_sum_exp(len: usize, mut x: impl FnMut(usize) -> f32) -> f32 {
+    let max = (0..len).map(&mut x).fold(f32::NEG_INFINITY, f32::max);
+    if max == f32::NEG_INFINITY {
+        return max;
+    }
+    max + ln_f32((0..len).map(|k| exp_f32(x(k) - max)).sum())
+}
 
-        ConvergenceResult { converged: false, iterations: self.max_iterations, residual }
+/// Natural logarithm for no_std; −∞ for `x ≤ 0` (so zero entries stay zero
+/// through the log-domain iteration).
+///
+/// Splits `x = m × 2^e` with `m ∈ [√½, √2)` and evaluates
+/// `ln m = 2·atanh((m − 1)/(m + 1))` by its odd series. Absolute error < 1e-6
+/// across the f32 range.
+fn ln_f32(x: f32) -> f32 {
+    if x <= 0.0 {
+        return f32::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    let (x, bias) = if x < f32::MIN_POSITIVE { (x * 8_388_608.0, -23) } else { (x, 0) };
+    let bits = x.to_bits();
+    let mut e = ((bits >> 23) & 0xff) as i32 - 127 + bias;
+    let mut m = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    if m > core::f32::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+    let z = (m - 1.0) / (m + 1.0);
+    let z2 = z * z;
+    let series = 1.0 + z2 * (1.0 / 3.0 + z2 * (1.0 / 5.0 + z2 * (1.0 / 7.0 + z2 / 9.0)));
+    2.0 * z * series + e as f32 * core::f32::consts::LN_2
+}
+
+/// `e^x` for no_std; 0.0 below the f32 range, +∞ above it.
+///
+/// Reduces `x = k·ln2 + r` with `|r| ≤ ½ln2` and evaluates a degree-7 Taylor
+/// polynomial for `e^r`. Relative error < 1e-7.
+fn exp_f32(x: f32) -> f32 {
+    if x < -104.0 {
+        return 0.0;
+    }
+    if x > 88.8 {
+        return f32::INFINITY;
+    }
+    // ln 2 split into a high part exact in f32 and a low correction.
+    const LN2_HI: f32 = 0.693_145_75;
+    const LN2_LO: f32 = 1.428_606_8e-6;
+    let k = (x * core::f32::consts::LOG2_E + if x < 0.0 { -0.5 } else { 0.5 }) as i32;
+    let r = (x - k as f32 * LN2_HI) - k as f32 * LN2_LO;
+    let p = 1.0
+        + r * (1.0
+            + r * (1.0 / 2.0
+                + r * (1.0 / 6.0
+                    + r * (1.0 / 24.0 + r * (1.0 / 120.0 + r * (1.0 / 720.0 + r / 5040.0))))));
+    // 2^k, in two steps when k is outside the normal exponent range.
+    if k < -126 {
+        p * pow2(k + 126) * pow2(-126)
+    } else if k > 127 {
+        p * 2.0 * pow2(k - 1)
+    } else {
+        p * pow2(k)
+    }
+}
+
+/// `2^k` for `k ∈ [−126, 127]`.
+fn pow2(k: i32) -> f32 {
+    f32::from_bits(((k + 127) as u32) << 23)
+}
+
+/// Maximum `|row_sum(i) − row_target(i)|`.
+fn row_residual<T: Float, M: Cells<T>>(m: &mut M, rows: usize, cols: usize, row_target: &impl Fn(usize) -> T) -> T {
+    (0..rows)
+        .map(|i| {
+            let s: T = (0..cols).map(|j| *m.cell(i, j)).sum();
+            (s - row_target(i)).abs()
+        })
+        .fold(T::ZERO, |worst, dev| if dev > worst { dev } else { worst })
+}
+
+/// Matrix storage the iteration runs over: nested arrays or a flat slice.
+trait Cells<T = f32> {
+    /// Entry at row `i`, column `j`.
+    fn cell(&mut self, i: usize, j: usize) -> &mut T;
+}
+
+impl<T, const N: usize> Cells<T> for [[T; N]; N] {
+    fn cell(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self[i][j]
+    }
+}
+
+/// Row-major flat slice with `cols` entries per row.
+struct Flat<'a> {
+    m: &'a mut [f32],
+    cols: usize,
+}
+
+impl Cells for Flat<'_> {
+    fn cell(&mut self, i: usize, j: usize) -> &mut f32 {
+        &mut self.m[i * self.cols + j]
     }
 }
 
@@ -189,7 +622,7 @@ mod tests {
             assert!((rs - 1.0).abs() < tol, "row {} sum = {}", i, rs);
         }
         for j in 0..N {
-            let cs: f32 = (0..N).map(|i| m[i][j]).sum();
+            let cs: f32 = m.iter().map(|row| row[j]).sum();
             assert!((cs - 1.0).abs() < tol, "col {} sum = {}", j, cs);
         }
     }
@@ -240,7 +673,7 @@ mod tests {
             assert!(rs <= 1.0 + 1e-5, "row {} sum {} exceeds 1.0 — unbounded weight", i, rs);
             // No single entry can exceed the row sum (trivially true for non-negative)
             for &v in row {
-                assert!(v >= 0.0 && v <= 1.0 + 1e-5, "entry {} out of [0,1] bounds", v);
+                assert!((0.0..=1.0 + 1e-5).contains(&v), "entry {} out of [0,1] bounds", v);
             }
         }
     }
@@ -276,6 +709,195 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_project_rect_matches_target_marginals() {
+        // Two clusters mixed against three contexts with unequal weights.
+        let mut m = [1.0_f32, 2.0, 3.0, 4.0, 1.0, 2.0];
+        let rows = [1.0_f32, 2.0];
+        let cols = [0.5_f32, 1.5, 1.0];
+        let r = SinkhornKnopp::new(1e-5, 200).project_rect(&mut m, 2, 3, &rows, &cols);
+        assert!(r.converged, "did not converge: {:?}", r);
+        for (i, &target) in rows.iter().enumerate() {
+            let rs: f32 = m[i * 3..(i + 1) * 3].iter().sum();
+            assert!((rs - target).abs() < 1e-4, "row {} sum = {}", i, rs);
+        }
+        for (j, &target) in cols.iter().enumerate() {
+            let cs = m[j] + m[3 + j];
+            assert!((cs - target).abs() < 1e-4, "col {} sum = {}", j, cs);
+        }
+        assert!(m.iter().all(|&v| v >= 0.0));
+    }
+
+    #[test]
+    fn test_project_rect_unit_targets_match_square_projection() {
+        let mut square = [[10.0_f32, 1.0, 5.0], [2.0, 8.0, 3.0], [4.0, 6.0, 7.0]];
+        let mut flat = [10.0_f32, 1.0, 5.0, 2.0, 8.0, 3.0, 4.0, 6.0, 7.0];
+        let a = sk().project(&mut square);
+        let b = sk().project_rect(&mut flat, 3, 3, &[1.0; 3], &[1.0; 3]);
+        assert_eq!(a, b);
+        for i in 0..3 {
+            assert_eq!(square[i], flat[i * 3..(i + 1) * 3]);
+        }
+    }
+
+    #[test]
+    fn test_project_rect_mismatched_totals_do_not_converge() {
+        let mut m = [1.0_f32; 4];
+        let r = sk().project_rect(&mut m, 2, 2, &[1.0, 1.0], &[2.0, 2.0]);
+        assert!(!r.converged);
+        assert!(r.residual > 0.5);
+    }
+
+    #[test]
+    fn test_log_helpers_accuracy() {
+        for &x in &[1e-30_f32, 1e-13, 1e-8, 0.5, 1.0, 2.0, 3.5, 1e6, 3e38] {
+            // Rounding ln(x) to f32 alone costs up to |ln x| × 6e-8 relative.
+            let back = exp_f32(ln_f32(x));
+            assert!((back - x).abs() <= x * 1e-5, "exp(ln({})) = {}", x, back);
+        }
+        assert!((ln_f32(core::f32::consts::E) - 1.0).abs() < 1e-6);
+        assert!((exp_f32(1.0) - core::f32::consts::E).abs() < 1e-6);
+        assert_eq!(ln_f32(0.0), f32::NEG_INFINITY);
+        assert_eq!(exp_f32(f32::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_stabilized_projection_handles_near_zero_rows() {
+        // Row 0 sums to 4e-20, far below the multiplicative path's 1e-12
+        // guard, so that path never rescales it directly and only creeps up
+        // through column scaling — it exhausts the iteration budget.
+        let input = [1e-20_f32, 2e-20, 1e-20, 1e-8, 1.0, 2.0, 3.0, 1.0, 1.0];
+        let mut fast = input;
+        let r = sk().project_flat(&mut fast, 3);
+        assert!(!r.converged);
+        assert!(r.residual > 0.5);
+
+        let stable = SinkhornKnopp { stabilized: true, ..sk() };
+        let mut m = input;
+        let r = stable.project_flat(&mut m, 3);
+        assert!(r.converged, "did not converge: {:?}", r);
+        assert!(m.iter().all(|v| v.is_finite() && *v >= 0.0));
+        let square = [[m[0], m[1], m[2]], [m[3], m[4], m[5]], [m[6], m[7], m[8]]];
+        assert_doubly_stochastic(&square, 1e-4);
+
+        // project_log is the same iteration regardless of the flag.
+        let mut direct = input;
+        let r2 = sk().project_log(&mut direct, 3, 3, &[1.0; 3], &[1.0; 3]);
+        assert_eq!(r, r2);
+        assert_eq!(m, direct);
+    }
+
+    #[test]
+    fn test_scaling_vectors_reconstruct_projection() {
+        let original = [[10.0_f32, 1.0, 5.0], [2.0, 8.0, 3.0], [4.0, 6.0, 7.0]];
+        for stabilized in [false, true] {
+            let projector = SinkhornKnopp { stabilized, ..sk() };
+            let mut m = original;
+            let (r, scaling) = projector.project_scaled(&mut m);
+            assert!(r.converged);
+            let mut plain = original;
+            assert_eq!(projector.project(&mut plain), r);
+
+            let mut rebuilt = original;
+            scaling.apply(&mut rebuilt);
+            for i in 0..3 {
+                for j in 0..3 {
+                    let expected = scaling.row_scale[i] * original[i][j] * scaling.col_scale[j];
+                    assert!((rebuilt[i][j] - expected).abs() < 1e-6);
+                    assert!((rebuilt[i][j] - m[i][j]).abs() < 1e-5, "[{},{}] {} vs {}", i, j, rebuilt[i][j], m[i][j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transport_concentrates_on_cheapest_cells() {
+        // The cheapest assignment is the permutation 0→1, 1→2, 2→0.
+        let cost = [[4.0_f32, 1.0, 3.0], [2.0, 5.0, 0.5], [0.0, 3.0, 2.0]];
+        let a = [0.2_f32, 0.3, 0.5];
+        let b = [0.5_f32, 0.2, 0.3];
+        let solver = SinkhornKnopp::new(1e-5, 500);
+
+        let sharp = solver.transport(&cost, &a, &b, 0.05);
+        for i in 0..3 {
+            let rs: f32 = sharp[i].iter().sum();
+            assert!((rs - a[i]).abs() < 1e-4, "row {} sum {}", i, rs);
+            let cs: f32 = sharp.iter().map(|row| row[i]).sum();
+            assert!((cs - b[i]).abs() < 1e-4, "col {} sum {}", i, cs);
+        }
+        let on_cheapest = sharp[0][1] + sharp[1][2] + sharp[2][0];
+        assert!(on_cheapest > 0.95, "only {} on the cheapest cells", on_cheapest);
+
+        // A large epsilon approaches the independent coupling a·bᵀ.
+        let blurry = solver.transport(&cost, &a, &b, 100.0);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((blurry[i][j] - a[i] * b[j]).abs() < 0.01);
+            }
+        }
+        let blurry_cheapest = blurry[0][1] + blurry[1][2] + blurry[2][0];
+        assert!(blurry_cheapest < on_cheapest);
+    }
+
+    #[test]
+    fn test_f64_projection_beats_f32_residual_floor() {
+        // Largest deviation of any row or column sum from 1.0, measured in f64.
+        fn marginal_error(m: &[[f64; 3]; 3]) -> f64 {
+            let rows = m.iter().map(|row| (row.iter().sum::<f64>() - 1.0).abs());
+            let cols = (0..3).map(|j| (m.iter().map(|row| row[j]).sum::<f64>() - 1.0).abs());
+            rows.chain(cols).fold(0.0, f64::max)
+        }
+        let input = [[10.0_f64, 1.0, 5.0], [2.0, 8.0, 3.0], [4.0, 6.0, 7.0]];
+
+        // f32 stops once its row sums round to 1.0, but the entries are only
+        // accurate to ~1e-7, so the true marginals plateau well above 1e-10.
+        let mut m32 = input.map(|row| row.map(|x| x as f32));
+        SinkhornKnopp::new(1e-10, 100).project(&mut m32);
+        let m32 = m32.map(|row| row.map(f64::from));
+        assert!(marginal_error(&m32) > 1e-9, "f32 error {}", marginal_error(&m32));
+
+        let mut m64 = input;
+        let r64 = SinkhornKnoppF64::default().project_f64(&mut m64);
+        assert!(r64.converged, "{:?}", r64);
+        assert!(r64.residual < 1e-10);
+        assert!(marginal_error(&m64) < 1e-10, "f64 error {}", marginal_error(&m64));
+        // Both precisions agree to f32 accuracy.
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((m64[i][j] - m32[i][j]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_project_with_traces_non_increasing_residuals() {
+        let input = [[10.0_f32, 1.0, 5.0], [2.0, 8.0, 3.0], [4.0, 6.0, 7.0]];
+        let mut trace = [f32::NAN; 20];
+        let mut seen = 0;
+        let mut m = input;
+        let r = sk().project_with(&mut m, |iteration, residual| {
+            assert_eq!(iteration, seen + 1);
+            trace[seen] = residual;
+            seen += 1;
+            true
+        });
+        assert!(r.converged);
+        assert_eq!(seen, r.iterations as usize);
+        assert_eq!(trace[seen - 1], r.residual);
+        for pair in trace[..seen].windows(2) {
+            assert!(pair[1] <= pair[0], "residual rose: {:?}", &trace[..seen]);
+        }
+        let mut plain = input;
+        assert_eq!(sk().project(&mut plain), r);
+        assert_eq!(plain, m);
+
+        // Returning false aborts after that iteration.
+        let mut m = input;
+        let r = sk().project_with(&mut m, |iteration, _| iteration < 2);
+        assert!(!r.converged);
+        assert_eq!(r.iterations, 2);
+    }
+
     #[test]
     fn test_2x2_simple_case() {
         let mut m = [[1.0_f32, 3.0], [2.0, 1.0]];
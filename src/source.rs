@@ -0,0 +1,204 @@
+//! Sensor source adapter — the hardware boundary.
+//!
+//! Separates *how the robot reads the world* from *how CCF processes it*.
+//! Every hardware integration implements [`SensorSource`] once; everything
+//! downstream (context keys, coherence field, phase classification) is then
+//! driven uniformly from that trait.
+//!
+//! # Implementing for real hardware
+//!
+//! ```rust,ignore
+//! use ccf_core::mbot::{MbotSensors, BrightnessBand /* … */};
+//! use ccf_core::source::SensorSource;
+//!
+//! struct CyberPi { /* driver handles */ }
+//!
+//! impl SensorSource<MbotSensors, 6> for CyberPi {
+//!     fn sample(&mut self) -> MbotSensors {
+//!         let lux = self.read_light();
+//!         MbotSensors {
+//!             brightness: if lux < 10 { BrightnessBand::Dark } else { BrightnessBand::Bright },
+//!             // … quantise the remaining sensors …
+//!         }
+//!     }
+//!     fn instant_coherence(&mut self) -> f32 { self.smoothness_of_last_second() }
+//!     fn tension(&mut self) -> f32 { self.accelerometer_jerk().clamp(0.0, 1.0) }
+//! }
+//! ```
+//!
+//! For tests and examples use [`MockSensorSource`], which replays a scripted
+//! sequence of frames deterministically.
+//!
+//! # Invariants
+//! - **I-DIST-001** — no_std compatible; no heap allocation required
+//! - **I-DIST-005** — zero unsafe code
+
+use crate::vocabulary::{ContextKey, SensorVocabulary};
+
+// ─── SensorSource ───────────────────────────────────────────────────────────
+
+/// A source of sensor readings for the CCF pipeline.
+///
+/// The trait is object-safe: it can be stored as `&mut dyn SensorSource<V, N>`
+/// (or boxed when `std` is available) so the hardware backend can be chosen at
+/// runtime.
+pub trait SensorSource<V: SensorVocabulary<N>, const N: usize> {
+    /// Read the sensors and quantise them into a vocabulary instance.
+    fn sample(&mut self) -> V;
+
+    /// Instantaneous coherence of the current reading in [0.0, 1.0].
+    ///
+    /// Fed as `instant` into the asymmetric gate (CCF-001).
+    fn instant_coherence(&mut self) -> f32;
+
+    /// Instantaneous tension of the current reading in [0.0, 1.0].
+    ///
+    /// Fed into [`SocialPhase::classify`](crate::phase::SocialPhase::classify).
+    fn tension(&mut self) -> f32;
+
+    /// Read the sensors and wrap the result in a [`ContextKey`].
+    fn sample_key(&mut self) -> ContextKey<V, N> {
+        ContextKey::new(self.sample())
+    }
+}
+
+// ─── MockSensorSource ───────────────────────────────────────────────────────
+
+/// One scripted reading replayed by [`MockSensorSource`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockFrame<V> {
+    /// Vocabulary returned by `sample()`.
+    pub vocabulary: V,
+    /// Value returned by `instant_coherence()`.
+    pub instant: f32,
+    /// Value returned by `tension()`.
+    pub tension: f32,
+}
+
+/// Deterministic sensor source that replays a scripted sequence of frames.
+///
+/// Each call to [`SensorSource::sample`] advances to the next frame; the
+/// `instant_coherence` and `tension` calls report the frame most recently
+/// sampled. The script loops when exhausted. Borrows the script, so no heap
+/// allocation is required.
+#[derive(Clone, Debug)]
+pub struct MockSensorSource<'a, V> {
+    frames: &'a [MockFrame<V>],
+    cursor: usize,
+    current: usize,
+}
+
+impl<'a, V> MockSensorSource<'a, V> {
+    /// Create a source replaying `frames` in order.
+    ///
+    /// # Panics
+    /// Panics if `frames` is empty.
+    pub fn new(frames: &'a [MockFrame<V>]) -> Self {
+        assert!(!frames.is_empty(), "MockSensorSource needs at least one frame");
+        Self { frames, cursor: 0, current: 0 }
+    }
+
+    /// Number of `sample()` calls made so far.
+    pub fn samples_taken(&self) -> usize {
+        self.cursor
+    }
+
+    /// Restart the script from the first frame.
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+        self.current = 0;
+    }
+}
+
+impl<'a, V: SensorVocabulary<N>, const N: usize> SensorSource<V, N> for MockSensorSource<'a, V> {
+    fn sample(&mut self) -> V {
+        self.current = self.cursor % self.frames.len();
+        self.cursor += 1;
+        self.frames[self.current].vocabulary.clone()
+    }
+
+    fn instant_coherence(&mut self) -> f32 {
+        self.frames[self.current].instant
+    }
+
+    fn tension(&mut self) -> f32 {
+        self.frames[self.current].tension
+    }
+}
+
+// ─── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mbot::{
+        BrightnessBand, MbotSensors, MotionContext, NoiseBand, Orientation,
+        PresenceSignature, TimePeriod,
+    };
+
+    fn sensors(brightness: BrightnessBand, noise: NoiseBand) -> MbotSensors {
+        MbotSensors {
+            brightness,
+            noise,
+            presence: PresenceSignature::Close,
+            motion: MotionContext::Static,
+            orientation: Orientation::Upright,
+            time_period: TimePeriod::Day,
+        }
+    }
+
+    fn script() -> [MockFrame<MbotSensors>; 2] {
+        [
+            MockFrame {
+                vocabulary: sensors(BrightnessBand::Bright, NoiseBand::Quiet),
+                instant: 0.8,
+                tension: 0.1,
+            },
+            MockFrame {
+                vocabulary: sensors(BrightnessBand::Dark, NoiseBand::Loud),
+                instant: 0.2,
+                tension: 0.7,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_mock_source_replays_in_order_and_loops() {
+        let frames = script();
+        let mut src = MockSensorSource::new(&frames);
+        for round in 0..3 {
+            for frame in &frames {
+                let v: MbotSensors = src.sample();
+                assert_eq!(v, frame.vocabulary, "round {}", round);
+                assert_eq!(SensorSource::<MbotSensors, 6>::instant_coherence(&mut src), frame.instant);
+                assert_eq!(SensorSource::<MbotSensors, 6>::tension(&mut src), frame.tension);
+            }
+        }
+        assert_eq!(src.samples_taken(), 6);
+    }
+
+    #[test]
+    fn test_mock_source_is_deterministic() {
+        let frames = script();
+        let mut a = MockSensorSource::new(&frames);
+        let mut b = MockSensorSource::new(&frames);
+        for _ in 0..5 {
+            let ka: ContextKey<MbotSensors, 6> = a.sample_key();
+            let kb: ContextKey<MbotSensors, 6> = b.sample_key();
+            assert_eq!(ka.context_hash_u32(), kb.context_hash_u32());
+        }
+        a.reset();
+        let first: MbotSensors = a.sample();
+        assert_eq!(first, frames[0].vocabulary);
+    }
+
+    #[test]
+    fn test_sensor_source_is_object_safe() {
+        let frames = script();
+        let mut mock = MockSensorSource::new(&frames);
+        let src: &mut dyn SensorSource<MbotSensors, 6> = &mut mock;
+        let key = src.sample_key();
+        assert_eq!(key.vocabulary, frames[0].vocabulary);
+        assert_eq!(src.instant_coherence(), 0.8);
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * Notice of Provisional Patent Filing:
+ * The methods and algorithms implemented in this file are the subject of a
+ * United States Provisional Patent Application (63/988,438)
+ * filed on February 23, 2026.
+ *
+ * This source code is licensed under the Business Source License 1.1.
+ */
+
+//! Fixed-capacity coherence field for bare-metal targets.
+//!
+//! [`StaticCoherenceField`] is a drop-in for the core of
+//! [`CoherenceField`](crate::accumulator::CoherenceField): the same
+//! interaction and CCF-001 gate API, backed by a `heapless::FnvIndexMap` sized
+//! at compile time instead of a `hashbrown` map. The field itself never
+//! touches the allocator, so it can live in a `static` or on the stack of a
+//! microcontroller with no global allocator configured.
+//!
+//! # Capacity trade-off
+//!
+//! `C` is the hard upper bound on tracked contexts and must be a power of two
+//! (a `heapless` requirement). The whole map is reserved up front, so memory
+//! cost is paid for `C` entries whether or not they are used — roughly
+//! `C × (size_of::<ContextKey<V, N>>() + size_of::<CoherenceAccumulator>())`
+//! plus index overhead. When a new context arrives at capacity, the
+//! least-recently-interacted context is evicted, exactly as
+//! `CoherenceField` does at [`MAX_CONTEXTS`](crate::defaults::MAX_CONTEXTS).
+//! Pick `C` small enough for the RAM budget but large enough that familiar
+//! contexts are not churned out by passing novelty — a context that is evicted
+//! loses its earned trust.
+//!
+//! # Not supported
+//!
+//! Interaction histories, the similarity growth boost and degraded-mode
+//! fallback coherence all need heap storage or whole-field scans and are left
+//! to `CoherenceField`; the corresponding [`FieldConfig`] options are ignored.
+//! Feature flag: `static-field`.
+
+use heapless::FnvIndexMap;
+
+use crate::accumulator::{slew_limit, CoherenceAccumulator, FieldConfig, FloorParams};
+use crate::defaults::BASELINE_PER_CURIOSITY;
+use crate::phase::Personality;
+use crate::vocabulary::{ContextKey, SensorVocabulary};
+
+// ─── StaticCoherenceField ─────────────────────────────────────────────────────
+
+/// A coherence field holding at most `C` contexts in fixed, inline storage.
+///
+/// `C` must be a power of two greater than one. See the [module docs](self)
+/// for the capacity trade-off.
+pub struct StaticCoherenceField<V: SensorVocabulary<N>, const N: usize, const C: usize> {
+    /// Context-keyed accumulators.
+    accumulators: FnvIndexMap<ContextKey<V, N>, CoherenceAccumulator, C>,
+    /// Personality baseline for new contexts (0.15 × curiosity_drive).
+    personality_baseline: f32,
+    /// Field configuration (cold-start curve, slew limit, gate, dynamics).
+    config: FieldConfig,
+}
+
+impl<V: SensorVocabulary<N>, const N: usize, const C: usize> Default for StaticCoherenceField<V, N, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: SensorVocabulary<N>, const N: usize, const C: usize> StaticCoherenceField<V, N, C> {
+    /// Construct a fresh field with no accumulated coherence.
+    pub fn new() -> Self {
+        Self::with_config(FieldConfig::default())
+    }
+
+    /// Construct a fresh field with the given configuration.
+    pub fn with_config(config: FieldConfig) -> Self {
+        Self { accumulators: FnvIndexMap::new(), personality_baseline: 0.0, config }
+    }
+
+    /// Construct a fresh field whose cold-start baseline is seeded from
+    /// `personality` (0.15 × curiosity_drive).
+    pub fn with_personality(personality: &Personality) -> Self {
+        let mut field = Self::new();
+        field.update_personality(personality);
+        field
+    }
+
+    /// The field configuration.
+    pub fn config(&self) -> &FieldConfig {
+        &self.config
+    }
+
+    /// Personality baseline used for cold starts (0.15 × curiosity_drive).
+    pub fn personality_baseline(&self) -> f32 {
+        self.personality_baseline
+    }
+
+    /// Set the personality baseline for cold-start contexts (0.15 × curiosity_drive).
+    pub fn set_personality_baseline(&mut self, baseline: f32) {
+        self.personality_baseline = baseline.clamp(0.0, 1.0);
+    }
+
+    /// Adopt a new personality for *future* cold starts; existing contexts are untouched.
+    pub fn update_personality(&mut self, personality: &Personality) {
+        self.set_personality_baseline(BASELINE_PER_CURIOSITY * personality.curiosity_drive.clamp(0.0, 1.0));
+    }
+
+    // ── CCF-001: asymmetric min-gate ───────────────────────────────────────
+
+    /// Compute effective coherence using the asymmetric gate (CCF-001).
+    ///
+    /// Identical to [`CoherenceField::effective_coherence`](crate::accumulator::CoherenceField::effective_coherence).
+    pub fn effective_coherence(&self, instant: f32, key: &ContextKey<V, N>) -> f32 {
+        let ctx = self.context_coherence(key);
+        let gate = &self.config.gate;
+        if ctx < gate.familiarity_threshold {
+            if instant < ctx { instant } else { ctx }
+        } else {
+            (gate.instant_weight * instant + (1.0 - gate.instant_weight) * ctx).clamp(0.0, 1.0)
+        }
+    }
+
+    // ── Interaction API ────────────────────────────────────────────────────
+
+    /// Record a positive interaction for a context, modulated by `personality`.
+    ///
+    /// Creates the accumulator at the personality baseline if the context is
+    /// unseen, evicting the least-recently-seen context when full.
+    pub fn positive_interaction(
+        &mut self,
+        key: &ContextKey<V, N>,
+        personality: &Personality,
+        tick: u64,
+        alone: bool,
+    ) {
+        let (gain, threshold) = (personality.curiosity_growth_factor(), self.config.gate.familiarity_threshold);
+        self.apply_interaction(key, tick, |acc| acc.grow(personality.recovery_speed, tick, alone, gain, threshold));
+    }
+
+    /// Record a negative interaction for a context, modulated by `personality`.
+    ///
+    /// The drop is bounded by [`FieldConfig::min_negative_delta`] /
+    /// [`FieldConfig::max_negative_delta`] and never falls below the earned floor.
+    pub fn negative_interaction(&mut self, key: &ContextKey<V, N>, personality: &Personality, tick: u64) {
+        let (min, max) = (self.config.min_negative_delta, self.config.max_negative_delta);
+        self.apply_interaction(key, tick, |acc| {
+            acc.negative_interaction_bounded(personality.startle_sensitivity, tick, 1.0, min, max);
+        });
+    }
+
+    // ── Queries ────────────────────────────────────────────────────────────
+
+    /// Raw coherence for a context (0.0 if unseen).
+    pub fn context_coherence(&self, key: &ContextKey<V, N>) -> f32 {
+        self.accumulators.get(key).map_or(0.0, |a| a.value)
+    }
+
+    /// Number of positive interactions recorded for a context (0 if unseen).
+    pub fn context_interaction_count(&self, key: &ContextKey<V, N>) -> u32 {
+        self.accumulators.get(key).map_or(0, |a| a.interaction_count)
+    }
+
+    /// Number of tracked contexts.
+    pub fn context_count(&self) -> usize {
+        self.accumulators.len()
+    }
+
+    /// Maximum number of contexts this field can hold (`C`).
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    /// Iterate over all (context key, accumulator) pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&ContextKey<V, N>, &CoherenceAccumulator)> {
+        self.accumulators.iter()
+    }
+
+    // ── Decay ──────────────────────────────────────────────────────────────
+
+    /// Decay all accumulators by `elapsed_ticks`, honouring the configured dead zone.
+    pub fn decay_all(&mut self, elapsed_ticks: u64) {
+        let (dead_zone, snap) = (self.config.decay_dead_zone, self.config.snap_to_floor);
+        for (_, acc) in self.accumulators.iter_mut() {
+            acc.decay_with_dead_zone(elapsed_ticks, dead_zone, snap);
+        }
+    }
+
+    // ── Internals ──────────────────────────────────────────────────────────
+
+    /// Get or create the accumulator for `key`, initialising it on the cold-start curve.
+    ///
+    /// A new accumulator is stamped with `tick` as its last interaction.
+    /// Evicts the least-recently-seen entry when the field is at capacity `C`.
+    pub fn get_or_create(&mut self, key: &ContextKey<V, N>, tick: u64) -> &mut CoherenceAccumulator {
+        if !self.accumulators.contains_key(key) {
+            if self.accumulators.len() >= C {
+                self.evict_oldest();
+            }
+            let threshold = self.config.gate.familiarity_threshold;
+            let mut acc = CoherenceAccumulator::cold_at(self.config.cold_start.baseline_below(self.curiosity(), threshold));
+            acc.floor_params = FloorParams::new(self.config.floor_params.asymptote, self.config.floor_params.half_count);
+            acc.dynamics = self.config.dynamics;
+            acc.last_interaction_tick = tick;
+            // Cannot fail: an entry was evicted above if the map was full.
+            let _ = self.accumulators.insert(key.clone(), acc);
+        }
+        self.accumulators.get_mut(key).expect("context inserted above")
+    }
+
+    /// Run one interaction on `key`'s accumulator and apply the slew limit.
+    fn apply_interaction(&mut self, key: &ContextKey<V, N>, tick: u64, f: impl FnOnce(&mut CoherenceAccumulator)) {
+        let (max_delta, threshold) = (self.config.max_delta_per_tick, self.config.gate.familiarity_threshold);
+        let acc = self.get_or_create(key, tick);
+        let before = (acc.value, acc.peak_value);
+        let first_familiar_before = acc.first_familiar_tick;
+        f(acc);
+        slew_limit(acc, before, max_delta);
+        // A crossing undone by the slew limit is not a crossing.
+        if acc.value < threshold {
+            acc.first_familiar_tick = first_familiar_before;
+        }
+    }
+
+    /// Curiosity recovered from the personality baseline (0.15 × curiosity_drive).
+    fn curiosity(&self) -> f32 {
+        if self.personality_baseline > 0.0 {
+            (self.personality_baseline / BASELINE_PER_CURIOSITY).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some(oldest_key) = self
+            .accumulators
+            .iter()
+            .min_by_key(|(_, acc)| acc.last_interaction_tick)
+            .map(|(k, _)| k.clone())
+        {
+            self.accumulators.remove(&oldest_key);
+        }
+    }
+}
@@ -51,6 +51,28 @@ fn sqrt_nr(x: f32) -> f32 {
     s
 }
 
+/// Quantise a feature in [0.0, 1.0] to u16 — shared by hashing and the integer similarity.
+fn quantise_u16(f: f32) -> u16 {
+    (f.clamp(0.0, 1.0) * 65535.0) as u16
+}
+
+/// Integer square root (floor) by fixed 64-step digit recurrence.
+///
+/// Branch-free inner loop, so timing does not depend on the input.
+fn isqrt_u128(n: u128) -> u64 {
+    let mut rem = n;
+    let mut root: u128 = 0;
+    let mut bit: u128 = 1 << 126;
+    while bit > 0 {
+        let trial = root + bit;
+        let mask = ((rem >= trial) as u128).wrapping_neg();
+        rem -= trial & mask;
+        root = (root >> 1) + (bit & mask);
+        bit >>= 2;
+    }
+    root as u64
+}
+
 /// Platform-independent sensor vocabulary trait.
 ///
 /// Implementors define the discrete sensory space the robot operates in.
@@ -72,6 +94,77 @@ pub trait SensorVocabulary<const N: usize>: Eq + Hash + Clone + core::fmt::Debug
     /// Each element should be in [0.0, 1.0] for cosine similarity to be meaningful.
     /// The order of dimensions must be consistent across calls.
     fn to_feature_vec(&self) -> [f32; N];
+
+    /// Rebuild a vocabulary instance from a feature vector, e.g. one read back
+    /// from a snapshot or passed in over FFI.
+    ///
+    /// Optional: the default returns `None`. Implementations should snap each
+    /// component to the nearest representable value, so that
+    /// `from_feature_vec(&v.to_feature_vec()) == Some(v)`.
+    fn from_feature_vec(_v: &[f32; N]) -> Option<Self> {
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Similarity metrics
+// ---------------------------------------------------------------------------
+
+/// Similarity measure between two feature vectors, for [`ContextKey::similarity`]
+/// and the `M` parameter of [`crate::boundary::MinCutBoundary`].
+///
+/// Boundary edge weights and the similarity-based helpers of the coherence
+/// field expect values in [0.0, 1.0]; both provided metrics guarantee that for
+/// features in [0.0, 1.0], as [`SensorVocabulary::to_feature_vec`] requires.
+pub trait ContextMetric {
+    /// Similarity of `a` and `b`: 1.0 for identical vectors, lower for less alike.
+    fn similarity<const N: usize>(a: &[f32; N], b: &[f32; N]) -> f32;
+}
+
+/// Cosine similarity — the default, used by [`ContextKey::cosine_similarity`].
+///
+/// In [0.0, 1.0] for non-negative features (negative cosines clamp to 0.0);
+/// 0.0 if either vector is (near) zero. Scale-invariant, so it compares the
+/// *shape* of two contexts rather than their magnitude.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CosineMetric;
+
+impl ContextMetric for CosineMetric {
+    fn similarity<const N: usize>(a: &[f32; N], b: &[f32; N]) -> f32 {
+        let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+        let sq_a: f32 = a.iter().map(|x| x * x).sum();
+        let sq_b: f32 = b.iter().map(|x| x * x).sum();
+        let norm_a: f32 = sqrt_nr(sq_a);
+        let norm_b: f32 = sqrt_nr(sq_b);
+
+        let epsilon: f32 = 1e-9;
+        let tiny_a: bool = norm_a < epsilon;
+        let tiny_b: bool = norm_b < epsilon;
+        if tiny_a || tiny_b {
+            0.0
+        } else {
+            let raw: f32 = dot / (norm_a * norm_b);
+            raw.clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// One minus the mean absolute difference: `1 − Σ|aᵢ − bᵢ| / N`.
+///
+/// In [0.0, 1.0] for features in [0.0, 1.0] (clamped otherwise). Unlike
+/// cosine it tells an all-zero context apart from others, and each dimension
+/// counts the same however the rest of the vector looks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct L1Metric;
+
+impl ContextMetric for L1Metric {
+    fn similarity<const N: usize>(a: &[f32; N], b: &[f32; N]) -> f32 {
+        if N == 0 {
+            return 1.0;
+        }
+        let l1: f32 = a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum();
+        (1.0 - l1 / N as f32).clamp(0.0, 1.0)
+    }
 }
 
 /// Composite context key — generic over sensor vocabulary.
@@ -94,6 +187,13 @@ impl<V: SensorVocabulary<N>, const N: usize> ContextKey<V, N> {
         Self { vocabulary }
     }
 
+    /// Rebuild a context key from a feature vector via
+    /// [`SensorVocabulary::from_feature_vec`]; `None` if the vocabulary does
+    /// not support the inverse mapping or rejects `v`.
+    pub fn from_feature_vec(v: &[f32; N]) -> Option<Self> {
+        V::from_feature_vec(v).map(Self::new)
+    }
+
     /// Deterministic FNV-1a hash of the feature vector.
     ///
     /// Used to key context entries in fixed-size arrays (no_std compatible).
@@ -103,36 +203,96 @@ impl<V: SensorVocabulary<N>, const N: usize> ContextKey<V, N> {
         let mut h: u32 = 2_166_136_261;
         for &f in vec.iter() {
             // Quantise to u16 for stable hashing of float feature vectors.
-            let bits: u16 = (f.clamp(0.0, 1.0) * 65535.0) as u16;
+            let bits: u16 = quantise_u16(f);
             h ^= bits as u32;
             h = h.wrapping_mul(16_777_619);
         }
         h
     }
 
+    /// 64-bit FNV-1a hash over the same quantised features as
+    /// [`Self::context_hash_u32`].
+    ///
+    /// Use where distinct contexts must never silently share an identity at
+    /// production cardinality; the 32-bit hash stays the boundary graph's node ID.
+    pub fn context_hash_u64(&self) -> u64 {
+        let vec = self.vocabulary.to_feature_vec();
+        let mut h: u64 = 14_695_981_039_346_656_037;
+        for &f in vec.iter() {
+            h ^= quantise_u16(f) as u64;
+            h = h.wrapping_mul(1_099_511_628_211);
+        }
+        h
+    }
+
     /// Cosine similarity between two context keys via their feature vectors.
     ///
     /// Returns a value in [0.0, 1.0] (assumes non-negative feature vectors).
     /// Used as the raw edge weight in the World Shape graph (Graph A).
+    /// Equivalent to [`Self::similarity`] with [`CosineMetric`], and to
+    /// [`Self::weighted_cosine_similarity`] with all-ones weights.
     pub fn cosine_similarity(&self, other: &Self) -> f32 {
+        self.similarity::<CosineMetric>(other)
+    }
+
+    /// Similarity between two context keys under metric `M`.
+    pub fn similarity<M: ContextMetric>(&self, other: &Self) -> f32 {
+        M::similarity(&self.vocabulary.to_feature_vec(), &other.vocabulary.to_feature_vec())
+    }
+
+    /// Cosine similarity with each feature component scaled by `weights[i]`
+    /// before the dot product and norms.
+    ///
+    /// Lets salient dimensions dominate "does this room feel like that room":
+    /// a zero weight ignores that dimension entirely. Returns a value in
+    /// [0.0, 1.0], and 0.0 if either weighted vector is (near) zero.
+    pub fn weighted_cosine_similarity(&self, other: &Self, weights: &[f32; N]) -> f32 {
+        let mut a = self.vocabulary.to_feature_vec();
+        let mut b = other.vocabulary.to_feature_vec();
+        for i in 0..N {
+            a[i] *= weights[i];
+            b[i] *= weights[i];
+        }
+        CosineMetric::similarity(&a, &b)
+    }
+
+    /// Integer approximation of [`Self::cosine_similarity`] in fixed point
+    /// (`65535` ≡ 1.0).
+    ///
+    /// Works on the same u16 quantisation as [`Self::context_hash_u32`] with
+    /// integer-only arithmetic and a fixed-iteration integer square root, so it
+    /// needs no FPU and runs in predictable time. Not bit-identical to the f32
+    /// version, but rank-preserving up to quantisation, so min-cut edge
+    /// structure is unchanged.
+    pub fn approx_similarity_u16(&self, other: &Self) -> u16 {
         let a = self.vocabulary.to_feature_vec();
         let b = other.vocabulary.to_feature_vec();
 
-        let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
-        let sq_a: f32 = a.iter().map(|x| x * x).sum();
-        let sq_b: f32 = b.iter().map(|x| x * x).sum();
-        let norm_a: f32 = sqrt_nr(sq_a);
-        let norm_b: f32 = sqrt_nr(sq_b);
+        let mut dot: u64 = 0;
+        let mut sq_a: u64 = 0;
+        let mut sq_b: u64 = 0;
+        for (x, y) in a.iter().zip(b.iter()) {
+            let (x, y) = (quantise_u16(*x) as u64, quantise_u16(*y) as u64);
+            dot += x * y;
+            sq_a += x * x;
+            sq_b += y * y;
+        }
 
-        let epsilon: f32 = 1e-9;
-        let tiny_a: bool = norm_a < epsilon;
-        let tiny_b: bool = norm_b < epsilon;
-        if tiny_a || tiny_b {
-            0.0
-        } else {
-            let raw: f32 = dot / (norm_a * norm_b);
-            raw.clamp(0.0, 1.0)
+        let norm = isqrt_u128(sq_a as u128 * sq_b as u128) as u128;
+        if norm == 0 {
+            return 0;
         }
+        (dot as u128 * 65535 / norm).min(65535) as u16
+    }
+
+    /// Euclidean distance between two context keys' feature vectors.
+    ///
+    /// For normalised features in [0.0, 1.0] the result lies in [0.0, √N].
+    pub fn euclidean_distance(&self, other: &Self) -> f32 {
+        let a = self.vocabulary.to_feature_vec();
+        let b = other.vocabulary.to_feature_vec();
+        let sq: f32 = a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum();
+        sqrt_nr(sq)
     }
 }
 
@@ -200,6 +360,111 @@ mod tests {
         assert!(sim < 0.5_f32, "sim={}", sim);
     }
 
+    #[test]
+    fn test_weighted_cosine_ignores_zero_weight_dimension() {
+        use crate::mbot::{
+            BrightnessBand, MbotSensors, MotionContext, NoiseBand, Orientation,
+            PresenceSignature, TimePeriod,
+        };
+        let at = |time_period| {
+            ContextKey::new(MbotSensors {
+                brightness: BrightnessBand::Bright,
+                noise: NoiseBand::Quiet,
+                presence: PresenceSignature::Close,
+                motion: MotionContext::Static,
+                orientation: Orientation::Upright,
+                time_period,
+            })
+        };
+        let (day, night) = (at(TimePeriod::Day), at(TimePeriod::Night));
+        assert!(day.cosine_similarity(&night) < 0.9);
+        assert_eq!(day.weighted_cosine_similarity(&night, &[1.0; 6]), day.cosine_similarity(&night));
+
+        let no_time = [1.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+        assert!((day.weighted_cosine_similarity(&night, &no_time) - 1.0).abs() < 1e-5);
+    }
+
+    /// Every one of the 486 mBot2 sensor combinations.
+    fn all_mbot_sensors() -> [crate::mbot::MbotSensors; 486] {
+        use crate::mbot::{
+            BrightnessBand, MbotSensors, MotionContext, NoiseBand, Orientation,
+            PresenceSignature, TimePeriod,
+        };
+        core::array::from_fn(|i| {
+            let three = |n: usize| n % 3;
+            MbotSensors {
+                brightness: [BrightnessBand::Dark, BrightnessBand::Dim, BrightnessBand::Bright][three(i)],
+                noise: [NoiseBand::Quiet, NoiseBand::Moderate, NoiseBand::Loud][three(i / 3)],
+                presence: [PresenceSignature::Absent, PresenceSignature::Far, PresenceSignature::Close][three(i / 9)],
+                motion: [MotionContext::Static, MotionContext::Slow, MotionContext::Fast][three(i / 27)],
+                time_period: [TimePeriod::Day, TimePeriod::Evening, TimePeriod::Night][three(i / 81)],
+                orientation: [Orientation::Upright, Orientation::Tilted][i / 243],
+            }
+        })
+    }
+
+    #[test]
+    fn test_context_hash_u64_no_collisions_over_mbot_space() {
+        let mut hashes = all_mbot_sensors().map(|s| ContextKey::new(s).context_hash_u64());
+        hashes.sort_unstable();
+        assert!(hashes.windows(2).all(|w| w[0] != w[1]), "u64 context hash collision");
+    }
+
+    #[test]
+    fn test_mbot_from_feature_vec_round_trips() {
+        use crate::mbot::MbotSensors;
+        for s in all_mbot_sensors() {
+            let key = ContextKey::new(s);
+            let v = key.vocabulary.to_feature_vec();
+            assert_eq!(ContextKey::from_feature_vec(&v), Some(key.clone()));
+            // Noisy readings snap to the nearest band.
+            let noisy = v.map(|x| if x > 0.5 { x - 0.2 } else { x + 0.2 });
+            assert_eq!(MbotSensors::from_feature_vec(&noisy), Some(key.vocabulary));
+        }
+        let mut bad = [0.0; 6];
+        bad[2] = f32::NAN;
+        assert_eq!(MbotSensors::from_feature_vec(&bad), None);
+        // Vocabularies without an inverse mapping opt out.
+        assert_eq!(ContextKey::<TwoSensor, 2>::from_feature_vec(&[1.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_metrics_on_mbot_keys() {
+        use crate::mbot::MbotSensors;
+        let all = all_mbot_sensors();
+        let (first, last) = (ContextKey::new(all[0].clone()), ContextKey::new(all[485].clone()));
+        let near = ContextKey::new(MbotSensors { time_period: crate::mbot::TimePeriod::Evening, ..last.vocabulary.clone() });
+
+        // The default metric is cosine.
+        assert_eq!(last.similarity::<CosineMetric>(&near), last.cosine_similarity(&near));
+        // All-zero context: cosine cannot compare it, L1 can.
+        assert_eq!(first.cosine_similarity(&first), 0.0);
+        assert_eq!(first.similarity::<L1Metric>(&first), 1.0);
+        assert_eq!(first.similarity::<L1Metric>(&last), 0.0);
+        // One dimension off by half a band step.
+        assert!((last.similarity::<L1Metric>(&near) - (1.0 - 0.5 / 6.0)).abs() < 1e-6);
+
+        for a in all.iter().step_by(7) {
+            for b in all.iter().step_by(11) {
+                let (a, b) = (ContextKey::new(a.clone()), ContextKey::new(b.clone()));
+                for sim in [a.similarity::<CosineMetric>(&b), a.similarity::<L1Metric>(&b)] {
+                    assert!((0.0..=1.0).contains(&sim), "{:?} {:?}: {}", a, b, sim);
+                }
+                assert_eq!(a.similarity::<L1Metric>(&b), b.similarity::<L1Metric>(&a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_euclidean_distance() {
+        let k1 = bright_quiet();
+        let k2 = dark_loud();
+        assert!(k1.euclidean_distance(&k1) < 1e-6);
+        // (1,0) vs (0,1) → √2
+        assert!((k1.euclidean_distance(&k2) - core::f32::consts::SQRT_2).abs() < 1e-5);
+        assert_eq!(k1.euclidean_distance(&k2), k2.euclidean_distance(&k1));
+    }
+
     #[test]
     fn test_custom_vocabulary_works_without_modifying_ccf_core() {
         // Acceptance criterion: custom vocabulary compiles without modifying ccf-core
@@ -216,6 +481,39 @@ mod tests {
         assert!((sim - 1.0_f32).abs() < 1e-5, "self-similarity={}", sim);
     }
 
+    #[test]
+    fn test_approx_similarity_u16_preserves_ranking() {
+        let keys: [ContextKey<TwoSensor, 2>; 9] = core::array::from_fn(|i| {
+            ContextKey::new(TwoSensor { light: (i / 3) as u8, noise: (i % 3) as u8 })
+        });
+        let mut pairs = [(0.0_f32, 0u16); 81];
+        for (i, a) in keys.iter().enumerate() {
+            for (j, b) in keys.iter().enumerate() {
+                let f = a.cosine_similarity(b);
+                let q = a.approx_similarity_u16(b);
+                assert!((q as f32 / 65535.0 - f).abs() < 1e-3, "{:?} {:?}: {} vs {}", a, b, q, f);
+                pairs[i * 9 + j] = (f, q);
+            }
+        }
+        for &(fa, qa) in &pairs {
+            for &(fb, qb) in &pairs {
+                if fa > fb + 1e-4 {
+                    assert!(qa > qb, "rank flip: f {} > {} but q {} <= {}", fa, fb, qa, qb);
+                }
+            }
+        }
+        assert_eq!(keys[4].approx_similarity_u16(&keys[4]), 65535);
+        assert_eq!(keys[0].approx_similarity_u16(&keys[4]), 0);
+    }
+
+    #[test]
+    fn test_isqrt_u128() {
+        for &n in &[0u128, 1, 2, 3, 4, 15, 16, 1 << 40, (1u128 << 64) - 1, u64::MAX as u128 * 3] {
+            let r = isqrt_u128(n) as u128;
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({}) = {}", n, r);
+        }
+    }
+
     #[test]
     fn test_sqrt_nr_accuracy() {
         // Verify our no_std sqrt helper is accurate enough for cosine similarity
@@ -223,7 +521,7 @@ mod tests {
             (0.0, 0.0),
             (1.0, 1.0),
             (0.25, 0.5),
-            (0.5, 0.7071068),
+            (0.5, core::f32::consts::FRAC_1_SQRT_2),
             (4.0, 2.0),
         ];
         for &(input, expected) in cases {
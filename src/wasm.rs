@@ -0,0 +1,184 @@
+//! JavaScript bindings via wasm-bindgen.
+//!
+//! Mirrors the Python FFI surface for browser-based simulators: fixed
+//! 6-dimensional feature vectors passed as `Float32Array`. For custom sensor
+//! dimensions, use the Rust API directly.
+//!
+//! # Building the WASM package
+//!
+//! ```bash
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/ccf_core.wasm
+//! ```
+//!
+//! # Usage
+//!
+//! ```js
+//! import init, { CoherenceField, Personality } from "./pkg/ccf_core.js";
+//! await init();
+//!
+//! const personality = new Personality(0.6, 0.5, 0.5);
+//! const field = new CoherenceField();
+//! // brightness, noise, presence, motion, orientation, time — each in [0, 1]
+//! const features = new Float32Array([0.8, 0.0, 1.0, 0.0, 1.0, 0.5]);
+//! field.positiveInteraction(features, personality, 0n, false); // ticks are BigInt
+//! const coherence = field.effectiveCoherence(0.9, features);
+//! field.decayAll(100n);
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::accumulator::CoherenceField;
+use crate::phase::Personality;
+use crate::vocabulary::{ContextKey, SensorVocabulary};
+
+/// Dimensionality of the JS-facing feature vector (the mBot2 vocabulary).
+const JS_DIM: usize = 6;
+
+// ── Internal vocabulary wrapper ──────────────────────────────────────────────
+
+/// Internal vocabulary type for the JS API.
+/// Stores features quantised to u16 for stable hashing.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct JsVocab([u16; JS_DIM]);
+
+impl SensorVocabulary<JS_DIM> for JsVocab {
+    fn to_feature_vec(&self) -> [f32; JS_DIM] {
+        let mut out = [0.0f32; JS_DIM];
+        for (i, &q) in self.0.iter().enumerate() {
+            out[i] = q as f32 / 65535.0;
+        }
+        out
+    }
+}
+
+fn features_to_key(features: &[f32]) -> Result<ContextKey<JsVocab, JS_DIM>, JsError> {
+    if features.len() != JS_DIM {
+        return Err(JsError::new(&format!(
+            "feature vector must have exactly {JS_DIM} elements, got {}",
+            features.len()
+        )));
+    }
+    let mut q = [0u16; JS_DIM];
+    for (i, &f) in features.iter().enumerate() {
+        q[i] = (f.clamp(0.0, 1.0) * 65535.0) as u16;
+    }
+    Ok(ContextKey::new(JsVocab(q)))
+}
+
+// ── Personality ───────────────────────────────────────────────────────────────
+
+/// Personality modulators — tune how trust builds and erodes.
+///
+/// All parameters are clamped to [0.0, 1.0].
+#[wasm_bindgen(js_name = Personality)]
+#[derive(Clone)]
+pub struct WasmPersonality {
+    inner: Personality,
+}
+
+#[wasm_bindgen(js_class = Personality)]
+impl WasmPersonality {
+    /// Create a new Personality from curiosity drive, startle sensitivity and recovery speed.
+    #[wasm_bindgen(constructor)]
+    pub fn new(curiosity_drive: f32, startle_sensitivity: f32, recovery_speed: f32) -> Self {
+        Self { inner: Personality::new_clamped(curiosity_drive, startle_sensitivity, recovery_speed) }
+    }
+
+    /// Returns the curiosity drive modulator in [0.0, 1.0].
+    #[wasm_bindgen(getter, js_name = curiosityDrive)]
+    pub fn curiosity_drive(&self) -> f32 {
+        self.inner.curiosity_drive
+    }
+
+    /// Returns the startle sensitivity modulator in [0.0, 1.0].
+    #[wasm_bindgen(getter, js_name = startleSensitivity)]
+    pub fn startle_sensitivity(&self) -> f32 {
+        self.inner.startle_sensitivity
+    }
+
+    /// Returns the recovery speed modulator in [0.0, 1.0].
+    #[wasm_bindgen(getter, js_name = recoverySpeed)]
+    pub fn recovery_speed(&self) -> f32 {
+        self.inner.recovery_speed
+    }
+}
+
+// ── CoherenceField ────────────────────────────────────────────────────────────
+
+/// Context-keyed trust accumulator.
+///
+/// Every method takes a 6-element `Float32Array` describing the current
+/// sensory state (values in [0.0, 1.0]); other lengths throw.
+#[wasm_bindgen(js_name = CoherenceField)]
+pub struct WasmCoherenceField {
+    inner: CoherenceField<JsVocab, JS_DIM>,
+}
+
+impl Default for WasmCoherenceField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen(js_class = CoherenceField)]
+impl WasmCoherenceField {
+    /// Create a new empty coherence field.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self { inner: CoherenceField::new() }
+    }
+
+    /// Record a positive interaction in the given sensory context.
+    #[wasm_bindgen(js_name = positiveInteraction)]
+    pub fn positive_interaction(
+        &mut self,
+        features: &[f32],
+        personality: &WasmPersonality,
+        tick: u64,
+        alone: bool,
+    ) -> Result<(), JsError> {
+        let key = features_to_key(features)?;
+        self.inner.positive_interaction(&key, &personality.inner, tick, alone);
+        Ok(())
+    }
+
+    /// Record a negative interaction (startle, aversive event) in the given context.
+    #[wasm_bindgen(js_name = negativeInteraction)]
+    pub fn negative_interaction(
+        &mut self,
+        features: &[f32],
+        personality: &WasmPersonality,
+        tick: u64,
+    ) -> Result<(), JsError> {
+        let key = features_to_key(features)?;
+        self.inner.negative_interaction(&key, &personality.inner, tick);
+        Ok(())
+    }
+
+    /// Effective coherence in [0.0, 1.0] for a context under the CCF-001 gate.
+    #[wasm_bindgen(js_name = effectiveCoherence)]
+    pub fn effective_coherence(&self, instant: f32, features: &[f32]) -> Result<f32, JsError> {
+        let key = features_to_key(features)?;
+        Ok(self.inner.effective_coherence(instant, &key))
+    }
+
+    /// Age every context by `elapsed_ticks` of disuse.
+    #[wasm_bindgen(js_name = decayAll)]
+    pub fn decay_all(&mut self, elapsed_ticks: u64) {
+        self.inner.decay_all(elapsed_ticks);
+    }
+
+    /// Accumulated trust for a context, without the instant gate (0.0 if unseen).
+    #[wasm_bindgen(js_name = contextCoherence)]
+    pub fn context_coherence(&self, features: &[f32]) -> Result<f32, JsError> {
+        let key = features_to_key(features)?;
+        Ok(self.inner.context_coherence(&key))
+    }
+
+    /// Number of distinct sensory contexts tracked.
+    #[wasm_bindgen(js_name = contextCount)]
+    pub fn context_count(&self) -> usize {
+        self.inner.context_count()
+    }
+}
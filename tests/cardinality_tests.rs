@@ -142,11 +142,13 @@ fn test_merge_uses_min_coherence() {
         value: 0.8,
         interaction_count: 10,
         last_interaction_tick: 100,
+        ..CoherenceAccumulator::new()
     };
     let b = CoherenceAccumulator {
         value: 0.3,
         interaction_count: 5,
         last_interaction_tick: 200,
+        ..CoherenceAccumulator::new()
     };
 
     let merged = merge_accumulators(&a, &b);
@@ -179,11 +181,13 @@ fn test_merge_sums_counts() {
         value: 0.5,
         interaction_count: 7,
         last_interaction_tick: 50,
+        ..CoherenceAccumulator::new()
     };
     let b = CoherenceAccumulator {
         value: 0.9,
         interaction_count: 13,
         last_interaction_tick: 80,
+        ..CoherenceAccumulator::new()
     };
 
     let merged = merge_accumulators(&a, &b);
@@ -492,16 +496,19 @@ fn test_merge_associative_commutative() {
         value: 0.7,
         interaction_count: 4,
         last_interaction_tick: 10,
+        ..CoherenceAccumulator::new()
     };
     let b = CoherenceAccumulator {
         value: 0.4,
         interaction_count: 8,
         last_interaction_tick: 30,
+        ..CoherenceAccumulator::new()
     };
     let c = CoherenceAccumulator {
         value: 0.9,
         interaction_count: 2,
         last_interaction_tick: 20,
+        ..CoherenceAccumulator::new()
     };
 
     // Commutativity: merge(a, b) == merge(b, a)
@@ -532,3 +539,242 @@ fn test_merge_associative_commutative() {
         "merge not associative on interaction_count"
     );
 }
+
+// ─── test 9: cold-start curve ────────────────────────────────────────────────
+
+/// New Tier 1 classes start on the configured cold-start curve, and never at
+/// or above the familiarity threshold.
+#[test]
+fn test_cold_start_curve_applies_to_new_classes() {
+    use ccf_core::accumulator::ColdStartCurve;
+
+    let config = TieredContextConfig {
+        cold_start: ColdStartCurve::new(0.25, 2),
+        ..TieredContextConfig::default()
+    };
+    let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config);
+    map.set_personality_baseline(0.15); // curiosity = 1.0
+
+    let key = default_key();
+    map.negative_interaction(&key, &default_personality(), 0);
+
+    let cls = map.classes.values().next().expect("Tier 1 class must exist");
+    // 0.25 cold start minus one startle (0.05 × (0.5 + 0.5)), floored at 0.
+    assert!(
+        (cls.accumulator.value - 0.20).abs() < 1e-5,
+        "cold-start class value = {}",
+        cls.accumulator.value
+    );
+    assert!(map.context_coherence(&key) < 0.3);
+}
+
+// ─── test 10: promotion by coherence ─────────────────────────────────────────
+
+/// With `promotion_coherence` set, a class that earns high coherence quickly
+/// promotes before reaching the interaction-count threshold (OR semantics).
+#[test]
+fn test_promotion_by_coherence_before_count() {
+    let config = TieredContextConfig {
+        promotion_threshold: 20,
+        promotion_coherence: Some(0.3),
+        ..TieredContextConfig::default()
+    };
+    let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config);
+    let mut count_only: TieredContextMap<MbotSensors, 6, 8, 4> =
+        TieredContextMap::new(TieredContextConfig::default());
+
+    let key = default_key();
+    let personality = default_personality();
+
+    // Alone bootstrap: coherence climbs fast while the count stays low.
+    let mut tick = 0u64;
+    while map.classes.values().next().map_or(true, |c| !c.tier2_active) {
+        map.positive_interaction(&key, &personality, tick, true);
+        count_only.positive_interaction(&key, &personality, tick, true);
+        tick += 1;
+        assert!(tick < 20, "coherence rule should promote before the count rule");
+    }
+
+    let cls = map.classes.values().next().unwrap();
+    assert!(cls.accumulator.value >= 0.3, "promoted at coherence {}", cls.accumulator.value);
+    assert!(cls.accumulator.interaction_count < 20);
+    assert!(
+        !count_only.classes.values().next().unwrap().tier2_active,
+        "count rule alone must not have promoted yet"
+    );
+}
+
+// ─── test 11: enumeration ────────────────────────────────────────────────────
+
+/// `iter_tier1` reports every class and `tier2_entries_of` the fine keys
+/// remembered under each.
+#[test]
+fn test_enumerate_classes_and_entries() {
+    let config = TieredContextConfig {
+        promotion_threshold: 1,
+        tier1_feature_mask: 0b01_1111, // ignore time of day
+        ..TieredContextConfig::default()
+    };
+    let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config);
+    let personality = default_personality();
+
+    let day = key_with_time(TimePeriod::Day);
+    let evening = key_with_time(TimePeriod::Evening);
+    let bright = ContextKey::new(MbotSensors {
+        brightness: BrightnessBand::Bright,
+        ..day.vocabulary.clone()
+    });
+    for (tick, key) in [&day, &evening, &bright, &day].into_iter().enumerate() {
+        map.positive_interaction(key, &personality, tick as u64, false);
+    }
+
+    let dim_class = map.tier1_key_of(&day);
+    assert_eq!(dim_class, map.tier1_key_of(&evening));
+    let bright_class = map.tier1_key_of(&bright);
+    assert_ne!(dim_class, bright_class);
+
+    let mut classes: Vec<_> = map.iter_tier1().map(|(k, cls)| (k, cls.accumulator.interaction_count)).collect();
+    classes.sort();
+    let mut expected = vec![(dim_class, 3), (bright_class, 1)];
+    expected.sort();
+    assert_eq!(classes, expected);
+
+    let dim_entries: Vec<_> = map.tier2_entries_of(dim_class).map(|(k, _)| k.clone()).collect();
+    assert_eq!(dim_entries.len(), 2);
+    assert!(dim_entries.contains(&day) && dim_entries.contains(&evening));
+    let bright_entries: Vec<_> = map.tier2_entries_of(bright_class).map(|(k, _)| k.clone()).collect();
+    assert_eq!(bright_entries, vec![bright.clone()]);
+    assert_eq!(map.tier2_entries_of(0).count(), 0);
+
+    #[cfg(feature = "std")]
+    {
+        let stats = map.stats();
+        assert_eq!((stats.tier1.entries, stats.tier1.total_interactions), (2, 4));
+        assert_eq!((stats.tier2.entries, stats.tier2.total_interactions), (3, 4));
+        assert!(stats.tier1.mean_coherence > 0.0 && stats.tier2.mean_coherence > 0.0);
+    }
+}
+
+// ─── test 12: snapshot round trip ────────────────────────────────────────────
+
+/// `CcfSegSnapshot::from_tiered` flattens both tiers and `restore_tiered`
+/// puts every coarse and fine coherence value back where it was.
+#[cfg(feature = "serde")]
+#[test]
+fn test_seg_snapshot_round_trip_preserves_both_tiers() {
+    use ccf_core::seg::{CcfSegSnapshot, RecordTier};
+
+    let config = TieredContextConfig {
+        promotion_threshold: 3,
+        tier1_feature_mask: 0b01_1111, // ignore time of day
+        ..TieredContextConfig::default()
+    };
+    let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config.clone());
+    let personality = default_personality();
+
+    // Day encodes time as 0.0, which the mask would make collide with its
+    // class; Evening and Night keep the two tiers distinct.
+    let evening = key_with_time(TimePeriod::Evening);
+    let night = key_with_time(TimePeriod::Night);
+    let bright = ContextKey::new(MbotSensors {
+        brightness: BrightnessBand::Bright,
+        ..evening.vocabulary.clone()
+    });
+    for tick in 0..12u64 {
+        let key = if tick % 3 == 0 { &night } else { &evening };
+        map.positive_interaction(key, &personality, tick, false);
+    }
+    map.positive_interaction(&bright, &personality, 12, false);
+    map.negative_interaction(&evening, &personality, 13);
+
+    let snapshot = CcfSegSnapshot::from_tiered(&map, &personality, 0, 0, 14);
+    let tiers: Vec<_> = snapshot.contexts.iter().filter_map(|r| r.tier).collect();
+    assert_eq!(tiers.iter().filter(|t| **t == RecordTier::Coarse).count(), 2);
+    assert_eq!(tiers.iter().filter(|t| **t == RecordTier::Fine).count(), 2);
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let loaded: CcfSegSnapshot = serde_json::from_str(&json).unwrap();
+    let mut restored: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config);
+    let candidates = [evening.clone(), night.clone(), bright.clone()];
+    assert_eq!(loaded.restore_tiered(&mut restored, &candidates), Ok(4));
+
+    assert_eq!(restored.tier1_class_count(), 2);
+    assert_eq!(restored.tier2_entry_count(), 2);
+    for (t1k, cls) in map.iter_tier1() {
+        let back = &restored.classes.get(&t1k).expect("class restored").accumulator;
+        assert_eq!(back.value, cls.accumulator.value);
+        assert_eq!(back.interaction_count, cls.accumulator.interaction_count);
+    }
+    for key in &candidates {
+        assert_eq!(restored.context_coherence(key), map.context_coherence(key));
+    }
+}
+
+/// With the default all-dimensions mask a class and its fine entry share a
+/// hash; the snapshot stores their min-coherence merge once, in both tiers.
+#[cfg(feature = "serde")]
+#[test]
+fn test_seg_snapshot_merges_colliding_tiers() {
+    use ccf_core::seg::{CcfSegSnapshot, RecordTier};
+
+    let config = TieredContextConfig { promotion_threshold: 2, ..TieredContextConfig::default() };
+    let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config.clone());
+    let personality = default_personality();
+    let key = default_key();
+    for tick in 0..6 {
+        map.positive_interaction(&key, &personality, tick, false);
+    }
+
+    let t1k = map.tier1_key_of(&key);
+    let cls = map.classes.get(&t1k).unwrap();
+    let fine = cls.tier2_entries.get(&key).unwrap();
+    let merged = merge_accumulators(&cls.accumulator, fine);
+
+    let snapshot = CcfSegSnapshot::from_tiered(&map, &personality, 0, 0, 6);
+    assert_eq!(snapshot.contexts.len(), 1);
+    let record = &snapshot.contexts[0];
+    assert_eq!(record.tier, Some(RecordTier::Both));
+    assert_eq!(record.coherence_value, merged.value);
+    assert_eq!(record.interaction_count, merged.interaction_count);
+
+    let mut restored: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config);
+    assert_eq!(snapshot.restore_tiered(&mut restored, core::slice::from_ref(&key)), Ok(2));
+    let cls = restored.classes.get(&t1k).unwrap();
+    assert!(cls.tier2_active);
+    assert_eq!(cls.accumulator.value, merged.value);
+    assert_eq!(restored.context_coherence(&key), merged.value);
+}
+
+// ─── test 13: personality parity with CoherenceField ─────────────────────────
+
+/// The same personality grows a context at the same rate in both tiers of the
+/// map as in a flat `CoherenceField`, curiosity factor included.
+#[test]
+fn test_positive_interaction_matches_coherence_field() {
+    use ccf_core::accumulator::CoherenceField;
+
+    let key = default_key();
+    let config = TieredContextConfig { promotion_threshold: 5, ..TieredContextConfig::default() };
+    for curiosity_drive in [0.0, 0.5, 1.0] {
+        let personality = Personality { curiosity_drive, ..Personality::default() };
+        let mut map: TieredContextMap<MbotSensors, 6, 8, 4> = TieredContextMap::new(config.clone());
+        let mut coarse: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let mut fine: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        for tick in 0..20 {
+            map.positive_interaction(&key, &personality, tick, false);
+            coarse.positive_interaction(&key, &personality, tick, false);
+            // The fine entry is created on the interaction that promotes the class.
+            if tick >= 4 {
+                fine.positive_interaction(&key, &personality, tick, false);
+            }
+        }
+
+        let cls = map.classes.get(&map.tier1_key_of(&key)).unwrap();
+        let (tier1, flat) = (cls.accumulator.value, coarse.context_coherence(&key));
+        assert!((tier1 - flat).abs() < 1e-6, "curiosity {curiosity_drive}: {tier1} vs {flat}");
+        assert!(cls.tier2_active);
+        assert_eq!(map.context_interaction_count(&key), fine.context_interaction_count(&key));
+        let (tier2, flat) = (map.context_coherence(&key), fine.context_coherence(&key));
+        assert!((tier2 - flat).abs() < 1e-6, "curiosity {curiosity_drive}: {tier2} vs {flat}");
+    }
+}
@@ -12,8 +12,15 @@ mod tests {
         TimePeriod,
     };
     use ccf_core::phase::Personality;
-    use ccf_core::seg::{CcfSegSnapshot, CCF_SEG_VERSION};
-    use ccf_core::vocabulary::ContextKey;
+    use ccf_core::boundary::{BoundaryConfig, MinCutBoundary, PartitionSide};
+    use ccf_core::phase::{PhaseSpace, PhaseTracker, SocialPhase};
+    use ccf_core::seg::{
+        BoundarySnapshot, CcfSegSnapshot, CcfSystemSnapshot, ChecksumStatus, SnapshotError,
+        CCF_SEG_VERSION, CCF_SYSTEM_VERSION,
+    };
+    #[cfg(feature = "seg-migrate")]
+    use ccf_core::seg::MigrateError;
+    use ccf_core::vocabulary::{ContextKey, CosineMetric};
 
     // ── Helpers ──────────────────────────────────────────────────────────────
 
@@ -87,6 +94,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_filtered_snapshot_keeps_only_trusted_contexts() {
+        let (field, personality) = make_field();
+        let full = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 36);
+        let trusted = CcfSegSnapshot::from_field_filtered(&field, &personality, 0, 0, 36, 0.3);
+
+        let expected: Vec<u32> = full
+            .contexts
+            .iter()
+            .filter(|r| r.coherence_value >= 0.3)
+            .map(|r| r.context_hash)
+            .collect();
+        assert!(!expected.is_empty() && expected.len() < full.context_count());
+        assert_eq!(trusted.context_count(), expected.len());
+        assert!(trusted.contexts.iter().all(|r| r.coherence_value >= 0.3));
+        assert!(trusted.find_context(dark_loud().context_hash_u32()).is_none());
+
+        assert_eq!(trusted.min_coherence, Some(0.3));
+        assert_eq!(full.min_coherence, None);
+        assert_eq!(trusted.total_interactions, 36);
+        assert_eq!(trusted.checksum_status(), ChecksumStatus::Valid);
+    }
+
     #[test]
     fn test_snapshot_version_is_current() {
         let (field, personality) = make_field();
@@ -290,6 +320,22 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "seg-binary")]
+    #[test]
+    fn test_binary_round_trip_is_stable_and_smaller_than_json() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 1_700_000_000, 1_700_003_600, 300);
+
+        let bytes = snapshot.to_bytes();
+        let restored = CcfSegSnapshot::from_bytes(&bytes).expect("decode");
+        assert_eq!(restored, snapshot);
+        assert_eq!(restored.to_bytes(), bytes);
+
+        let json = serde_json::to_vec(&snapshot).unwrap();
+        assert!(bytes.len() * 3 < json.len(), "binary {} bytes vs JSON {}", bytes.len(), json.len());
+        assert!(CcfSegSnapshot::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+
     #[test]
     fn test_empty_field_snapshot() {
         let field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
@@ -312,4 +358,501 @@ mod tests {
         // Hash 0 is extremely unlikely to match any real context
         assert!(snapshot.find_context(0xDEAD_BEEF).is_none());
     }
+
+    // ── validate / restore_into ──────────────────────────────────────────────
+
+    #[test]
+    fn test_validate_accepts_from_field_snapshot() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        assert_eq!(snapshot.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_names_failing_check() {
+        let (field, personality) = make_field();
+        let good = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+
+        let mut bad = good.clone();
+        bad.contexts[1].coherence_value = 1.5;
+        let hash = bad.contexts[1].context_hash;
+        assert_eq!(
+            bad.validate(),
+            Err(SnapshotError::CoherenceOutOfRange { hash, value: 1.5 })
+        );
+
+        let mut nan = good.clone();
+        nan.contexts[0].coherence_value = f32::NAN;
+        assert!(matches!(nan.validate(), Err(SnapshotError::CoherenceOutOfRange { .. })));
+
+        let mut bad = good.clone();
+        bad.personality.startle_sensitivity = -0.1;
+        assert_eq!(
+            bad.validate(),
+            Err(SnapshotError::PersonalityOutOfRange { field: "startle_sensitivity", value: -0.1 })
+        );
+
+        let mut bad = good.clone();
+        bad.version = 99;
+        assert!(matches!(bad.validate(), Err(SnapshotError::VersionMismatch { found: 99, .. })));
+
+        let mut bad = good;
+        let filler = bad.contexts[0].clone();
+        bad.contexts.resize(65, filler);
+        assert!(matches!(bad.validate(), Err(SnapshotError::TooManyContexts { count: 65, .. })));
+    }
+
+    #[test]
+    fn test_restore_into_round_trip_and_rejects_invalid() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+
+        let mut restored: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(snapshot.restore_into(&mut restored, &keys), Ok(3));
+        for key in &keys {
+            assert_eq!(restored.context_coherence(key), field.context_coherence(key));
+        }
+
+        let mut corrupt = snapshot;
+        corrupt.contexts[0].coherence_value = f32::INFINITY;
+        let mut untouched: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert!(corrupt.restore_into(&mut untouched, &keys).is_err());
+        assert_eq!(untouched.context_count(), 0);
+    }
+
+    // ── checksum ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_checksum_detects_single_bit_flip() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        assert_eq!(snapshot.checksum_status(), ChecksumStatus::Valid);
+
+        for bit in 0..32 {
+            let mut flipped = snapshot.clone();
+            let v = flipped.contexts[2].coherence_value;
+            flipped.contexts[2].coherence_value = f32::from_bits(v.to_bits() ^ (1 << bit));
+            assert!(!flipped.verify_checksum(), "bit {} not detected", bit);
+            let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+            let mut target: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+            assert!(flipped.restore_into(&mut target, &keys).is_err(), "bit {}", bit);
+        }
+
+        let mut flipped = snapshot;
+        flipped.contexts[0].coherence_value *= 0.5;
+        assert!(matches!(flipped.validate(), Err(SnapshotError::ChecksumMismatch { .. })));
+    }
+
+    #[cfg(feature = "seg-binary")]
+    #[test]
+    fn test_from_bytes_checked_rejects_flipped_byte() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        let bytes = snapshot.to_bytes();
+        assert_eq!(
+            CcfSegSnapshot::from_bytes_checked(&bytes),
+            Ok((snapshot.clone(), ChecksumStatus::Valid))
+        );
+
+        // Flip one byte of the first coherence value (stored as raw f32 LE bytes).
+        let needle = snapshot.contexts[0].coherence_value.to_le_bytes();
+        let at = bytes.windows(4).position(|w| w == needle).expect("coherence bytes present");
+        let mut corrupt = bytes.clone();
+        corrupt[at] ^= 0x01;
+
+        let decoded = CcfSegSnapshot::from_bytes(&corrupt).expect("still decodes");
+        assert!(!decoded.verify_checksum());
+        assert!(matches!(
+            CcfSegSnapshot::from_bytes_checked(&corrupt),
+            Err(SnapshotError::ChecksumMismatch { .. })
+        ));
+        assert_eq!(CcfSegSnapshot::from_bytes_checked(&bytes[..3]), Err(SnapshotError::Malformed));
+    }
+
+    #[cfg(feature = "seg-binary")]
+    #[test]
+    fn test_verify_fails_for_any_flipped_byte() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        assert!(snapshot.verify());
+        let bytes = snapshot.to_bytes();
+
+        // Every single-byte corruption either fails to decode or fails verify().
+        for at in 0..bytes.len() {
+            let mut corrupt = bytes.clone();
+            corrupt[at] ^= 0xA5;
+            if let Ok(decoded) = CcfSegSnapshot::from_bytes(&corrupt) {
+                assert!(!decoded.verify(), "byte {} flipped but verify() passed", at);
+            }
+        }
+
+        let mut unchecked = snapshot;
+        unchecked.checksum = None;
+        assert!(!unchecked.verify(), "a missing checksum verifies nothing");
+    }
+
+    #[cfg(feature = "seg-binary")]
+    #[test]
+    fn test_from_bytes_checked_reports_missing_checksum() {
+        let (field, personality) = make_field();
+        let mut snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        snapshot.checksum = None;
+        let (loaded, status) =
+            CcfSegSnapshot::from_bytes_checked(&snapshot.to_bytes()).expect("unchecked snapshot loads");
+        assert_eq!(status, ChecksumStatus::Missing);
+        assert_eq!(loaded, snapshot);
+    }
+
+    #[test]
+    fn test_snapshot_without_checksum_still_loads() {
+        let (field, personality) = make_field();
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        let mut json: serde_json::Value = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().remove("checksum");
+
+        let legacy: CcfSegSnapshot = serde_json::from_value(json).expect("legacy snapshot parses");
+        assert_eq!(legacy.checksum, None);
+        assert_eq!(legacy.checksum_status(), ChecksumStatus::Missing);
+        assert!(legacy.verify_checksum());
+        assert!(!legacy.verify());
+        assert_eq!(legacy.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_snapshot_with_boundary_restores_same_min_cut() {
+        let (field, personality) = make_field();
+        let mut boundary: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        let keys = [bright_quiet(), dim_moderate(), dark_loud()];
+        let seen: Vec<_> = keys.iter().map(|k| (k.clone(), k.context_hash_u32())).collect();
+        for key in &keys {
+            boundary.report_context_with_key(key, &seen);
+        }
+        assert!(boundary.min_cut_value() > 0.0);
+        let snapshot = CcfSegSnapshot::from_field_and_boundary(&field, &personality, 0, 0, 0, &boundary);
+        assert_eq!(snapshot.checksum_status(), ChecksumStatus::Valid);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: CcfSegSnapshot = serde_json::from_str(&json).unwrap();
+        let restored: MinCutBoundary<MbotSensors, 6> =
+            loaded.restore_boundary().expect("valid").expect("boundary stored");
+        assert_eq!(restored.min_cut_value(), boundary.min_cut_value());
+        assert_eq!(restored.node_count(), 3);
+
+        // Field-only snapshots, including JSON without the key, carry no boundary.
+        let field_only = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        let mut legacy: serde_json::Value = serde_json::to_value(&field_only).unwrap();
+        legacy.as_object_mut().unwrap().remove("boundary");
+        let legacy: CcfSegSnapshot = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.restore_boundary::<MbotSensors, 6, 64, CosineMetric>().unwrap().is_none());
+    }
+
+    // ── migration ────────────────────────────────────────────────────────────
+
+    #[cfg(feature = "seg-migrate")]
+    #[test]
+    fn test_migrate_v1_json_preserves_coherence() {
+        let v1 = r#"{
+            "version": 1,
+            "created_at": 1700000000,
+            "last_active_at": 1700003600,
+            "total_interactions": 42,
+            "personality": { "curiosity_drive": 0.8, "startle_sensitivity": 0.3, "recovery_speed": 0.7 },
+            "contexts": [
+                { "context_hash": 305419896, "coherence_value": 0.625,
+                  "interaction_count": 30, "last_interaction_tick": 900 },
+                { "context_hash": 2596069104, "coherence_value": 0.125,
+                  "interaction_count": 12, "last_interaction_tick": 400 }
+            ]
+        }"#;
+        let snapshot = CcfSegSnapshot::migrate(serde_json::from_str(v1).unwrap()).expect("v1 migrates");
+        assert_eq!(snapshot.version, CCF_SEG_VERSION);
+        assert_eq!(snapshot.total_interactions, 42);
+        assert_eq!(snapshot.find_context(305_419_896).unwrap().coherence_value, 0.625);
+        assert_eq!(snapshot.find_context(2_596_069_104).unwrap().coherence_value, 0.125);
+        assert_eq!(snapshot.checksum_status(), ChecksumStatus::Missing);
+        assert_eq!(snapshot.validate(), Ok(()));
+
+        // A late v1 snapshot carrying a checksum is verified, then re-signed.
+        let (field, personality) = make_field();
+        let mut late = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        late.version = 1;
+        late.update_checksum();
+        let migrated = CcfSegSnapshot::migrate(serde_json::to_value(&late).unwrap()).unwrap();
+        assert_eq!(migrated.checksum_status(), ChecksumStatus::Valid);
+        assert_eq!(migrated.contexts, late.contexts);
+
+        late.contexts[0].coherence_value *= 0.5;
+        assert!(matches!(
+            CcfSegSnapshot::migrate(serde_json::to_value(&late).unwrap()),
+            Err(MigrateError::ChecksumMismatch { version: 1, .. })
+        ));
+        let mut future = serde_json::to_value(&migrated).unwrap();
+        future["version"] = serde_json::json!(CCF_SEG_VERSION + 1);
+        assert_eq!(
+            CcfSegSnapshot::migrate(future),
+            Err(MigrateError::UnsupportedVersion { found: CCF_SEG_VERSION + 1, latest: CCF_SEG_VERSION })
+        );
+        assert_eq!(
+            CcfSegSnapshot::migrate(serde_json::json!({ "contexts": [] })),
+            Err(MigrateError::MissingVersion)
+        );
+        assert_eq!(
+            CcfSegSnapshot::migrate(serde_json::json!({ "version": 1 })),
+            Err(MigrateError::Malformed { version: 1 })
+        );
+    }
+
+    #[test]
+    fn test_first_familiar_tick_survives_round_trip() {
+        let (field, personality) = make_field();
+        let k3 = dim_moderate();
+        let crossed = field.context_first_familiar_tick(&k3);
+        assert!(crossed.is_some());
+        assert_eq!(field.context_first_familiar_tick(&dark_loud()), None);
+
+        let snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let back: CcfSegSnapshot = serde_json::from_str(&json).unwrap();
+        let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+        let mut target: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        back.restore_into(&mut target, &keys).unwrap();
+        assert_eq!(target.context_first_familiar_tick(&k3), crossed);
+        assert_eq!(target.context_first_familiar_tick(&dark_loud()), None);
+    }
+
+    #[test]
+    fn test_restore_requires_u64_hash_match_when_present() {
+        let (field, personality) = make_field();
+        let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+        let mut snapshot = CcfSegSnapshot::from_field(&field, &personality, 0, 0, 0);
+        assert!(snapshot.contexts.iter().all(|r| r.context_hash_u64.is_some()));
+
+        // A different context that happens to share the 32-bit hash.
+        let target_hash = bright_quiet().context_hash_u32();
+        let record = snapshot.contexts.iter_mut().find(|r| r.context_hash == target_hash).unwrap();
+        record.context_hash_u64 = record.context_hash_u64.map(|h| h ^ 1);
+        snapshot.update_checksum();
+        let mut target: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(snapshot.restore_into(&mut target, &keys), Ok(2));
+        assert!(snapshot.find_context_for(&bright_quiet()).is_none());
+
+        // Older snapshots without the 64-bit hash still restore by the 32-bit one.
+        for r in &mut snapshot.contexts {
+            r.context_hash_u64 = None;
+        }
+        snapshot.update_checksum();
+        let mut legacy: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        assert_eq!(snapshot.restore_into(&mut legacy, &keys), Ok(3));
+    }
+
+    // ── CcfSystemSnapshot ────────────────────────────────────────────────────
+
+    /// Field from `make_field`, a boundary over its three contexts, and a
+    /// tracker that has settled into QuietlyBeloved.
+    fn make_system() -> (
+        CoherenceField<MbotSensors, 6>,
+        MinCutBoundary<MbotSensors, 6>,
+        PhaseTracker,
+        Personality,
+    ) {
+        let (field, personality) = make_field();
+        let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+        let all: Vec<_> = keys.iter().map(|k| (k.clone(), k.context_hash_u32())).collect();
+
+        let mut boundary: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::new();
+        for (i, key) in keys.iter().enumerate() {
+            boundary.report_context_with_key(key, &all[..i]);
+        }
+        for key in &keys {
+            boundary.update_trust(
+                key,
+                field.context_coherence(key),
+                field.context_interaction_count(key),
+            );
+        }
+
+        let mut tracker = PhaseTracker::new();
+        for _ in 0..3 {
+            tracker.update(0.7, 0.1, &PhaseSpace::default());
+        }
+        (field, boundary, tracker, personality)
+    }
+
+    #[test]
+    fn test_system_snapshot_round_trip_restores_identical_state() {
+        let (field, boundary, tracker, personality) = make_system();
+        let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 42);
+        assert_eq!(snap.version, CCF_SYSTEM_VERSION);
+
+        let json = serde_json::to_string(&snap).expect("serialise system snapshot");
+        let back: CcfSystemSnapshot<MbotSensors, 6> =
+            serde_json::from_str(&json).expect("deserialise system snapshot");
+        assert_eq!(back, snap);
+        assert_eq!(back.tick, 42);
+
+        let (r_field, r_boundary, r_tracker) = back.restore().expect("restore");
+
+        // Identical effective coherence for every context and instant.
+        for key in [bright_quiet(), dark_loud(), dim_moderate()] {
+            for &instant in &[0.0_f32, 0.4, 0.9] {
+                assert_eq!(
+                    r_field.effective_coherence(instant, &key),
+                    field.effective_coherence(instant, &key)
+                );
+            }
+        }
+
+        // Identical partition.
+        let (a, b) = (boundary.partition(), r_boundary.partition());
+        assert_eq!(r_boundary.min_cut_value(), boundary.min_cut_value());
+        assert_eq!(a.partition_s[..a.partition_s_count], b.partition_s[..b.partition_s_count]);
+
+        // Exact phase and dwell; hysteresis resumes (0.60 is in the deadband).
+        assert_eq!(r_tracker, tracker);
+        let mut resumed = r_tracker;
+        assert_eq!(resumed.update(0.60, 0.1, &PhaseSpace::default()), SocialPhase::QuietlyBeloved);
+        assert_eq!(resumed.ticks_in_phase(), 4);
+    }
+
+    #[test]
+    fn test_system_snapshot_rejects_other_version() {
+        let (field, boundary, tracker, personality) = make_system();
+        let mut snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 0);
+        snap.version = CCF_SYSTEM_VERSION + 1;
+        assert_eq!(
+            snap.restore().err(),
+            Some(SnapshotError::VersionMismatch {
+                expected: CCF_SYSTEM_VERSION,
+                found: CCF_SYSTEM_VERSION + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_system_snapshot_rejects_partial_component() {
+        let (field, boundary, tracker, personality) = make_system();
+        let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 0);
+
+        // Boundary with a node dropped: an edge now points past the node list.
+        let mut truncated = snap.clone();
+        truncated.boundary.nodes.pop();
+        assert!(matches!(
+            truncated.restore().err(),
+            Some(SnapshotError::BoundaryEdgeOutOfRange { .. })
+        ));
+
+        // Missing component entirely: deserialisation fails cleanly.
+        let mut value = serde_json::to_value(&snap).unwrap();
+        value.as_object_mut().unwrap().remove("phase");
+        assert!(serde_json::from_value::<CcfSystemSnapshot<MbotSensors, 6>>(value).is_err());
+    }
+
+    #[test]
+    fn test_boundary_snapshot_keeps_keys_recency_and_partition() {
+        let keys = [bright_quiet(), dark_loud(), dim_moderate()];
+        let all: Vec<_> = keys.iter().map(|k| (k.clone(), k.context_hash_u32())).collect();
+        let config = BoundaryConfig {
+            recency_lambda: 0.01,
+            min_trust_observations: 1,
+            ..BoundaryConfig::default()
+        };
+        let mut boundary: MinCutBoundary<MbotSensors, 6> = MinCutBoundary::with_config(config);
+        for (i, key) in keys.iter().enumerate() {
+            boundary.report_context_with_key(key, &all[..i]);
+        }
+        // Staggered updates: the first context is 100 ticks staler than the last.
+        for (i, key) in keys.iter().enumerate() {
+            boundary.update_trust_at(key, 0.8, 10, 100 * i as u64);
+        }
+        let before = boundary.refresh().clone();
+        let snap = BoundarySnapshot::capture(&boundary);
+        assert_eq!(snap.current_tick, Some(200));
+        assert!(snap.partition.is_some(), "cached cut is persisted");
+
+        let json = serde_json::to_string(&snap).unwrap();
+        let back: BoundarySnapshot = serde_json::from_str(&json).unwrap();
+        let mut restored: MinCutBoundary<MbotSensors, 6> = back.restore().expect("restore");
+        assert!(!restored.is_dirty(), "partition restored as the cache");
+        let after = restored.partition();
+        assert_eq!(after.min_cut_value, before.min_cut_value);
+        assert_eq!(after.side(PartitionSide::S), before.side(PartitionSide::S));
+
+        // Keys are hash-only until re-reported, then side_keys sees them again.
+        assert_eq!(restored.side_keys(&after, PartitionSide::S).count(), 0);
+        for key in &keys {
+            restored.report_context_with_key(key, &all);
+        }
+        let expected: Vec<_> = boundary.side_keys(&before, PartitionSide::S).cloned().collect();
+        let got: Vec<_> = restored.side_keys(&after, PartitionSide::S).cloned().collect();
+        assert!(!got.is_empty());
+        assert_eq!(got, expected);
+
+        // Recency resumes from the stored ticks: advancing the clock reweights
+        // both graphs identically.
+        boundary.update_trust_at(&keys[2], 0.8, 10, 300);
+        restored.update_trust_at(&keys[2], 0.8, 10, 300);
+        assert_eq!(restored.min_cut_value(), boundary.min_cut_value());
+    }
+
+    #[test]
+    fn test_system_snapshot_restores_boundary_keys() {
+        let (field, boundary, tracker, personality) = make_system();
+        let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 0);
+        let (_, r_boundary, _) = snap.restore().expect("restore");
+        let result = r_boundary.partition();
+        let total = r_boundary.side_keys(&result, PartitionSide::S).count()
+            + r_boundary.side_keys(&result, PartitionSide::Complement).count();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn test_system_snapshot_does_not_add_field_only_contexts_to_boundary() {
+        let (mut field, mut boundary, tracker, personality) = make_system();
+        // A context the field knows but the boundary was never told about.
+        let extra = make_key(BrightnessBand::Dim, NoiseBand::Loud);
+        field.positive_interaction(&extra, &personality, 0, false);
+        assert_eq!(field.context_count(), 4);
+        let before = boundary.refresh().clone();
+
+        let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 0);
+        let (r_field, r_boundary, _) = snap.restore().expect("restore");
+        assert_eq!(r_field.context_count(), 4);
+        assert_eq!(r_boundary.node_count(), boundary.node_count());
+        assert_eq!(r_boundary.node_count(), 3);
+
+        let after = r_boundary.partition();
+        assert!(after.partitions_equal(&before));
+        assert_eq!(after.min_cut_value, before.min_cut_value);
+        let total = r_boundary.side_keys(&after, PartitionSide::S).count()
+            + r_boundary.side_keys(&after, PartitionSide::Complement).count();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn test_boundary_snapshot_rejects_bad_weights() {
+        let (field, mut boundary, tracker, personality) = make_system();
+        boundary.refresh();
+        let snap = CcfSystemSnapshot::capture(&field, &boundary, &tracker, &personality, 0);
+        assert!(!snap.boundary.edges.is_empty());
+        for bad in [f32::NAN, f32::INFINITY, -0.1, 1.5] {
+            let mut b = snap.boundary.clone();
+            b.edges[0].weight = bad;
+            assert!(matches!(
+                b.restore::<MbotSensors, 6, 64, CosineMetric>().err(),
+                Some(SnapshotError::BoundaryWeightOutOfRange { .. })
+            ));
+        }
+        let mut b = snap.boundary.clone();
+        b.edges[0].base_weight = Some(f32::NAN);
+        assert!(b.restore::<MbotSensors, 6, 64, CosineMetric>().is_err());
+
+        // A partition that drops a node is rejected rather than trusted.
+        let mut b = snap.boundary.clone();
+        b.partition.as_mut().expect("cut was cached").s.pop();
+        assert_eq!(
+            b.restore::<MbotSensors, 6, 64, CosineMetric>().err(),
+            Some(SnapshotError::BoundaryPartitionMismatch)
+        );
+    }
 }
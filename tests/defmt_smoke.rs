@@ -0,0 +1,18 @@
+//! Smoke test for the `defmt` feature: the logging derives exist.
+//!
+//! Run with: `cargo test --features defmt`
+
+#![cfg(feature = "defmt")]
+
+use ccf_core::accumulator::FieldStats;
+use ccf_core::phase::{Personality, PhaseTransition, SocialPhase};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn test_defmt_format_derives_exist() {
+    assert_format::<SocialPhase>();
+    assert_format::<Personality>();
+    assert_format::<PhaseTransition>();
+    assert_format::<FieldStats>();
+}
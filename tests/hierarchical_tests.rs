@@ -333,3 +333,126 @@ fn test_unequal_cluster_sizes() {
         );
     });
 }
+
+// ─── test 7 ───────────────────────────────────────────────────────────────────
+
+/// `apply_parallel` matches `apply` bit for bit on an 8-cluster mixer, both in
+/// steady state and while a restructure transition is blending.
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_apply_matches_sequential() {
+    with_large_stack(|| {
+        let mut mixer = HierarchicalMixer::new(test_config());
+        let n = 64;
+        let assignments: Vec<u16> = (0..n).map(|i| (i % 8) as u16).collect();
+        mixer.update_clusters(&assignments, 8);
+        for c in 0..8u16 {
+            let raw: Vec<f32> = (0..64).map(|i| 1.0 + ((i * 7 + c as usize * 3) % 11) as f32).collect();
+            mixer.update_intra_params(c, &raw);
+        }
+        let inter: Vec<f32> = (0..64).map(|i| 1.0 + ((i * 5) % 9) as f32).collect();
+        mixer.update_inter_params(&inter);
+        mixer.reproject_all();
+
+        let coherence: Vec<f32> = (0..n).map(|i| ((i * 37) % 100) as f32 / 100.0).collect();
+        let counts: Vec<u32> = (0..n).map(|i| ((i * 13) % 17) as u32).collect();
+
+        let assert_same = |mixer: &HierarchicalMixer| {
+            let mut sequential = coherence.clone();
+            let mut parallel = coherence.clone();
+            mixer.apply(&mut sequential, &counts);
+            mixer.apply_parallel(&mut parallel, &counts);
+            for (i, (s, p)) in sequential.iter().zip(&parallel).enumerate() {
+                assert_eq!(s.to_bits(), p.to_bits(), "context {}: {} vs {}", i, s, p);
+            }
+            assert_ne!(sequential, coherence, "mixing should move values");
+        };
+        assert_same(&mixer);
+
+        // Regroup into 8 contiguous clusters; the blend runs both structures.
+        let regrouped: Vec<u16> = (0..n).map(|i| (i / 8) as u16).collect();
+        mixer.update_clusters(&regrouped, 8);
+        mixer.update_inter_params(&inter);
+        mixer.reproject_all();
+        mixer.tick_transition();
+        assert!(mixer.in_transition);
+        assert_same(&mixer);
+    });
+}
+
+// ─── test 8 ───────────────────────────────────────────────────────────────────
+
+/// Run `f` directly when cluster matrices live on the heap, otherwise on a
+/// large-stack thread.
+fn with_mixer_stack<F: FnOnce() + Send + 'static>(f: F) {
+    if cfg!(feature = "hierarchical-heap") {
+        f()
+    } else {
+        with_large_stack(f)
+    }
+}
+
+/// Both matrix layouts produce the same `apply` output: the inline and
+/// `hierarchical-heap` builds must match these reference values.  The heap
+/// build runs on the default test thread stack.
+#[test]
+fn test_matrix_layout_reference_output() {
+    with_mixer_stack(|| {
+        let mut mixer = HierarchicalMixer::new(test_config());
+        let assignments = [0u16, 0, 0, 1, 1, 2, 2, 2, 2];
+        mixer.update_clusters(&assignments, 3);
+        mixer.update_intra_params(0, &[3.0, 1.0, 2.0, 1.0, 4.0, 1.0, 2.0, 2.0, 5.0]);
+        mixer.update_intra_params(1, &[1.0, 3.0, 2.0, 1.0]);
+        mixer.update_intra_params(2, &[1.0, 2.0, 3.0, 4.0, 4.0, 3.0, 2.0, 1.0, 1.0, 1.0, 5.0, 1.0, 2.0, 2.0, 1.0, 3.0]);
+        mixer.update_inter_params(&[0.8, 0.1, 0.1, 0.2, 0.6, 0.2, 0.1, 0.3, 0.6]);
+        mixer.reproject_all();
+
+        let mut coherence = [0.9_f32, 0.2, 0.5, 0.7, 0.1, 0.3, 0.8, 0.6, 0.4];
+        let counts = [3u32, 1, 4, 1, 5, 9, 2, 6, 5];
+        mixer.apply(&mut coherence, &counts);
+
+        let expected = [
+            0.668_076_16_f32, 0.384_060_53, 0.531_549_93, 0.282_415_5, 0.568_445_44,
+            0.515_449_35, 0.511_710_9, 0.545_799_14, 0.492_493_15,
+        ];
+        for (i, (&got, &want)) in coherence.iter().zip(expected.iter()).enumerate() {
+            assert!((got - want).abs() < 1e-6, "coherence[{}] = {} (expected {})", i, got, want);
+        }
+
+        if cfg!(feature = "hierarchical-heap") {
+            let size = std::mem::size_of::<HierarchicalMixer>();
+            assert!(size < 256 * 1024, "heap-backed mixer is {} bytes", size);
+        }
+    });
+}
+
+// ─── test 9 ───────────────────────────────────────────────────────────────────
+
+/// The cluster accessors index the logical `size × size` block, so callers see
+/// the same entries whichever storage layout the build uses.
+#[test]
+fn test_cluster_accessors_are_layout_independent() {
+    with_mixer_stack(|| {
+        let mut mixer = HierarchicalMixer::new(test_config());
+        mixer.update_clusters(&[0u16, 0, 0, 1, 1], 2);
+        let raw = [3.0, 1.0, 2.0, 1.0, 4.0, 1.0, 2.0, 2.0, 5.0];
+        mixer.update_intra_params(0, &raw);
+        mixer.reproject_all();
+
+        let cluster = &mixer.clusters[0];
+        assert_eq!(cluster.size, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(cluster.intra_mix_raw_at(i, j), raw[i * 3 + j]);
+            }
+            let row: f32 = (0..3).map(|j| cluster.intra_mix_projected_at(i, j)).sum();
+            let col: f32 = (0..3).map(|j| cluster.intra_mix_projected_at(j, i)).sum();
+            assert!((row - 1.0).abs() < 1e-4 && (col - 1.0).abs() < 1e-4, "row/col {}", i);
+        }
+
+        // The untouched 2-member cluster stays at the identity.
+        let other = &mixer.clusters[1];
+        assert_eq!(other.intra_mix_projected_at(0, 0), 1.0);
+        assert_eq!(other.intra_mix_projected_at(0, 1), 0.0);
+    });
+}
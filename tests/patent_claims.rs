@@ -254,7 +254,7 @@ fn test_claim_7_min_gate_asymmetric_for_unfamiliar_context() {
 
     // Build a small amount of coherence (below 0.3 threshold)
     {
-        let acc = field.get_or_create(&key);
+        let acc = field.get_or_create(&key, 0);
         for tick in 0..5 {
             acc.positive_interaction(0.5, tick, false);
         }
@@ -280,7 +280,7 @@ fn test_claim_13_familiar_context_history_blending() {
 
     // Build enough history to be familiar (ctx >= 0.3)
     {
-        let acc = field.get_or_create(&key);
+        let acc = field.get_or_create(&key, 0);
         for tick in 0..100 {
             acc.positive_interaction(0.5, tick, false);
         }
@@ -675,6 +675,26 @@ fn test_claim_24_curiosity_raises_positive_delta() {
     );
 }
 
+/// Claim 24, through the field API: with `recovery_speed` held fixed,
+/// `curiosity_drive` alone raises growth of a context from the same start.
+#[test]
+fn test_claim_24_curiosity_raises_growth_at_fixed_recovery() {
+    let key = ContextKey::new(MbotSensors::default());
+    let grow = |curiosity_drive: f32| {
+        let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+        let p = Personality { curiosity_drive, startle_sensitivity: 0.5, recovery_speed: 0.5 };
+        for tick in 0..20 {
+            field.positive_interaction(&key, &p, tick, false);
+        }
+        field.context_coherence(&key)
+    };
+
+    let (low, mid, high) = (grow(0.0), grow(0.5), grow(1.0));
+    assert!(low < mid && mid < high, "low={low} mid={mid} high={high}");
+    // The multiplier is small: within ±10% of the default delta.
+    assert!(high < mid * 1.1 && low > mid * 0.9, "low={low} mid={mid} high={high}");
+}
+
 /// Claim 25: startle_sensitivity in [0,1] amplifies negative_delta on a startle event.
 #[test]
 fn test_claim_25_startle_sensitivity_amplifies_negative_delta() {
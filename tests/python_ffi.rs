@@ -0,0 +1,214 @@
+//! Integration tests for the Python bindings.
+//!
+//! Tests are run with `cargo test --features python-ffi` (add `python-numpy`
+//! for the numpy tests, which need numpy installed in the interpreter).
+//!
+//! Each test embeds the interpreter, registers the `ccf_core` module under
+//! `sys.modules` and runs a short Python script against it, so the bindings
+//! are exercised exactly as Python callers see them.  Not compatible with the
+//! `extension-module` feature, which leaves libpython unlinked.
+
+#![cfg(feature = "python-ffi")]
+
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+/// Run `script` with `ccf_core` importable, panicking with the Python
+/// traceback if it raises.
+fn run_python(script: &str) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let result = (|| -> PyResult<()> {
+            let module = PyModule::new_bound(py, "ccf_core")?;
+            ccf_core::ffi::ccf_core(&module)?;
+            py.import_bound("sys")?.getattr("modules")?.set_item("ccf_core", module)?;
+            py.run_bound(script, None, None)
+        })();
+        if let Err(err) = result {
+            err.print(py);
+            panic!("Python script failed: {}", err);
+        }
+    });
+}
+
+// ─── test 1 ───────────────────────────────────────────────────────────────────
+
+/// `SinkhornKnopp.project` on a list of rows returns a doubly stochastic
+/// 3×3 matrix with its convergence flag and iteration count, honours
+/// `max_iterations`, and rejects non-square input with ValueError.
+#[test]
+fn test_sinkhorn_project_rows_is_doubly_stochastic() {
+    run_python(
+        r#"
+from ccf_core import SinkhornKnopp
+
+sk = SinkhornKnopp(tolerance=1e-6, max_iterations=50)
+rows, converged, iterations = sk.project([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+assert converged, "did not converge"
+assert isinstance(iterations, int) and 1 <= iterations <= 50, iterations
+assert len(rows) == 3 and all(len(r) == 3 for r in rows)
+for r in rows:
+    assert abs(sum(r) - 1.0) < 1e-4, r
+for j in range(3):
+    assert abs(sum(r[j] for r in rows) - 1.0) < 1e-4, j
+
+_, converged, iterations = SinkhornKnopp(tolerance=1e-9, max_iterations=1).project(
+    [[1.0, 9.0, 1.0], [1.0, 1.0, 9.0], [9.0, 1.0, 1.0]]
+)
+assert iterations <= 1, iterations
+
+try:
+    sk.project([[1.0, 2.0], [3.0]])
+    raise AssertionError("non-square input accepted")
+except ValueError as e:
+    assert "square" in str(e)
+"#,
+    );
+}
+
+// ─── test 2 ───────────────────────────────────────────────────────────────────
+
+/// A pickled-then-unpickled CoherenceField reports identical coherence for
+/// every known context, including its cold-start baseline for unseen ones.
+#[test]
+fn test_coherence_field_pickle_round_trip() {
+    run_python(
+        r#"
+import pickle
+from ccf_core import CoherenceField, Personality
+
+p = Personality(curiosity_drive=0.8, startle_sensitivity=0.4, recovery_speed=0.6)
+field = CoherenceField()
+bright = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]
+dark = [0.1, 0.9, 0.0, 1.0, 0.0, 0.9]
+for tick in range(30):
+    field.positive_interaction(bright, p, tick=tick, alone=False)
+field.negative_interaction(dark, p, tick=31)
+
+restored = pickle.loads(pickle.dumps(field))
+assert isinstance(restored, CoherenceField)
+assert restored.context_count() == field.context_count() == 2
+for features in (bright, dark):
+    for instant in (0.2, 0.9):
+        assert restored.effective_coherence(instant, features) == field.effective_coherence(instant, features)
+    assert restored.context_interaction_count(features) == field.context_interaction_count(features)
+
+unseen = [0.5, 0.5, 0.5, 0.5, 0.5, 0.5]
+assert restored.effective_coherence(1.0, unseen) == field.effective_coherence(1.0, unseen)
+"#,
+    );
+}
+
+// ─── test 3 ───────────────────────────────────────────────────────────────────
+
+/// `decay_all` lowers accumulated coherence, and the read-back methods see
+/// distinct contexts and their interaction counts.
+#[test]
+fn test_coherence_field_decay_and_read_back() {
+    run_python(
+        r#"
+from ccf_core import CoherenceField, Personality
+
+p = Personality()
+field = CoherenceField()
+a = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]
+b = [0.2, 0.7, 0.0, 1.0, 0.0, 0.1]
+assert field.context_count() == 0
+assert field.context_coherence(a) == 0.0
+
+for tick in range(40):
+    field.positive_interaction(a, p, tick=tick, alone=False)
+field.positive_interaction(b, p, tick=40, alone=False)
+field.positive_interaction(a, p, tick=41, alone=False)
+assert field.context_count() == 2, field.context_count()
+assert field.context_interaction_count(a) == 41
+assert field.context_interaction_count(b) == 1
+
+before = field.context_coherence(a)
+field.decay_all(5000)
+after = field.context_coherence(a)
+assert 0.0 <= after < before, (before, after)
+assert field.context_count() == 2
+"#,
+    );
+}
+
+// ─── test 4 ───────────────────────────────────────────────────────────────────
+
+/// A float32 numpy array and the equivalent list name the same context; other
+/// dtypes raise TypeError and the wrong length raises ValueError.
+#[cfg(feature = "python-numpy")]
+#[test]
+fn test_numpy_features_match_list_features() {
+    run_python(
+        r#"
+import numpy as np
+from ccf_core import CoherenceField, Personality
+
+p = Personality()
+features = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5]
+array = np.array(features, dtype=np.float32)
+
+field = CoherenceField()
+field.positive_interaction(features, p, tick=0, alone=False)
+field.positive_interaction(array, p, tick=1, alone=False)
+field.positive_interaction(array[::-1][::-1], p, tick=2, alone=False)  # non-contiguous view
+assert field.context_count() == 1, field.context_count()
+assert field.context_interaction_count(features) == 3
+assert field.context_coherence(array) == field.context_coherence(features)
+
+try:
+    field.context_coherence(np.array(features, dtype=np.float64))
+    raise AssertionError("float64 array accepted")
+except TypeError:
+    pass
+try:
+    field.context_coherence(np.zeros(5, dtype=np.float32))
+    raise AssertionError("5-element array accepted")
+except ValueError:
+    pass
+"#,
+    );
+}
+
+// ─── test 5 ───────────────────────────────────────────────────────────────────
+
+/// `SinkhornKnopp.project` on a float32 numpy matrix returns a doubly
+/// stochastic float32 array plus convergence data, leaves the input alone,
+/// and rejects non-square shapes (ValueError) and other dtypes (TypeError).
+#[cfg(feature = "python-numpy")]
+#[test]
+fn test_sinkhorn_project_numpy_is_doubly_stochastic() {
+    run_python(
+        r#"
+import numpy as np
+from ccf_core import SinkhornKnopp
+
+m = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], dtype=np.float32)
+original = m.copy()
+ds, converged, iterations, residual = SinkhornKnopp(tolerance=1e-6, max_iterations=50).project(m)
+assert converged and iterations >= 1 and residual < 1e-4, (converged, iterations, residual)
+assert ds.dtype == np.float32 and ds.shape == (3, 3)
+assert np.allclose(ds.sum(axis=0), 1.0, atol=1e-4)
+assert np.allclose(ds.sum(axis=1), 1.0, atol=1e-4)
+assert np.array_equal(m, original)
+
+# Fortran order is read in logical order.
+ds_f, *_ = SinkhornKnopp(tolerance=1e-6, max_iterations=50).project(np.asfortranarray(m))
+assert np.allclose(ds_f, ds)
+
+try:
+    SinkhornKnopp().project(np.ones((2, 3), dtype=np.float32))
+    raise AssertionError("non-square matrix accepted")
+except ValueError as e:
+    assert "square" in str(e)
+try:
+    SinkhornKnopp().project(np.ones((3, 3), dtype=np.float64))
+    raise AssertionError("float64 matrix accepted")
+except TypeError:
+    pass
+"#,
+    );
+}
@@ -0,0 +1,146 @@
+//! Integration tests for the fixed-capacity `StaticCoherenceField`.
+//!
+//! Run with: `cargo test --features static-field`
+//!
+//! Capacity C=8 is used throughout so the eviction path is reached quickly.
+
+#![cfg(feature = "static-field")]
+
+use ccf_core::accumulator::CoherenceField;
+use ccf_core::defaults::FAMILIARITY_THRESHOLD;
+use ccf_core::mbot::{BrightnessBand, MbotSensors, NoiseBand, PresenceSignature};
+use ccf_core::phase::Personality;
+use ccf_core::static_field::StaticCoherenceField;
+use ccf_core::vocabulary::ContextKey;
+
+type Field = StaticCoherenceField<MbotSensors, 6, 8>;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+fn make_key(brightness: BrightnessBand, noise: NoiseBand, presence: PresenceSignature) -> ContextKey<MbotSensors, 6> {
+    ContextKey::new(MbotSensors::builder().brightness(brightness).noise(noise).presence(presence).build())
+}
+
+fn bright_quiet_static() -> ContextKey<MbotSensors, 6> {
+    make_key(BrightnessBand::Bright, NoiseBand::Quiet, PresenceSignature::Absent)
+}
+
+fn dark_loud_close() -> ContextKey<MbotSensors, 6> {
+    make_key(BrightnessBand::Dark, NoiseBand::Loud, PresenceSignature::Close)
+}
+
+/// Distinct key for each `i` in `0..27`.
+fn nth_key(i: usize) -> ContextKey<MbotSensors, 6> {
+    let brightness = [BrightnessBand::Dark, BrightnessBand::Dim, BrightnessBand::Bright][i % 3];
+    let noise = [NoiseBand::Quiet, NoiseBand::Moderate, NoiseBand::Loud][(i / 3) % 3];
+    let presence = [PresenceSignature::Absent, PresenceSignature::Far, PresenceSignature::Close][(i / 9) % 3];
+    make_key(brightness, noise, presence)
+}
+
+// ─── tests ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_unfamiliar_context_gated_by_min() {
+    let field = Field::new();
+    let key = bright_quiet_static();
+    assert_eq!(field.effective_coherence(0.9, &key), 0.0);
+    assert_eq!(field.context_coherence(&key), 0.0);
+}
+
+#[test]
+fn test_familiar_context_blends_instant_and_history() {
+    let mut field = Field::new();
+    let p = Personality::new();
+    let key = bright_quiet_static();
+    for t in 0..50 {
+        field.positive_interaction(&key, &p, t, false);
+    }
+    let ctx = field.context_coherence(&key);
+    assert!(ctx >= FAMILIARITY_THRESHOLD, "ctx={}", ctx);
+    let eff = field.effective_coherence(0.1, &key);
+    assert!((eff - (0.3 * 0.1 + 0.7 * ctx)).abs() < 1e-6);
+}
+
+#[test]
+fn test_contexts_are_independent() {
+    let mut field = Field::new();
+    let p = Personality::new();
+    let a = bright_quiet_static();
+    let b = dark_loud_close();
+    for t in 0..20 {
+        field.positive_interaction(&a, &p, t, false);
+    }
+    assert!(field.context_coherence(&a) > 0.0);
+    assert_eq!(field.context_coherence(&b), 0.0);
+    assert_eq!(field.context_interaction_count(&a), 20);
+    assert_eq!(field.context_interaction_count(&b), 0);
+}
+
+#[test]
+fn test_negative_interaction_reduces_coherence() {
+    let mut field = Field::new();
+    let p = Personality::new();
+    let key = bright_quiet_static();
+    for t in 0..20 {
+        field.positive_interaction(&key, &p, t, false);
+    }
+    let before = field.context_coherence(&key);
+    field.negative_interaction(&key, &p, 21);
+    assert!(field.context_coherence(&key) < before);
+}
+
+#[test]
+fn test_decay_all_reduces_coherence() {
+    let mut field = Field::new();
+    let p = Personality::new();
+    let key = bright_quiet_static();
+    for t in 0..20 {
+        field.positive_interaction(&key, &p, t, false);
+    }
+    let before = field.context_coherence(&key);
+    field.decay_all(1000);
+    assert!(field.context_coherence(&key) < before);
+}
+
+#[test]
+fn test_eviction_keeps_capacity_and_drops_oldest() {
+    let mut field = Field::new();
+    let p = Personality::new();
+    assert_eq!(field.capacity(), 8);
+    for i in 0..8 {
+        field.positive_interaction(&nth_key(i), &p, i as u64, false);
+    }
+    assert_eq!(field.context_count(), 8);
+
+    // Refresh key 0 so key 1 becomes the least recently seen.
+    field.positive_interaction(&nth_key(0), &p, 100, false);
+    field.positive_interaction(&nth_key(8), &p, 101, false);
+
+    assert_eq!(field.context_count(), 8);
+    assert_eq!(field.context_interaction_count(&nth_key(1)), 0, "oldest context evicted");
+    assert_eq!(field.context_interaction_count(&nth_key(0)), 2);
+    assert_eq!(field.context_interaction_count(&nth_key(8)), 1);
+}
+
+#[test]
+fn test_matches_coherence_field() {
+    let mut fixed = Field::new();
+    let mut field: CoherenceField<MbotSensors, 6> = CoherenceField::new();
+    let p = Personality::new();
+    let a = bright_quiet_static();
+    let b = dark_loud_close();
+    for t in 0..30 {
+        fixed.positive_interaction(&a, &p, t, t % 2 == 0);
+        field.positive_interaction(&a, &p, t, t % 2 == 0);
+        if t % 7 == 0 {
+            fixed.negative_interaction(&b, &p, t);
+            field.negative_interaction(&b, &p, t);
+        }
+    }
+    fixed.decay_all(50);
+    field.decay_all(50);
+    for key in [&a, &b] {
+        assert!((fixed.context_coherence(key) - field.context_coherence(key)).abs() < 1e-6);
+        assert!((fixed.effective_coherence(0.6, key) - field.effective_coherence(0.6, key)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,34 @@
+//! Headless wasm-bindgen tests for the JS-facing API.
+//!
+//! Run under node with `wasm-bindgen-cli` installed:
+//!
+//! ```bash
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test --target wasm32-unknown-unknown --features wasm --test wasm_tests
+//! ```
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use ccf_core::wasm::{WasmCoherenceField, WasmPersonality};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_interaction_cycle() {
+    let personality = WasmPersonality::new(0.6, 0.5, 0.5);
+    let mut field = WasmCoherenceField::new();
+    let features = [0.8, 0.0, 1.0, 0.0, 1.0, 0.5];
+
+    assert_eq!(field.effective_coherence(0.9, &features).unwrap(), 0.0);
+    for tick in 0..50 {
+        field.positive_interaction(&features, &personality, tick, false).unwrap();
+    }
+    let trusted = field.context_coherence(&features).unwrap();
+    assert!(trusted > 0.3, "trusted={}", trusted);
+    assert!(field.effective_coherence(0.9, &features).unwrap() > 0.3);
+
+    field.negative_interaction(&features, &personality, 50).unwrap();
+    field.decay_all(100);
+    assert!(field.context_coherence(&features).unwrap() < trusted);
+    assert_eq!(field.context_count(), 1);
+    assert!(field.positive_interaction(&[0.5; 3], &personality, 51, false).is_err());
+}